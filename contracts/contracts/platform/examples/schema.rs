@@ -1,6 +1,6 @@
 use cosmwasm_schema::write_api;
 
-use cf_base::platform::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cf_base::platform::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
 
 fn main() {
     write_api! {
@@ -8,5 +8,6 @@ fn main() {
         query: QueryMsg,
         execute: ExecuteMsg,
         migrate: MigrateMsg,
+        sudo: SudoMsg,
     }
 }
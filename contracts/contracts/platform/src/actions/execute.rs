@@ -1,150 +1,2062 @@
-use cosmwasm_std::{Decimal, DepsMut, Env, Int256, MessageInfo, Response, StdResult, Uint128};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, Binary, CosmosMsg, Decimal, DepsMut, Env, HexBinary,
+    MessageInfo, Order, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
+};
+
+use cw_storage_plus::Bound;
 
 use cf_base::{
     assets::Token,
+    dex::{Asset, AssetInfo, PairExecuteMsg},
     error::ContractError,
     platform::{
+        msg::{Cw721ReceiveMsg, ReceiveNftMsg},
         state::{
-            APP_INFO, CONFIG, FLIP_COOLDOWN, IS_PAUSED, NORMALIZED_DECIMAL, TRANSFER_ADMIN_STATE,
-            TRANSFER_ADMIN_TIMEOUT, USERS,
+            APP_INFO, ASSIGNED_TIERS, BONUS_BALANCES, BUYBACKS, BUYBACK_BALANCE, BUYBACK_CONFIG,
+            BUYBACK_REPLY_ID, CONFIG, CONTRACT_CALLERS, DEFERRED_ONLY, DISTRIBUTED_REVENUE,
+            FLIP_COOLDOWN, FLIP_ID, HOUSE_NFTS, INSURANCE_CONFIG, IS_PAUSED, MAX_BONUS_CREDITS,
+            MAX_CLIENT_SEED_LEN, MAX_JOB_ID_LEN, MAX_NFT_COLLECTIONS, MAX_PAYOUT_BATCH,
+            MAX_SESSION_DURATION, NFT_FLOOR_PRICES, NFT_PERKS, NORMALIZED_DECIMAL, ORACLE_CONFIG,
+            PAYOUT_BATCH, PAYOUT_QUEUE, PAYOUT_QUEUE_IDS, PENDING_FLIPS, PROMO_CODES,
+            PROMO_REDEMPTIONS, RAKEBACK, RAKEBACK_CONFIG, RANDOMNESS_FEE, RANDOMNESS_REQUESTS,
+            RANDOMNESS_REQUEST_ID, REVEALED_SEEDS, REVEAL_TIMEOUT, REVENUE_SPLIT, SEED_CHAIN,
+            SESSION_GRANTS, SIGNED_NONCES, TIERS, TRANSFER_ADMIN_STATE, TRANSFER_ADMIN_TIMEOUT,
+            USERS, USER_BALANCES, USER_NONCES,
         },
-        types::{Config, Range, Side, TransferAdminState},
+        types::{
+            AppInfo, Bet, BonusCredit, Buyback, BuybackConfig, Callback, Config, IbcTarget,
+            InsuranceConfig, Nft, NftPerk, OracleConfig, Payout, PendingFlip, PromoCode,
+            RakebackConfig, RandomnessRequest, Range, RevenueRecipient, SeedChain, SessionGrant,
+            Side, Tier, TransferAdminState,
+        },
+    },
+    utils::{
+        check_authorization, check_funds, get_nft_transfer_msg, get_transfer_msg,
+        query_token_balance, Attrs, AuthType, FundsType,
     },
-    utils::{check_authorization, check_funds, get_transfer_msg, AuthType, FundsType},
 };
 
-use crate::helpers::{calc_available_to_withdraw, check_pause_state, get_random_weight};
+use hashing_helper::base::{calc_sha256, ENC_KEY_LEN};
+
+use crate::helpers::{
+    calc_available_to_withdraw, calc_distributable_revenue, calc_insurance_to_withdraw,
+    check_callback, check_contract_caller, check_deferred_only, check_pause_state,
+    check_revenue_split, check_tiers, credit_user_balance, dequeue_payout, enqueue_payout,
+    get_flip_callback_msg, get_payout_msg, get_random_hash, get_random_weight,
+    get_randomness_callback_msg, get_seeded_random_weight, get_seeded_randomness, load_nft_perk,
+    load_rakeback, load_user_tier, process_payout_queue, register_ibc_sender, verify_signed_flip,
+};
+
+/// Flip placed on behalf of the user, the user gets the winnings
+pub enum FlipDelegate {
+    /// the session key flips within the grant of the owner
+    Session { owner: String },
+    /// the worker relays the flip signed by the user
+    Signed { user: Addr },
+}
 
+#[allow(clippy::too_many_arguments)]
 pub fn try_flip(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    side: Side,
+    side: Side,
+    nft: Option<Nft>,
+    client_seed: Option<String>,
+    callback: Option<Callback>,
+    amount: Option<Uint128>,
+    delegate: Option<FlipDelegate>,
+    payout_to: Option<IbcTarget>,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    // the bet from the user balance doesn't require funds
+    let (sender_address, asset_amount, asset_info) = match amount {
+        Some(x) => {
+            let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+            (sender_address, x, None)
+        }
+        None => {
+            let (sender_address, asset_amount, asset_info) = check_funds(
+                deps.as_ref(),
+                &info,
+                FundsType::Single {
+                    sender: None,
+                    amount: None,
+                },
+            )?;
+            (sender_address, asset_amount, Some(asset_info))
+        }
+    };
+    check_contract_caller(deps.as_ref(), &sender_address)?;
+    let block_time = env.block.time.seconds();
+    // the ibc-hooks sender is mapped to the original sender receiving the payouts
+    if let Some(x) = &payout_to {
+        register_ibc_sender(deps.api, deps.storage, &sender_address, x)?;
+    }
+    // the session key or the relayer flips on behalf of the user
+    let (user_address, session) = match delegate {
+        Some(FlipDelegate::Session { owner }) => {
+            let owner_address = deps.api.addr_validate(&owner)?;
+            let key = (&owner_address, &sender_address);
+            let mut grant = SESSION_GRANTS
+                .may_load(deps.storage, key)?
+                .ok_or(ContractError::SessionIsNotFound)?;
+            grant.spend(&side, asset_amount, block_time)?;
+            SESSION_GRANTS.save(deps.storage, key, &grant)?;
+
+            (owner_address, Some(sender_address))
+        }
+        Some(FlipDelegate::Signed { user }) => (user, None),
+        None => (sender_address, None),
+    };
+    let normalized_decimal = NORMALIZED_DECIMAL.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let user = USERS.load(deps.storage, &user_address).unwrap_or_default();
+
+    // check fund amount
+    if asset_amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    let (_, mut tier) = load_user_tier(
+        deps.as_ref(),
+        &user_address,
+        user.stats.bets.value,
+        block_time,
+    )?;
+    if let Some(x) = &nft {
+        tier = load_nft_perk(deps.as_ref(), &user_address, x)?.apply(tier);
+    }
+
+    tier.bet.validate(asset_amount)?;
+
+    // check fund denom
+    if let Some(x) = asset_info {
+        if x.try_get_native()? != config.denom {
+            Err(ContractError::WrongAssetType)?;
+        }
+    }
+
+    if amount.is_some() {
+        let user_balance = USER_BALANCES
+            .may_load(deps.storage, &user_address)?
+            .unwrap_or_default();
+
+        if user_balance < asset_amount {
+            Err(ContractError::InsufficientUserBalance)?;
+        }
+
+        USER_BALANCES.save(deps.storage, &user_address, &(user_balance - asset_amount))?;
+        app_info.take_balance_bet(asset_amount);
+    }
+
+    let callback = match callback {
+        Some(x) => Some(Callback {
+            contract: deps.api.addr_validate(&x.contract)?.to_string(),
+            msg: x.msg,
+        }),
+        None => None,
+    };
+
+    let bet = Bet {
+        user: user_address.clone(),
+        side,
+        amount: asset_amount,
+        fee: tier.get_fee(config.platform_fee),
+        cashback: tier.calc_cashback(asset_amount),
+        nft,
+        callback,
+        from_balance: amount.is_some(),
+        session,
+    };
+
+    // seeded flips don't depend on the block, multiple flips per tx are allowed
+    if let Some(client_seed) = client_seed {
+        return place_pending_flip(deps, &env, bet, client_seed, &payout_to, &mut app_info);
+    }
+
+    check_deferred_only(deps.storage)?;
+
+    // don't allow to flip multiple coins in single tx
+    if block_time < user.last_flip_date + FLIP_COOLDOWN {
+        Err(ContractError::MultipleFlipsPerTx)?;
+    }
+
+    let id = match &bet.callback {
+        Some(x) => {
+            check_callback(deps.as_ref(), &Addr::unchecked(&x.contract))?;
+            let id = FLIP_ID.may_load(deps.storage)?.unwrap_or_default();
+            FLIP_ID.save(deps.storage, &(id + 1))?;
+            Some(id)
+        }
+        None => None,
+    };
+
+    let random_weight = get_random_weight(&env, &user_address, &normalized_decimal)?;
+    let (prize, msg, event) = settle_bet(
+        deps.storage,
+        &env,
+        &bet,
+        random_weight,
+        &mut app_info,
+        &config.denom,
+    )?;
+    let callback_msg = match id {
+        Some(x) => get_flip_callback_msg(x, &bet, prize)?,
+        None => None,
+    };
+
+    NORMALIZED_DECIMAL.save(deps.storage, &random_weight)?;
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessages(msg)
+        .add_submessages(callback_msg)
+        .add_attribute("action", "try_flip")
+        .add_attribute("prize", prize)
+        .add_event(
+            event
+                .flip_id(id)
+                .callback(&bet.callback)
+                .ibc_target(&payout_to)
+                .app_info(&app_info),
+        ))
+}
+
+pub fn try_flip_signed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    payload: Binary,
+    pubkey: Binary,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &info.sender,
+        &config.admin,
+        &config.worker,
+        AuthType::Specified {
+            allowlist: vec![config.worker.clone()],
+        },
+    )?;
+
+    let (user, payload) = verify_signed_flip(deps.as_ref(), &env, &payload, &pubkey, &signature)?;
+    let nonce = SIGNED_NONCES
+        .may_load(deps.storage, &user)?
+        .unwrap_or_default();
+
+    if payload.nonce != nonce {
+        Err(ContractError::ImproperSignedNonce)?;
+    }
+
+    SIGNED_NONCES.save(deps.storage, &user, &(nonce + 1))?;
+
+    try_flip(
+        deps,
+        env,
+        info,
+        payload.side,
+        None,
+        payload.client_seed,
+        None,
+        Some(payload.amount),
+        Some(FlipDelegate::Signed { user }),
+        None,
+    )
+}
+
+/// Accepts the bet of seeded flip, it's settled when the seed of the flip round is revealed
+fn place_pending_flip(
+    deps: DepsMut,
+    env: &Env,
+    bet: Bet,
+    client_seed: String,
+    payout_to: &Option<IbcTarget>,
+    app_info: &mut AppInfo,
+) -> Result<Response, ContractError> {
+    let seed_chain = SEED_CHAIN
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::SeedChainIsNotFound)?;
+
+    if client_seed.is_empty() || client_seed.len() > MAX_CLIENT_SEED_LEN {
+        Err(ContractError::ImproperClientSeed)?;
+    }
+
+    let id = FLIP_ID.may_load(deps.storage)?.unwrap_or_default();
+    let nonce = USER_NONCES
+        .may_load(deps.storage, &bet.user)?
+        .unwrap_or_default();
+    let flip = PendingFlip {
+        bet,
+        client_seed,
+        nonce,
+        round: seed_chain.get_flip_round(env.block.height),
+        created_at: env.block.time.seconds(),
+    };
+
+    app_info.add_pending_bet(flip.bet.amount);
+    APP_INFO.save(deps.storage, app_info)?;
+    PENDING_FLIPS.save(deps.storage, id, &flip)?;
+    FLIP_ID.save(deps.storage, &(id + 1))?;
+    USER_NONCES.save(deps.storage, &flip.bet.user, &(nonce + 1))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_flip")
+        .add_event(
+            Attrs::pending_flip(id, &flip)
+                .ibc_target(payout_to)
+                .app_info(app_info),
+        ))
+}
+
+/// Settles the bet with the random weight updating the user and the app info. Returns the prize,
+/// its transfer if it's paid automatically and the flip event without the app info
+fn settle_bet(
+    storage: &mut dyn Storage,
+    env: &Env,
+    bet: &Bet,
+    random_weight: Decimal,
+    app_info: &mut AppInfo,
+    denom: &str,
+) -> Result<(Uint128, Option<SubMsg>, Attrs), ContractError> {
+    let block_time = env.block.time.seconds();
+    let mut user = USERS.load(storage, &bet.user).unwrap_or_default();
+
+    let (rakeback, rakeback_amount) = load_rakeback(storage, &bet.user, &user.stats, block_time)?;
+    if !rakeback_amount.is_zero() {
+        app_info.add_rakeback(rakeback_amount);
+    }
+
+    let is_winner = bet.side.is_winner(random_weight, bet.fee);
+    let (prize, cashback) = if is_winner {
+        (Uint128::new(2) * bet.amount, Uint128::zero())
+    } else {
+        (Uint128::zero(), bet.cashback)
+    };
+    let payout = app_info.settle_flip(bet.amount, prize);
+    user.settle_flip(bet.amount, prize, &payout, block_time);
+
+    if !cashback.is_zero() {
+        app_info.add_cashback(cashback);
+        user.add_cashback(cashback);
+    }
+
+    let insurance_fill = match INSURANCE_CONFIG.may_load(storage)?.flatten() {
+        Some(x) if !is_winner => x.calc_fill(bet.amount, app_info.insurance),
+        _ => Uint128::zero(),
+    };
+    if !insurance_fill.is_zero() {
+        app_info.fill_insurance(insurance_fill);
+    }
+
+    let msg = match payout {
+        Payout::AutoPaid if bet.from_balance => {
+            credit_user_balance(storage, &bet.user, prize)?;
+            app_info.top_up(prize);
+            None
+        }
+        Payout::AutoPaid => Some(get_payout_msg(storage, env, &bet.user, prize, denom)?),
+        _ => None,
+    };
+
+    if !user.unclaimed.is_zero() {
+        enqueue_payout(storage, &bet.user)?;
+    }
+
+    USERS.save(storage, &bet.user, &user)?;
+
+    if let Some(x) = rakeback {
+        RAKEBACK.save(storage, &bet.user, &x)?;
+    }
+
+    let event = Attrs::flip(
+        &bet.user,
+        &bet.side,
+        bet.amount,
+        random_weight,
+        &payout,
+        prize,
+    )
+    .nft(&bet.nft)
+    .from_balance(bet.from_balance)
+    .session(&bet.session)
+    .cashback(cashback)
+    .insurance_fill(insurance_fill)
+    .rakeback(rakeback_amount)
+    .user_info(&user);
+
+    Ok((prize, msg, event))
+}
+
+pub fn try_settle_flip(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let flip = PENDING_FLIPS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::PendingFlipIsNotFound)?;
+    let seed = REVEALED_SEEDS
+        .may_load(deps.storage, flip.round)?
+        .ok_or(ContractError::SeedIsNotRevealed)?;
+
+    let random_weight = get_seeded_random_weight(&seed, &flip.client_seed, flip.nonce);
+    app_info.remove_pending_bet(flip.bet.amount);
+    let (prize, msg, event) = settle_bet(
+        deps.storage,
+        &env,
+        &flip.bet,
+        random_weight,
+        &mut app_info,
+        &config.denom,
+    )?;
+    let callback_msg = get_flip_callback_msg(id, &flip.bet, prize)?;
+
+    PENDING_FLIPS.remove(deps.storage, id);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessages(msg)
+        .add_submessages(callback_msg)
+        .add_attribute("action", "try_settle_flip")
+        .add_event(
+            event
+                .seeded(id, &flip, &seed)
+                .callback(&flip.bet.callback)
+                .app_info(&app_info),
+        ))
+}
+
+pub fn try_refund_flip(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let flip = PENDING_FLIPS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::PendingFlipIsNotFound)?;
+
+    if REVEALED_SEEDS.has(deps.storage, flip.round)
+        || env.block.time.seconds() < flip.created_at + REVEAL_TIMEOUT
+    {
+        Err(ContractError::RefundIsNotAvailable)?;
+    }
+
+    // the missed reveal is paid as the win, the unpaid penalty is added to unclaimed
+    app_info.remove_pending_bet(flip.bet.amount);
+    let penalty = flip.bet.amount;
+    let payout = app_info.pay_reveal_penalty(penalty);
+    let mut user = USERS.load(deps.storage, &flip.bet.user).unwrap_or_default();
+    user.add_reveal_penalty(penalty, &payout);
+
+    let refund = match payout {
+        Payout::AutoPaid => flip.bet.amount + penalty,
+        _ => flip.bet.amount,
+    };
+    let msg = if flip.bet.from_balance {
+        credit_user_balance(deps.storage, &flip.bet.user, refund)?;
+        app_info.top_up(refund);
+        None
+    } else {
+        Some(get_payout_msg(
+            deps.storage,
+            &env,
+            &flip.bet.user,
+            refund,
+            &config.denom,
+        )?)
+    };
+
+    if payout == Payout::Unclaimed {
+        enqueue_payout(deps.storage, &flip.bet.user)?;
+    }
+
+    PENDING_FLIPS.remove(deps.storage, id);
+    USERS.save(deps.storage, &flip.bet.user, &user)?;
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessages(msg)
+        .add_attribute("action", "try_refund_flip")
+        .add_event(
+            Attrs::refund_flip(id, &flip.bet, penalty, &payout)
+                .user_info(&user)
+                .app_info(&app_info),
+        ))
+}
+
+pub fn try_request_randomness(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    job_id: String,
+    callback: Callback,
+    deferred: bool,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, asset_amount, asset_info) = check_funds(
+        deps.as_ref(),
+        &info,
+        FundsType::Single {
+            sender: None,
+            amount: None,
+        },
+    )?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let fee = RANDOMNESS_FEE
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::RandomnessIsDisabled)?;
+
+    if asset_info.try_get_native()? != config.denom {
+        Err(ContractError::WrongAssetType)?;
+    }
+
+    if asset_amount != fee {
+        Err(ContractError::ImproperRandomnessFee)?;
+    }
+
+    if job_id.is_empty() || job_id.len() > MAX_JOB_ID_LEN {
+        Err(ContractError::ImproperJobId)?;
+    }
+
+    let callback = Callback {
+        contract: deps.api.addr_validate(&callback.contract)?.to_string(),
+        msg: callback.msg,
+    };
+    let id = RANDOMNESS_REQUEST_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let event = Attrs::request_randomness(id, &sender_address, &job_id, &callback, fee);
+    RANDOMNESS_REQUEST_ID.save(deps.storage, &(id + 1))?;
+
+    // deferred randomness is unknown in the request tx, so it can't be reverted
+    if deferred {
+        let seed_chain = SEED_CHAIN
+            .may_load(deps.storage)?
+            .flatten()
+            .ok_or(ContractError::SeedChainIsNotFound)?;
+        let request = RandomnessRequest {
+            requester: sender_address,
+            job_id,
+            callback,
+            round: seed_chain.get_flip_round(env.block.height),
+            fee,
+            created_at: env.block.time.seconds(),
+        };
+        RANDOMNESS_REQUESTS.save(deps.storage, id, &request)?;
+        app_info.add_pending_bet(fee);
+        APP_INFO.save(deps.storage, &app_info)?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "try_request_randomness")
+            .add_event(event.deferred(request.round).app_info(&app_info)));
+    }
+
+    check_deferred_only(deps.storage)?;
+    check_contract_caller(deps.as_ref(), &sender_address)?;
+    check_callback(deps.as_ref(), &Addr::unchecked(&callback.contract))?;
+
+    app_info.add_randomness_fee(fee);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    let normalized_decimal = NORMALIZED_DECIMAL.load(deps.storage)?;
+    let hash = get_random_hash(&env, &sender_address, &normalized_decimal)?;
+    NORMALIZED_DECIMAL.save(deps.storage, &hash.to_norm_dec())?;
+    let randomness = HexBinary::from(<[u8; ENC_KEY_LEN]>::from(hash));
+
+    Ok(Response::new()
+        .add_submessage(get_randomness_callback_msg(
+            id,
+            &job_id,
+            &callback,
+            &randomness,
+        )?)
+        .add_attribute("action", "try_request_randomness")
+        .add_event(event.randomness(&randomness).app_info(&app_info)))
+}
+
+pub fn try_fulfill_randomness(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let request = RANDOMNESS_REQUESTS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::RandomnessRequestIsNotFound)?;
+    let seed = REVEALED_SEEDS
+        .may_load(deps.storage, request.round)?
+        .ok_or(ContractError::SeedIsNotRevealed)?;
+
+    let randomness = get_seeded_randomness(&seed, &request.job_id, id);
+    app_info.remove_pending_bet(request.fee);
+    app_info.add_randomness_fee(request.fee);
+
+    RANDOMNESS_REQUESTS.remove(deps.storage, id);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessage(get_randomness_callback_msg(
+            id,
+            &request.job_id,
+            &request.callback,
+            &randomness,
+        )?)
+        .add_attribute("action", "try_fulfill_randomness")
+        .add_event(Attrs::fulfill_randomness(id, &request, &seed, &randomness).app_info(&app_info)))
+}
+
+pub fn try_refund_randomness(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let request = RANDOMNESS_REQUESTS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::RandomnessRequestIsNotFound)?;
+
+    if REVEALED_SEEDS.has(deps.storage, request.round)
+        || env.block.time.seconds() < request.created_at + REVEAL_TIMEOUT
+    {
+        Err(ContractError::RefundIsNotAvailable)?;
+    }
+
+    app_info.remove_pending_bet(request.fee);
+    let msg = get_payout_msg(
+        deps.storage,
+        &env,
+        &request.requester,
+        request.fee,
+        &config.denom,
+    )?;
+
+    RANDOMNESS_REQUESTS.remove(deps.storage, id);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessage(msg)
+        .add_attribute("action", "try_refund_randomness")
+        .add_event(Attrs::refund_randomness(id, &request).app_info(&app_info)))
+}
+
+pub fn try_distribute_revenue(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let recipients = REVENUE_SPLIT.may_load(deps.storage)?.unwrap_or_default();
+
+    if recipients.is_empty() {
+        Err(ContractError::RevenueSplitIsNotFound)?;
+    }
+
+    let revenue = calc_distributable_revenue(&app_info);
+    let mut amount = Uint128::zero();
+    let mut shares: Vec<(Addr, Uint128)> = vec![];
+    let mut msg_list: Vec<CosmosMsg> = vec![];
+
+    // rounding dust stays in the revenue
+    for recipient in recipients {
+        let address = Addr::unchecked(recipient.address);
+        let share = revenue.mul_floor(recipient.weight);
+
+        if share.is_zero() {
+            continue;
+        }
+
+        DISTRIBUTED_REVENUE.update(deps.storage, &address, |x| -> Result<_, ContractError> {
+            Ok(x.unwrap_or_default() + share)
+        })?;
+        msg_list.push(get_transfer_msg(
+            &address,
+            share,
+            &Token::new_native(&config.denom),
+        )?);
+        shares.push((address, share));
+        amount += share;
+    }
+
+    if amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    app_info.distribute_revenue(amount);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_messages(msg_list)
+        .add_attribute("action", "try_distribute_revenue")
+        .add_event(Attrs::distribute_revenue(&sender_address, amount, &shares).app_info(&app_info)))
+}
+
+pub fn try_receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // the sender is cw721 contract
+    let (collection, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let sender_address = deps.api.addr_validate(&msg.sender)?;
+
+    match from_json(&msg.msg)? {
+        ReceiveNftMsg::Flip { side } => try_flip_nft(
+            deps,
+            env,
+            sender_address,
+            side,
+            Nft {
+                collection: collection.to_string(),
+                token_id: msg.token_id,
+            },
+        ),
+    }
+}
+
+fn try_flip_nft(
+    deps: DepsMut,
+    env: Env,
+    sender_address: Addr,
+    side: Side,
+    nft: Nft,
+) -> Result<Response, ContractError> {
+    let mut response = Response::new().add_attribute("action", "try_flip_nft");
+    check_pause_state(deps.storage)?;
+    check_deferred_only(deps.storage)?;
+    check_contract_caller(deps.as_ref(), &sender_address)?;
+    let collection = Addr::unchecked(&nft.collection);
+    let block_time = env.block.time.seconds();
+    let normalized_decimal = NORMALIZED_DECIMAL.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let mut user = USERS
+        .load(deps.storage, &sender_address)
+        .unwrap_or_default();
+
+    // don't allow to flip multiple coins in single tx
+    if block_time < user.last_flip_date + FLIP_COOLDOWN {
+        Err(ContractError::MultipleFlipsPerTx)?;
+    }
+
+    let price = NFT_FLOOR_PRICES
+        .may_load(deps.storage, &collection)?
+        .ok_or(ContractError::NftCollectionIsNotWhitelisted)?;
+
+    let random_weight = get_random_weight(&env, &sender_address, &normalized_decimal)?;
+    let is_winner = side.is_winner(random_weight, config.platform_fee);
+    let prize = if is_winner { price } else { Uint128::zero() };
+    let payout = app_info.settle_nft_flip(prize);
+    user.settle_nft_flip(prize, &payout, block_time);
+
+    if is_winner {
+        response = response.add_message(get_nft_transfer_msg(
+            &collection,
+            &sender_address,
+            &nft.token_id,
+        )?);
+    } else {
+        HOUSE_NFTS.save(deps.storage, (&collection, &nft.token_id), &price)?;
+    }
+
+    if payout == Payout::AutoPaid {
+        response = response.add_submessage(get_payout_msg(
+            deps.storage,
+            &env,
+            &sender_address,
+            prize,
+            &config.denom,
+        )?);
+    }
+
+    if payout == Payout::Unclaimed {
+        enqueue_payout(deps.storage, &sender_address)?;
+    }
+
+    NORMALIZED_DECIMAL.save(deps.storage, &random_weight)?;
+    APP_INFO.save(deps.storage, &app_info)?;
+    USERS.save(deps.storage, &sender_address, &user)?;
+
+    let event = Attrs::nft_flip(&sender_address, &side, &nft, random_weight, &payout, prize)
+        .user_info(&user)
+        .app_info(&app_info);
+
+    Ok(response.add_attribute("prize", prize).add_event(event))
+}
+
+pub fn try_bonus_flip(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    side: Side,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let mut response = Response::new().add_attribute("action", "try_bonus_flip");
+    check_pause_state(deps.storage)?;
+    check_deferred_only(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    check_contract_caller(deps.as_ref(), &sender_address)?;
+    let block_time = env.block.time.seconds();
+    let normalized_decimal = NORMALIZED_DECIMAL.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let mut user = USERS
+        .load(deps.storage, &sender_address)
+        .unwrap_or_default();
+    let mut bonus = BONUS_BALANCES
+        .may_load(deps.storage, &sender_address)?
+        .unwrap_or_default();
+
+    if amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    let (_, tier) = load_user_tier(
+        deps.as_ref(),
+        &sender_address,
+        user.stats.bets.value,
+        block_time,
+    )?;
+    tier.bet.validate(amount)?;
+    bonus.wager(amount)?;
+
+    // don't allow to flip multiple coins in single tx
+    if block_time < user.last_flip_date + FLIP_COOLDOWN {
+        Err(ContractError::MultipleFlipsPerTx)?;
+    }
+
+    let random_weight = get_random_weight(&env, &sender_address, &normalized_decimal)?;
+    let is_winner = side.is_winner(random_weight, tier.get_fee(config.platform_fee));
+    let prize = if is_winner {
+        Uint128::new(2) * amount
+    } else {
+        Uint128::zero()
+    };
+    // the requirement includes the current stake
+    let is_locked = is_winner && bonus.is_locked();
+    let payout = app_info.settle_bonus_flip(amount, prize, is_locked);
+    let winnings = prize.saturating_sub(amount);
+    user.settle_bonus_flip(winnings, &payout, block_time);
+
+    if is_locked {
+        bonus.credit(prize, 0);
+    } else if is_winner {
+        bonus.credit(amount, 0);
+    }
+
+    if payout == Payout::AutoPaid {
+        response = response.add_submessage(get_payout_msg(
+            deps.storage,
+            &env,
+            &sender_address,
+            winnings,
+            &config.denom,
+        )?);
+    }
+
+    if payout == Payout::Unclaimed {
+        enqueue_payout(deps.storage, &sender_address)?;
+    }
+
+    NORMALIZED_DECIMAL.save(deps.storage, &random_weight)?;
+    APP_INFO.save(deps.storage, &app_info)?;
+    USERS.save(deps.storage, &sender_address, &user)?;
+    BONUS_BALANCES.save(deps.storage, &sender_address, &bonus)?;
+
+    let event = Attrs::bonus_flip(
+        &sender_address,
+        &side,
+        amount,
+        random_weight,
+        &payout,
+        winnings,
+        &bonus,
+    )
+    .locked(is_locked)
+    .user_info(&user)
+    .app_info(&app_info);
+
+    Ok(response.add_attribute("prize", winnings).add_event(event))
+}
+
+pub fn try_redeem_promo_code(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    code: String,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let hash = calc_sha256(code.as_bytes());
+    let mut promo = PROMO_CODES
+        .may_load(deps.storage, &hash)?
+        .ok_or(ContractError::PromoCodeIsNotFound)?;
+
+    if PROMO_REDEMPTIONS.has(deps.storage, (&hash, &sender_address)) {
+        Err(ContractError::PromoCodeIsRedeemed)?;
+    }
+
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let mut bonus = BONUS_BALANCES
+        .may_load(deps.storage, &sender_address)?
+        .unwrap_or_default();
+
+    bonus.credit(promo.amount, promo.wagering);
+    app_info.credit_bonus(promo.amount);
+    promo.redemptions -= 1;
+
+    if promo.redemptions == 0 {
+        PROMO_CODES.remove(deps.storage, &hash);
+    } else {
+        PROMO_CODES.save(deps.storage, &hash, &promo)?;
+    }
+
+    PROMO_REDEMPTIONS.save(
+        deps.storage,
+        (&hash, &sender_address),
+        &env.block.time.seconds(),
+    )?;
+    BONUS_BALANCES.save(deps.storage, &sender_address, &bonus)?;
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_redeem_promo_code")
+        .add_event(
+            Attrs::redeem_promo_code(&sender_address, &HexBinary::from(hash), &promo, &bonus)
+                .app_info(&app_info),
+        ))
+}
+
+pub fn try_top_up(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, asset_amount, asset_info) = check_funds(
+        deps.as_ref(),
+        &info,
+        FundsType::Single {
+            sender: None,
+            amount: None,
+        },
+    )?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+
+    if asset_info.try_get_native()? != config.denom {
+        Err(ContractError::WrongAssetType)?;
+    }
+
+    if asset_amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    let user_balance = credit_user_balance(deps.storage, &sender_address, asset_amount)?;
+    app_info.top_up(asset_amount);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_top_up")
+        .add_event(Attrs::top_up(&sender_address, asset_amount, user_balance).app_info(&app_info)))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn try_grant_session(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    session: String,
+    spend_cap: Uint128,
+    duration: u64,
+    side: Option<Side>,
+    bet: Option<Range>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let session_address = deps.api.addr_validate(&session)?;
+
+    if session_address == sender_address {
+        Err(ContractError::Unauthorized)?;
+    }
+
+    if spend_cap.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    if duration == 0 || duration > MAX_SESSION_DURATION {
+        Err(ContractError::ImproperSessionDuration)?;
+    }
+
+    if bet.as_ref().is_some_and(|x| x.min > x.max) {
+        Err(ContractError::ImproperMinBet)?;
+    }
+
+    let grant = SessionGrant {
+        spend_cap,
+        spent: Uint128::zero(),
+        expiration: env.block.time.seconds() + duration,
+        side,
+        bet,
+    };
+    SESSION_GRANTS.save(deps.storage, (&sender_address, &session_address), &grant)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_grant_session")
+        .add_event(Attrs::grant_session(
+            &sender_address,
+            &session_address,
+            &grant,
+        )))
+}
+
+pub fn try_revoke_session(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    session: String,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let session_address = deps.api.addr_validate(&session)?;
+    let key = (&sender_address, &session_address);
+
+    if !SESSION_GRANTS.has(deps.storage, key) {
+        Err(ContractError::SessionIsNotFound)?;
+    }
+
+    SESSION_GRANTS.remove(deps.storage, key);
+
+    Ok(Response::new()
+        .add_attribute("action", "try_revoke_session")
+        .add_event(Attrs::revoke_session(&sender_address, &session_address)))
+}
+
+pub fn try_withdraw_balance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let user_balance = USER_BALANCES
+        .may_load(deps.storage, &sender_address)?
+        .unwrap_or_default();
+    let amount = amount.unwrap_or(user_balance);
+
+    if amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    if amount > user_balance {
+        Err(ContractError::InsufficientUserBalance)?;
+    }
+
+    let user_balance = user_balance - amount;
+    USER_BALANCES.save(deps.storage, &sender_address, &user_balance)?;
+    app_info.withdraw_user_balance(amount);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessage(get_payout_msg(
+            deps.storage,
+            &env,
+            &sender_address,
+            amount,
+            &config.denom,
+        )?)
+        .add_attribute("action", "try_withdraw_balance")
+        .add_event(
+            Attrs::withdraw_balance(&sender_address, amount, user_balance).app_info(&app_info),
+        ))
+}
+
+pub fn try_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let mut user = USERS
+        .load(deps.storage, &sender_address)
+        .unwrap_or_default();
+
+    // check rewards
+    if user.unclaimed.is_zero() {
+        Err(ContractError::ZeroRewardsAmount)?;
+    }
+
+    // check app balance
+    if user.unclaimed > app_info.payable() {
+        Err(ContractError::NotEnoughLiquidity)?;
+    }
+
+    let msg = get_payout_msg(
+        deps.storage,
+        &env,
+        &sender_address,
+        user.unclaimed,
+        &config.denom,
+    )?;
+
+    let event = Attrs::claim(&sender_address, user.unclaimed);
+
+    app_info.claim(user.unclaimed);
+    user.unclaimed = Uint128::zero();
+
+    dequeue_payout(deps.storage, &sender_address)?;
+    APP_INFO.save(deps.storage, &app_info)?;
+    USERS.save(deps.storage, &sender_address, &user)?;
+
+    Ok(Response::new()
+        .add_submessage(msg)
+        .add_attribute("action", "try_claim")
+        .add_event(event.user_info(&user).app_info(&app_info)))
+}
+
+pub fn try_claim_rakeback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let block_time = env.block.time.seconds();
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    let user = USERS
+        .load(deps.storage, &sender_address)
+        .unwrap_or_default();
+
+    let (rakeback, rakeback_amount) =
+        load_rakeback(deps.storage, &sender_address, &user.stats, block_time)?;
+    let mut rakeback = rakeback.ok_or(ContractError::ZeroRewardsAmount)?;
+    if !rakeback_amount.is_zero() {
+        app_info.add_rakeback(rakeback_amount);
+    }
+
+    // check rewards
+    if rakeback.accrued.is_zero() {
+        Err(ContractError::ZeroRewardsAmount)?;
+    }
+
+    // check app balance
+    if rakeback.accrued > app_info.rakeback_payable() {
+        Err(ContractError::NotEnoughLiquidity)?;
+    }
+
+    let msg = get_payout_msg(
+        deps.storage,
+        &env,
+        &sender_address,
+        rakeback.accrued,
+        &config.denom,
+    )?;
+
+    let event = Attrs::claim_rakeback(&sender_address, rakeback.accrued).rakeback(rakeback_amount);
+
+    app_info.claim_rakeback(rakeback.accrued);
+    rakeback.accrued = Uint128::zero();
+
+    APP_INFO.save(deps.storage, &app_info)?;
+    RAKEBACK.save(deps.storage, &sender_address, &rakeback)?;
+
+    Ok(Response::new()
+        .add_submessage(msg)
+        .add_attribute("action", "try_claim_rakeback")
+        .add_event(event.app_info(&app_info)))
+}
+
+pub fn try_deposit(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let (sender_address, asset_amount, asset_info) = check_funds(
+        deps.as_ref(),
+        &info,
+        FundsType::Single {
+            sender: None,
+            amount: None,
+        },
+    )?;
+    let denom = asset_info.try_get_native()?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    if asset_amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    // check fund denom
+    if denom != config.denom {
+        Err(ContractError::WrongAssetType)?;
+    }
+
+    let mut app_info = APP_INFO.load(deps.storage)?;
+    app_info.deposit(asset_amount);
+
+    // the queue isn't processed while user actions are disabled
+    let payouts = if IS_PAUSED.load(deps.storage)? {
+        vec![]
+    } else {
+        process_payout_queue(deps.storage, &mut app_info, PAYOUT_BATCH)?
+    };
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessages(get_payout_msgs(
+            deps.storage,
+            &env,
+            &payouts,
+            &config.denom,
+        )?)
+        .add_attribute("action", "try_deposit")
+        .add_event(
+            Attrs::deposit(&sender_address, asset_amount)
+                .payouts(&payouts)
+                .app_info(&app_info),
+        ))
+}
+
+pub fn try_process_payouts(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    check_pause_state(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+
+    if PAYOUT_QUEUE.is_empty(deps.storage) {
+        Err(ContractError::PayoutQueueIsEmpty)?;
+    }
+
+    let payouts = process_payout_queue(deps.storage, &mut app_info, limit.min(MAX_PAYOUT_BATCH))?;
+
+    if payouts.is_empty() {
+        Err(ContractError::NotEnoughLiquidity)?;
+    }
+
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_submessages(get_payout_msgs(
+            deps.storage,
+            &env,
+            &payouts,
+            &config.denom,
+        )?)
+        .add_attribute("action", "try_process_payouts")
+        .add_event(Attrs::process_payouts(&sender_address, &payouts).app_info(&app_info)))
+}
+
+fn get_payout_msgs(
+    storage: &dyn Storage,
+    env: &Env,
+    payouts: &[(Addr, Uint128)],
+    denom: &str,
+) -> Result<Vec<SubMsg>, ContractError> {
+    payouts
+        .iter()
+        .map(|(address, amount)| get_payout_msg(storage, env, address, *amount, denom))
+        .collect()
+}
+
+pub fn try_withdraw(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut amount_to_send = Uint128::zero();
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    let app_info = APP_INFO.update(deps.storage, |mut x| -> Result<_, ContractError> {
+        amount_to_send =
+            amount.unwrap_or(calc_available_to_withdraw(x.deposited, x.revenue.current));
+
+        if amount_to_send.is_zero() {
+            Err(ContractError::ZeroAmount)?;
+        }
+
+        // don't allow to withdraw funds required to pay unclaimed rewards
+        if calc_available_to_withdraw(x.deposited, x.revenue.current) < amount_to_send {
+            Err(ContractError::NotEnoughLiquidity)?;
+        }
+
+        x.withdraw(amount_to_send);
+        Ok(x)
+    })?;
+
+    let recipient = recipient
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?
+        .unwrap_or(sender_address.to_owned());
+    let msg = get_transfer_msg(
+        &recipient,
+        amount_to_send,
+        &Token::new_native(&config.denom),
+    )?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "try_withdraw")
+        .add_event(
+            Attrs::withdraw(&sender_address, &recipient, amount_to_send).app_info(&app_info),
+        ))
+}
+
+pub fn try_deposit_insurance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let (sender_address, asset_amount, asset_info) = check_funds(
+        deps.as_ref(),
+        &info,
+        FundsType::Single {
+            sender: None,
+            amount: None,
+        },
+    )?;
+    let denom = asset_info.try_get_native()?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::AdminOrWorker,
+    )?;
+
+    if asset_amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    if denom != config.denom {
+        Err(ContractError::WrongAssetType)?;
+    }
+
+    let app_info = APP_INFO.update(deps.storage, |mut x| -> Result<_, ContractError> {
+        x.deposit_insurance(asset_amount);
+        Ok(x)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_deposit_insurance")
+        .add_event(Attrs::deposit_insurance(&sender_address, asset_amount).app_info(&app_info)))
+}
+
+pub fn try_withdraw_insurance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let insurance = INSURANCE_CONFIG.may_load(deps.storage)?.flatten();
+    let mut app_info = APP_INFO.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    let available_to_withdraw = calc_insurance_to_withdraw(&insurance, app_info.insurance);
+    let amount = amount.unwrap_or(available_to_withdraw);
+
+    if amount.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    if amount > available_to_withdraw {
+        Err(ContractError::NotEnoughLiquidity)?;
+    }
+
+    app_info.withdraw_insurance(amount);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    let recipient = recipient
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?
+        .unwrap_or(sender_address.to_owned());
+    let msg = get_transfer_msg(&recipient, amount, &Token::new_native(&config.denom))?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "try_withdraw_insurance")
+        .add_event(
+            Attrs::withdraw_insurance(&sender_address, &recipient, amount).app_info(&app_info),
+        ))
+}
+
+pub fn try_buyback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    belief_price: Option<Decimal>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::AdminOrWorker,
+    )?;
+
+    let buyback = BUYBACK_CONFIG
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::BuybackIsDisabled)?;
+    let amount_in = calc_distributable_revenue(&app_info).mul_floor(buyback.share);
+
+    if amount_in.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    app_info.distribute_revenue(amount_in);
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    // the tokens are burned in the reply
+    let token = buyback.token.verify(deps.api)?;
+    let balance = query_token_balance(deps.querier, &env.contract.address, &token)?;
+    let id = BUYBACKS
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |x| x + 1);
+
+    BUYBACK_BALANCE.save(deps.storage, &balance)?;
+    BUYBACKS.save(
+        deps.storage,
+        id,
+        &Buyback {
+            time: env.block.time.seconds(),
+            token: buyback.token,
+            amount_in,
+            amount_out: Uint128::zero(),
+        },
+    )?;
+
+    let msg = SubMsg::reply_on_success(
+        WasmMsg::Execute {
+            contract_addr: buyback.pair.to_owned(),
+            msg: to_json_binary(&PairExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: config.denom.to_owned(),
+                    },
+                    amount: amount_in,
+                },
+                ask_asset_info: Some(token.into()),
+                belief_price,
+                max_spread,
+                to: None,
+            })?,
+            funds: coins(amount_in.u128(), &config.denom),
+        },
+        BUYBACK_REPLY_ID,
+    );
+
+    Ok(Response::new()
+        .add_submessage(msg)
+        .add_attribute("action", "try_buyback")
+        .add_event(Attrs::buyback(&sender_address, &buyback.pair, amount_in).app_info(&app_info)))
+}
+
+pub fn try_enqueue_payouts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    start_after: Option<String>,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::AdminOrWorker,
+    )?;
+
+    let binding;
+    let start_bound = match start_after {
+        Some(addr) => {
+            binding = deps.api.addr_validate(&addr)?;
+            Some(Bound::exclusive(&binding))
+        }
+        None => None,
+    };
+    let user_list = USERS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit.min(MAX_PAYOUT_BATCH) as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    // the next page starts after the last scanned user
+    let last = user_list.last().map(|(address, _)| address.to_owned());
+    let mut enqueued: u32 = 0;
+
+    for (address, user) in user_list {
+        if !user.unclaimed.is_zero() && !PAYOUT_QUEUE_IDS.has(deps.storage, &address) {
+            enqueue_payout(deps.storage, &address)?;
+            enqueued += 1;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "try_enqueue_payouts")
+        .add_event(Attrs::enqueue_payouts(&sender_address, enqueued, &last)))
+}
+
+pub fn try_accept_admin_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let block_time = env.block.time.seconds();
+    let TransferAdminState {
+        new_admin,
+        deadline,
+    } = TRANSFER_ADMIN_STATE.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Specified {
+            allowlist: vec![Some(new_admin)],
+        },
+    )?;
+
+    if block_time >= deadline {
+        Err(ContractError::TransferAdminDeadline)?;
+    }
+
+    CONFIG.update(deps.storage, |mut x| -> Result<_, ContractError> {
+        x.admin = sender_address.to_owned();
+        Ok(x)
+    })?;
+
+    TRANSFER_ADMIN_STATE.update(deps.storage, |mut x| -> Result<_, ContractError> {
+        x.deadline = block_time;
+        Ok(x)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_accept_admin_role")
+        .add_event(Attrs::accept_admin_role(&sender_address)))
+}
+
+pub fn try_commit_seed_chain(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    head: HexBinary,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::AdminOrWorker,
+    )?;
+
+    if head.len() != ENC_KEY_LEN {
+        Err(ContractError::ImproperSeed)?;
+    }
+
+    let round = match SEED_CHAIN.may_load(deps.storage)?.flatten() {
+        Some(seed_chain) => {
+            if seed_chain.height == env.block.height {
+                Err(ContractError::SeedIsRevealedInBlock)?;
+            }
+
+            // flips and randomness requests are bound to the rounds of the current chain,
+            // they must be settled first
+            let last_flip = PENDING_FLIPS
+                .range(deps.storage, None, None, Order::Descending)
+                .next()
+                .transpose()?;
+            let last_request = RANDOMNESS_REQUESTS
+                .range(deps.storage, None, None, Order::Descending)
+                .next()
+                .transpose()?;
+            if matches!(last_flip, Some((_, x)) if x.round > seed_chain.round)
+                || matches!(last_request, Some((_, x)) if x.round > seed_chain.round)
+            {
+                Err(ContractError::PendingFlipsExist)?;
+            }
+
+            seed_chain.round
+        }
+        None => 0,
+    };
+
+    let seed_chain = SeedChain {
+        head,
+        round,
+        height: env.block.height,
+    };
+    SEED_CHAIN.save(deps.storage, &Some(seed_chain.clone()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_commit_seed_chain")
+        .add_event(Attrs::commit_seed_chain(&sender_address, &seed_chain)))
+}
+
+pub fn try_reveal_seed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    seed: HexBinary,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::AdminOrWorker,
+    )?;
+
+    let mut seed_chain = SEED_CHAIN
+        .may_load(deps.storage)?
+        .flatten()
+        .ok_or(ContractError::SeedChainIsNotFound)?;
+
+    // a single reveal per block keeps flips of the block bound to unknown seed
+    if seed_chain.height == env.block.height {
+        Err(ContractError::SeedIsRevealedInBlock)?;
+    }
+
+    if calc_sha256(seed.as_slice()).as_slice() != seed_chain.head.as_slice() {
+        Err(ContractError::ImproperSeed)?;
+    }
+
+    seed_chain.head = seed.clone();
+    seed_chain.round += 1;
+    seed_chain.height = env.block.height;
+    SEED_CHAIN.save(deps.storage, &Some(seed_chain.clone()))?;
+    REVEALED_SEEDS.save(deps.storage, seed_chain.round, &seed)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_reveal_seed")
+        .add_event(Attrs::reveal_seed(&sender_address, &seed_chain)))
+}
+
+pub fn try_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    admin: Option<String>,
+    worker: Option<String>,
+    bet: Option<Range>,
+    platform_fee: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut is_config_updated = false;
+    let mut new_admin: Option<Addr> = None;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    if let Some(x) = admin {
+        let block_time = env.block.time.seconds();
+        let address = deps.api.addr_validate(&x)?;
+
+        TRANSFER_ADMIN_STATE.save(
+            deps.storage,
+            &TransferAdminState {
+                new_admin: address.to_owned(),
+                deadline: block_time + TRANSFER_ADMIN_TIMEOUT,
+            },
+        )?;
+
+        new_admin = Some(address);
+
+        is_config_updated = true;
+    }
+
+    if let Some(x) = worker {
+        config.worker = Some(deps.api.addr_validate(&x)?);
+        is_config_updated = true;
+    }
+
+    if let Some(x) = bet {
+        if x.min > x.max {
+            Err(ContractError::ImproperMinBet)?;
+        }
+
+        if x.max.is_zero() {
+            Err(ContractError::ZeroMaxBet)?;
+        }
+
+        config.bet = x;
+        is_config_updated = true;
+    }
+
+    if let Some(x) = platform_fee {
+        if x > Decimal::one() {
+            Err(ContractError::FeeIsOutOfRange)?;
+        }
+
+        config.platform_fee = x;
+        is_config_updated = true;
+    }
+
+    // don't allow empty messages
+    if !is_config_updated {
+        Err(ContractError::NoParameters)?;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_update_config")
+        .add_event(Attrs::update_config(&sender_address, &config, &new_admin)))
+}
+
+pub fn try_update_tiers(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tiers: Vec<Tier>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    check_tiers(&tiers)?;
+    TIERS.save(deps.storage, &tiers)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_update_tiers")
+        .add_event(Attrs::update_tiers(&sender_address, &tiers)))
+}
+
+pub fn try_set_user_tier(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    tier: Option<u8>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let user_address = deps.api.addr_validate(&address)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    match tier {
+        Some(x) => {
+            let tiers = TIERS.may_load(deps.storage)?.unwrap_or_default();
+
+            if x as usize >= tiers.len() {
+                Err(ContractError::TierIsNotFound)?;
+            }
+
+            ASSIGNED_TIERS.save(deps.storage, &user_address, &x)?;
+        }
+        None => ASSIGNED_TIERS.remove(deps.storage, &user_address),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "try_set_user_tier")
+        .add_event(Attrs::set_user_tier(&sender_address, &user_address, tier)))
+}
+
+pub fn try_update_rakeback(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    rakeback: Option<RakebackConfig>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    if let Some(x) = &rakeback {
+        if x.rate > Decimal::one() {
+            Err(ContractError::RakebackRateIsOutOfRange)?;
+        }
+
+        if x.epoch == 0 {
+            Err(ContractError::ZeroRakebackEpoch)?;
+        }
+    }
+
+    RAKEBACK_CONFIG.save(deps.storage, &rakeback)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_update_rakeback")
+        .add_event(Attrs::update_rakeback(&sender_address, &rakeback)))
+}
+
+pub fn try_update_insurance(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    insurance: Option<InsuranceConfig>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    if let Some(x) = &insurance {
+        if x.rate > Decimal::one() {
+            Err(ContractError::InsuranceRateIsOutOfRange)?;
+        }
+
+        if x.target.is_zero() {
+            Err(ContractError::ZeroAmount)?;
+        }
+    }
+
+    INSURANCE_CONFIG.save(deps.storage, &insurance)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_update_insurance")
+        .add_event(Attrs::update_insurance(&sender_address, &insurance)))
+}
+
+pub fn try_update_contract_callers(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    whitelist: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    let whitelist = whitelist
+        .map(|x| {
+            x.iter()
+                .map(|address| deps.api.addr_validate(address))
+                .collect::<StdResult<Vec<Addr>>>()
+        })
+        .transpose()?;
+    CONTRACT_CALLERS.save(deps.storage, &whitelist)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_update_contract_callers")
+        .add_event(Attrs::update_contract_callers(&sender_address, &whitelist)))
+}
+
+pub fn try_update_deferred_only(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    DEFERRED_ONLY.save(deps.storage, &enabled)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_update_deferred_only")
+        .add_event(Attrs::update_deferred_only(&sender_address, enabled)))
+}
+
+pub fn try_update_randomness_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    fee: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    let mut response = Response::new().add_attribute("action", "try_flip");
-    check_pause_state(deps.storage)?;
-    let (sender_address, asset_amount, asset_info) = check_funds(
-        deps.as_ref(),
-        &info,
-        FundsType::Single {
-            sender: None,
-            amount: None,
-        },
-    )?;
-    let block_time = env.block.time.seconds();
-    let normalized_decimal = NORMALIZED_DECIMAL.load(deps.storage)?;
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     let config = CONFIG.load(deps.storage)?;
-    let mut app_info = APP_INFO.load(deps.storage)?;
-    let mut user = USERS
-        .load(deps.storage, &sender_address)
-        .unwrap_or_default();
 
-    // don't allow to flip multiple coins in single tx
-    if block_time < user.last_flip_date + FLIP_COOLDOWN {
-        Err(ContractError::MultipleFlipsPerTx)?;
-    }
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
 
-    // check fund amount
-    if asset_amount.is_zero() {
+    if fee.is_some_and(|x| x.is_zero()) {
         Err(ContractError::ZeroAmount)?;
     }
 
-    config.bet.validate(asset_amount)?;
+    RANDOMNESS_FEE.save(deps.storage, &fee)?;
 
-    // check fund denom
-    if asset_info.try_get_native()? != config.denom {
-        Err(ContractError::WrongAssetType)?;
-    }
+    Ok(Response::new()
+        .add_attribute("action", "try_update_randomness_fee")
+        .add_event(Attrs::update_randomness_fee(&sender_address, &fee)))
+}
 
-    let random_weight = get_random_weight(&env, &sender_address, &normalized_decimal)?;
-    let is_winner = side.is_winner(random_weight, config.platform_fee);
-    let prize = if is_winner {
-        Uint128::new(2) * asset_amount
-    } else {
-        Uint128::zero()
-    };
+pub fn try_update_nft_perk(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection: String,
+    perk: Option<NftPerk>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let collection = deps.api.addr_validate(&collection)?;
 
-    app_info.revenue.total += Int256::from(asset_amount);
-    app_info.revenue.current += Int256::from(asset_amount);
-    app_info.balance += asset_amount;
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
 
-    if is_winner {
-        app_info.revenue.total -= Int256::from(prize);
-        app_info.revenue.current -= Int256::from(prize);
-
-        if app_info.balance >= prize {
-            app_info.balance -= prize;
-            response = response.add_message(get_transfer_msg(&sender_address, prize, &asset_info)?);
-        } else {
-            app_info.user_unclaimed += prize;
-            user.unclaimed += prize;
+    match &perk {
+        Some(x) => {
+            if x.fee_discount > Decimal::one() {
+                Err(ContractError::NftDiscountIsOutOfRange)?;
+            }
+
+            if x.bet_max.is_some_and(|y| y.is_zero()) {
+                Err(ContractError::ZeroMaxBet)?;
+            }
+
+            if !NFT_PERKS.has(deps.storage, &collection)
+                && NFT_PERKS
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .count()
+                    >= MAX_NFT_COLLECTIONS
+            {
+                Err(ContractError::NftCollectionLimit)?;
+            }
+
+            NFT_PERKS.save(deps.storage, &collection, x)?;
         }
+        None => {
+            if !NFT_PERKS.has(deps.storage, &collection) {
+                Err(ContractError::NftCollectionIsNotFound)?;
+            }
 
-        app_info.user_stats.wins.increase(prize);
-        user.stats.wins.increase(prize);
+            NFT_PERKS.remove(deps.storage, &collection);
+        }
     }
 
-    app_info.user_stats.bets.increase(asset_amount);
-    app_info.update_average_fee();
-
-    user.stats.bets.increase(asset_amount);
-    user.update_roi();
-    user.last_flip_date = block_time;
-
-    NORMALIZED_DECIMAL.save(deps.storage, &random_weight)?;
-    APP_INFO.save(deps.storage, &app_info)?;
-    USERS.save(deps.storage, &sender_address, &user)?;
-
-    Ok(response.add_attribute("prize", prize))
+    Ok(Response::new()
+        .add_attribute("action", "try_update_nft_perk")
+        .add_event(Attrs::update_nft_perk(&sender_address, &collection, &perk)))
 }
 
-pub fn try_claim(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    check_pause_state(deps.storage)?;
+pub fn try_update_revenue_split(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    recipients: Vec<RevenueRecipient>,
+) -> Result<Response, ContractError> {
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     let config = CONFIG.load(deps.storage)?;
-    let mut app_info = APP_INFO.load(deps.storage)?;
-    let mut user = USERS
-        .load(deps.storage, &sender_address)
-        .unwrap_or_default();
-
-    // check rewards
-    if user.unclaimed.is_zero() {
-        Err(ContractError::ZeroRewardsAmount)?;
-    }
-
-    // check app balance
-    if user.unclaimed > app_info.balance {
-        Err(ContractError::NotEnoughLiquidity)?;
-    }
 
-    let msg = get_transfer_msg(
+    check_authorization(
         &sender_address,
-        user.unclaimed,
-        &Token::new_native(&config.denom),
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
     )?;
 
-    app_info.balance -= user.unclaimed;
-    app_info.user_unclaimed -= user.unclaimed;
-    user.unclaimed = Uint128::zero();
-
-    APP_INFO.save(deps.storage, &app_info)?;
-    USERS.save(deps.storage, &sender_address, &user)?;
+    let recipients = check_revenue_split(deps.api, &recipients)?;
+    REVENUE_SPLIT.save(deps.storage, &recipients)?;
 
     Ok(Response::new()
-        .add_message(msg)
-        .add_attribute("action", "try_claim"))
+        .add_attribute("action", "try_update_revenue_split")
+        .add_event(Attrs::update_revenue_split(&sender_address, &recipients)))
 }
 
-pub fn try_deposit(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    let (sender_address, asset_amount, asset_info) = check_funds(
-        deps.as_ref(),
-        &info,
-        FundsType::Single {
-            sender: None,
-            amount: None,
-        },
-    )?;
-    let denom = asset_info.try_get_native()?;
+pub fn try_update_buyback(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    buyback: Option<BuybackConfig>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     let config = CONFIG.load(deps.storage)?;
 
     check_authorization(
@@ -154,34 +2066,43 @@ pub fn try_deposit(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Respon
         AuthType::Admin,
     )?;
 
-    if asset_amount.is_zero() {
-        Err(ContractError::ZeroAmount)?;
-    }
+    let buyback = match buyback {
+        Some(x) => {
+            let pair = deps.api.addr_validate(&x.pair)?;
+            let token = x.token.verify(deps.api)?;
 
-    // check fund denom
-    if denom != config.denom {
-        Err(ContractError::WrongAssetType)?;
-    }
+            if token.try_get_native().is_ok_and(|y| y == config.denom) {
+                Err(ContractError::ImproperBuybackToken)?;
+            }
 
-    APP_INFO.update(deps.storage, |mut x| -> StdResult<_> {
-        x.deposited += asset_amount;
-        x.balance += asset_amount;
-        Ok(x)
-    })?;
+            if x.share.is_zero() || x.share > Decimal::one() {
+                Err(ContractError::BuybackShareIsOutOfRange)?;
+            }
+
+            Some(BuybackConfig {
+                pair: pair.to_string(),
+                token: token.into(),
+                share: x.share,
+            })
+        }
+        None => None,
+    };
+
+    BUYBACK_CONFIG.save(deps.storage, &buyback)?;
 
-    Ok(Response::new().add_attribute("action", "try_deposit"))
+    Ok(Response::new()
+        .add_attribute("action", "try_update_buyback")
+        .add_event(Attrs::update_buyback(&sender_address, &buyback)))
 }
 
-pub fn try_withdraw(
+pub fn try_update_oracle(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    amount: Option<Uint128>,
-    recipient: Option<String>,
+    oracle: Option<OracleConfig>,
 ) -> Result<Response, ContractError> {
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     let config = CONFIG.load(deps.storage)?;
-    let mut amount_to_send = Uint128::zero();
 
     check_authorization(
         &sender_address,
@@ -190,101 +2111,93 @@ pub fn try_withdraw(
         AuthType::Admin,
     )?;
 
-    APP_INFO.update(deps.storage, |mut x| -> StdResult<_> {
-        amount_to_send =
-            amount.unwrap_or(calc_available_to_withdraw(x.deposited, x.revenue.current));
+    let oracle = match oracle {
+        Some(x) => {
+            let contract = deps.api.addr_validate(&x.contract)?;
 
-        if amount_to_send.is_zero() {
-            Err(ContractError::ZeroAmount)?;
-        }
+            if x.bet.min > x.bet.max {
+                Err(ContractError::ImproperMinBet)?;
+            }
 
-        // don't allow to withdraw funds required to pay unclaimed rewards
-        if calc_available_to_withdraw(x.deposited, x.revenue.current) < amount_to_send {
-            Err(ContractError::NotEnoughLiquidity)?;
-        }
-        x.balance -= amount_to_send;
+            if x.bet.max.is_zero() {
+                Err(ContractError::ZeroMaxBet)?;
+            }
 
-        // withdraw deposited first
-        if x.deposited >= amount_to_send {
-            x.deposited -= amount_to_send;
-        } else {
-            let diff = Int256::from(amount_to_send - x.deposited);
-            x.deposited = Uint128::zero();
+            if x.max_age == 0 {
+                Err(ContractError::ImproperOracleMaxAge)?;
+            }
 
-            // then withdraw current revenue
-            x.revenue.current -= diff;
+            Some(OracleConfig {
+                contract: contract.to_string(),
+                ..x
+            })
         }
+        None => None,
+    };
 
-        Ok(x)
-    })?;
-
-    let recipient = recipient
-        .map(|x| deps.api.addr_validate(&x))
-        .transpose()?
-        .unwrap_or(sender_address);
-    let msg = get_transfer_msg(
-        &recipient,
-        amount_to_send,
-        &Token::new_native(&config.denom),
-    )?;
+    ORACLE_CONFIG.save(deps.storage, &oracle)?;
 
     Ok(Response::new()
-        .add_message(msg)
-        .add_attribute("action", "try_withdraw"))
+        .add_attribute("action", "try_update_oracle")
+        .add_event(Attrs::update_oracle(&sender_address, &oracle)))
 }
 
-pub fn try_accept_admin_role(
+pub fn try_credit_bonus(
     deps: DepsMut,
-    env: Env,
+    _env: Env,
     info: MessageInfo,
+    credits: Vec<BonusCredit>,
+    wagering: u32,
 ) -> Result<Response, ContractError> {
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
     let config = CONFIG.load(deps.storage)?;
-    let block_time = env.block.time.seconds();
-    let TransferAdminState {
-        new_admin,
-        deadline,
-    } = TRANSFER_ADMIN_STATE.load(deps.storage)?;
+    let mut app_info = APP_INFO.load(deps.storage)?;
 
     check_authorization(
         &sender_address,
         &config.admin,
         &config.worker,
-        AuthType::Specified {
-            allowlist: vec![Some(new_admin)],
-        },
+        AuthType::Admin,
     )?;
 
-    if block_time >= deadline {
-        Err(ContractError::TransferAdminDeadline)?;
+    if credits.len() > MAX_BONUS_CREDITS {
+        Err(ContractError::BonusCreditLimit)?;
     }
 
-    CONFIG.update(deps.storage, |mut x| -> StdResult<_> {
-        x.admin = sender_address;
-        Ok(x)
-    })?;
+    let mut credited: Vec<(Addr, Uint128)> = vec![];
 
-    TRANSFER_ADMIN_STATE.update(deps.storage, |mut x| -> StdResult<_> {
-        x.deadline = block_time;
-        Ok(x)
-    })?;
+    for credit in credits {
+        let address = deps.api.addr_validate(&credit.address)?;
+
+        if credit.amount.is_zero() {
+            Err(ContractError::ZeroAmount)?;
+        }
+
+        let mut bonus = BONUS_BALANCES
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default();
+        bonus.credit(credit.amount, wagering);
+        BONUS_BALANCES.save(deps.storage, &address, &bonus)?;
+        app_info.credit_bonus(credit.amount);
+        credited.push((address, credit.amount));
+    }
 
-    Ok(Response::new().add_attribute("action", "try_accept_admin_role"))
+    APP_INFO.save(deps.storage, &app_info)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "try_credit_bonus")
+        .add_event(Attrs::credit_bonus(&sender_address, wagering, &credited).app_info(&app_info)))
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn try_update_config(
+pub fn try_update_promo_code(
     deps: DepsMut,
-    env: Env,
+    _env: Env,
     info: MessageInfo,
-    admin: Option<String>,
-    worker: Option<String>,
-    bet: Option<Range>,
-    platform_fee: Option<Decimal>,
+    hash: HexBinary,
+    promo: Option<PromoCode>,
 ) -> Result<Response, ContractError> {
     let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
-    let mut config = CONFIG.load(deps.storage)?;
-    let mut is_config_updated = false;
+    let config = CONFIG.load(deps.storage)?;
 
     check_authorization(
         &sender_address,
@@ -293,56 +2206,120 @@ pub fn try_update_config(
         AuthType::Admin,
     )?;
 
-    if let Some(x) = admin {
-        let block_time = env.block.time.seconds();
-        let new_admin = &deps.api.addr_validate(&x)?;
+    if hash.len() != ENC_KEY_LEN {
+        Err(ContractError::ImproperPromoCodeHash)?;
+    }
 
-        TRANSFER_ADMIN_STATE.save(
-            deps.storage,
-            &TransferAdminState {
-                new_admin: new_admin.to_owned(),
-                deadline: block_time + TRANSFER_ADMIN_TIMEOUT,
-            },
-        )?;
+    match &promo {
+        Some(x) => {
+            if x.amount.is_zero() || x.redemptions == 0 {
+                Err(ContractError::ZeroAmount)?;
+            }
 
-        is_config_updated = true;
+            PROMO_CODES.save(deps.storage, hash.as_slice(), x)?;
+        }
+        None => PROMO_CODES.remove(deps.storage, hash.as_slice()),
     }
 
-    if let Some(x) = worker {
-        config.worker = Some(deps.api.addr_validate(&x)?);
-        is_config_updated = true;
-    }
+    Ok(Response::new()
+        .add_attribute("action", "try_update_promo_code")
+        .add_event(Attrs::update_promo_code(&sender_address, &hash, &promo)))
+}
 
-    if let Some(x) = bet {
-        if x.min > x.max {
-            Err(ContractError::ImproperMinBet)?;
-        }
+pub fn try_update_nft_floor_price(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection: String,
+    price: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let collection = deps.api.addr_validate(&collection)?;
+    let is_whitelisted = NFT_FLOOR_PRICES.has(deps.storage, &collection);
 
-        if x.max.is_zero() {
-            Err(ContractError::ZeroMaxBet)?;
+    // the worker can only update prices of whitelisted collections
+    let auth_type = if is_whitelisted && price.is_some() {
+        AuthType::AdminOrWorker
+    } else {
+        AuthType::Admin
+    };
+    check_authorization(&sender_address, &config.admin, &config.worker, auth_type)?;
+
+    match price {
+        Some(x) => {
+            if x.is_zero() {
+                Err(ContractError::ZeroAmount)?;
+            }
+
+            if !is_whitelisted
+                && NFT_FLOOR_PRICES
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .count()
+                    >= MAX_NFT_COLLECTIONS
+            {
+                Err(ContractError::NftCollectionLimit)?;
+            }
+
+            NFT_FLOOR_PRICES.save(deps.storage, &collection, &x)?;
         }
+        None => {
+            if !is_whitelisted {
+                Err(ContractError::NftCollectionIsNotWhitelisted)?;
+            }
 
-        config.bet = x;
-        is_config_updated = true;
+            NFT_FLOOR_PRICES.remove(deps.storage, &collection);
+        }
     }
 
-    if let Some(x) = platform_fee {
-        if x > Decimal::one() {
-            Err(ContractError::FeeIsOutOfRange)?;
-        }
+    Ok(Response::new()
+        .add_attribute("action", "try_update_nft_floor_price")
+        .add_event(Attrs::update_nft_floor_price(
+            &sender_address,
+            &collection,
+            price,
+        )))
+}
 
-        config.platform_fee = x;
-        is_config_updated = true;
-    }
+pub fn try_withdraw_nft(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection: String,
+    token_id: String,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let (sender_address, ..) = check_funds(deps.as_ref(), &info, FundsType::Empty)?;
+    let config = CONFIG.load(deps.storage)?;
+    let collection = deps.api.addr_validate(&collection)?;
 
-    // don't allow empty messages
-    if !is_config_updated {
-        Err(ContractError::NoParameters)?;
+    check_authorization(
+        &sender_address,
+        &config.admin,
+        &config.worker,
+        AuthType::Admin,
+    )?;
+
+    if !HOUSE_NFTS.has(deps.storage, (&collection, &token_id)) {
+        Err(ContractError::HouseNftIsNotFound)?;
     }
 
-    CONFIG.save(deps.storage, &config)?;
+    HOUSE_NFTS.remove(deps.storage, (&collection, &token_id));
+
+    let recipient = recipient
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?
+        .unwrap_or(sender_address.to_owned());
+    let msg = get_nft_transfer_msg(&collection, &recipient, &token_id)?;
+    let nft = Nft {
+        collection: collection.to_string(),
+        token_id,
+    };
 
-    Ok(Response::new().add_attribute("action", "try_update_config"))
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "try_withdraw_nft")
+        .add_event(Attrs::withdraw_nft(&sender_address, &recipient, &nft)))
 }
 
 pub fn try_pause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
@@ -352,7 +2329,9 @@ pub fn try_pause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
 
     IS_PAUSED.save(deps.storage, &true)?;
 
-    Ok(Response::new().add_attribute("action", "try_pause"))
+    Ok(Response::new()
+        .add_attribute("action", "try_pause")
+        .add_event(Attrs::pause(&sender_address)))
 }
 
 pub fn try_unpause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
@@ -362,5 +2341,7 @@ pub fn try_unpause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Respon
 
     IS_PAUSED.save(deps.storage, &false)?;
 
-    Ok(Response::new().add_attribute("action", "try_unpause"))
+    Ok(Response::new()
+        .add_attribute("action", "try_unpause")
+        .add_event(Attrs::unpause(&sender_address)))
 }
@@ -7,11 +7,12 @@ use cf_base::{
     platform::{
         msg::InstantiateMsg,
         state::{
-            APP_INFO, BET_MAX, BET_MIN, CONFIG, CONTRACT_NAME, DENOM, IS_PAUSED,
+            APP_INFO, BET_MAX, BET_MIN, CONFIG, CONTRACT_CALLERS, CONTRACT_NAME, DENOM, IS_PAUSED,
             NORMALIZED_DECIMAL, PLATFORM_FEE, SEED, TRANSFER_ADMIN_STATE,
         },
         types::{AppInfo, Config, Range, TransferAdminState},
     },
+    utils::Attrs,
 };
 
 use crate::helpers::get_random_weight;
@@ -38,26 +39,27 @@ pub fn try_instantiate(
         },
     )?;
 
-    CONFIG.save(
-        deps.storage,
-        &Config {
-            admin: sender.to_owned(),
-            worker: msg
-                .worker
-                .map(|x| deps.api.addr_validate(&x))
-                .transpose()
-                .unwrap_or(Some(sender.to_owned())),
-            bet: msg.bet.unwrap_or(Range::new(BET_MIN, BET_MAX)),
-            denom: String::from(DENOM),
-            platform_fee: msg.platform_fee.unwrap_or(str_to_dec(PLATFORM_FEE)),
-        },
-    )?;
+    let config = Config {
+        admin: sender.to_owned(),
+        worker: msg
+            .worker
+            .map(|x| deps.api.addr_validate(&x))
+            .transpose()
+            .unwrap_or(Some(sender.to_owned())),
+        bet: msg.bet.unwrap_or(Range::new(BET_MIN, BET_MAX)),
+        denom: String::from(DENOM),
+        platform_fee: msg.platform_fee.unwrap_or(str_to_dec(PLATFORM_FEE)),
+    };
+    CONFIG.save(deps.storage, &config)?;
 
     NORMALIZED_DECIMAL.save(
         deps.storage,
         &get_random_weight(&env, sender, &str_to_dec(SEED))?,
     )?;
     APP_INFO.save(deps.storage, &AppInfo::default())?;
+    CONTRACT_CALLERS.save(deps.storage, &Some(vec![]))?;
 
-    Ok(Response::new().add_attribute("action", "try_instantiate"))
+    Ok(Response::new()
+        .add_attribute("action", "try_instantiate")
+        .add_event(Attrs::instantiate(&config)))
 }
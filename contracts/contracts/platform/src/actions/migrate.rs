@@ -5,7 +5,10 @@ use semver::Version;
 
 use cf_base::{
     error::ContractError,
-    platform::{msg::MigrateMsg, state::CONTRACT_NAME},
+    platform::{
+        msg::MigrateMsg,
+        state::{CONTRACT_CALLERS, CONTRACT_NAME},
+    },
 };
 
 pub fn migrate_contract(
@@ -19,6 +22,11 @@ pub fn migrate_contract(
         set_contract_version(deps.storage, CONTRACT_NAME, version_new.to_string())?;
     }
 
+    // contracts are rejected by default
+    if !CONTRACT_CALLERS.exists(deps.storage) {
+        CONTRACT_CALLERS.save(deps.storage, &Some(vec![]))?;
+    }
+
     Ok(Response::new())
 }
 
@@ -1,13 +1,31 @@
-use cosmwasm_std::{Deps, Env, Order, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal, Deps, Env, HexBinary, Order, StdResult, Uint128};
 use cw_storage_plus::Bound;
 
 use cf_base::platform::{
-    msg::UserListRespItem,
-    state::{APP_INFO, CONFIG, USERS},
-    types::{AppInfo, Config, UserInfo},
+    msg::{
+        BetRangeResp, BuybackListRespItem, HouseNftRespItem, InsuranceResp, NftFloorPriceRespItem,
+        NftPerksRespItem, PayoutQueuePositionResp, PendingFlipsRespItem,
+        RandomnessRequestsRespItem, RevenueSplitRespItem, SessionGrantsRespItem, TierProgress,
+        UserListRespItem, UserRakebackResp, UserTierResp,
+    },
+    state::{
+        APP_INFO, ASSIGNED_TIERS, BONUS_BALANCES, BUYBACKS, BUYBACK_CONFIG, CONFIG,
+        CONTRACT_CALLERS, DEFERRED_ONLY, DISTRIBUTED_REVENUE, HOUSE_NFTS, IBC_SENDERS,
+        INSURANCE_CONFIG, NFT_FLOOR_PRICES, NFT_PERKS, ORACLE_CONFIG, PAYOUT_QUEUE,
+        PAYOUT_QUEUE_IDS, PAYOUT_QUEUE_LENGTH, PENDING_FLIPS, PROMO_CODES, RAKEBACK_CONFIG,
+        RANDOMNESS_FEE, RANDOMNESS_REQUESTS, REVEALED_SEEDS, REVENUE_SPLIT, SEED_CHAIN,
+        SESSION_GRANTS, SIGNED_NONCES, TIERS, USERS, USER_BALANCES,
+    },
+    types::{
+        AppInfo, BonusInfo, BuybackConfig, Config, IbcTarget, Nft, OracleConfig, PromoCode,
+        RakebackConfig, SeedChain, Tier, UserInfo,
+    },
 };
 
-use crate::helpers::{calc_available_to_withdraw, calc_required_to_deposit};
+use crate::helpers::{
+    calc_available_to_withdraw, calc_insurance_to_withdraw, calc_required_to_deposit,
+    get_reached_level, load_bet_range, load_rakeback, load_user_tier,
+};
 
 pub fn query_config(deps: Deps, _env: Env) -> StdResult<Config> {
     CONFIG.load(deps.storage)
@@ -19,7 +37,10 @@ pub fn query_app_info(deps: Deps, _env: Env) -> StdResult<AppInfo> {
 
 pub fn query_required_to_deposit(deps: Deps, _env: Env) -> StdResult<Uint128> {
     let x = APP_INFO.load(deps.storage)?;
-    Ok(calc_required_to_deposit(x.balance, x.user_unclaimed))
+    Ok(calc_required_to_deposit(
+        x.balance,
+        x.user_unclaimed + x.user_rakeback + x.user_balances,
+    ))
 }
 
 pub fn query_available_to_withdraw(deps: Deps, _env: Env) -> StdResult<Uint128> {
@@ -57,3 +78,313 @@ pub fn query_user_list(
         })
         .collect())
 }
+
+pub fn query_tiers(deps: Deps, _env: Env) -> StdResult<Vec<Tier>> {
+    Ok(TIERS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_user_tier(deps: Deps, env: Env, address: String) -> StdResult<UserTierResp> {
+    let address = deps.api.addr_validate(&address)?;
+    let bets_value = USERS
+        .load(deps.storage, &address)
+        .unwrap_or_default()
+        .stats
+        .bets
+        .value;
+    let tiers = TIERS.may_load(deps.storage)?.unwrap_or_default();
+    let (level, tier) = load_user_tier(deps, &address, bets_value, env.block.time.seconds())?;
+
+    let next_level = get_reached_level(&tiers, bets_value) + 1;
+    let next = tiers.get(next_level as usize).map(|x| TierProgress {
+        level: next_level,
+        threshold: x.threshold,
+        remaining: x.threshold - bets_value,
+        progress: Decimal::from_ratio(bets_value, x.threshold),
+    });
+
+    Ok(UserTierResp {
+        level,
+        tier,
+        is_assigned: ASSIGNED_TIERS.has(deps.storage, &address),
+        bets_value,
+        next,
+    })
+}
+
+pub fn query_seed_chain(deps: Deps, _env: Env) -> StdResult<Option<SeedChain>> {
+    Ok(SEED_CHAIN.may_load(deps.storage)?.flatten())
+}
+
+pub fn query_revealed_seed(deps: Deps, _env: Env, round: u64) -> StdResult<Option<HexBinary>> {
+    REVEALED_SEEDS.may_load(deps.storage, round)
+}
+
+pub fn query_pending_flips(
+    deps: Deps,
+    _env: Env,
+    amount: u32,
+    start_after: Option<u64>,
+) -> StdResult<Vec<PendingFlipsRespItem>> {
+    PENDING_FLIPS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(amount as usize)
+        .map(|x| x.map(|(id, flip)| PendingFlipsRespItem { id, flip }))
+        .collect()
+}
+
+pub fn query_randomness_fee(deps: Deps, _env: Env) -> StdResult<Option<Uint128>> {
+    Ok(RANDOMNESS_FEE.may_load(deps.storage)?.flatten())
+}
+
+pub fn query_randomness_requests(
+    deps: Deps,
+    _env: Env,
+    amount: u32,
+    start_after: Option<u64>,
+) -> StdResult<Vec<RandomnessRequestsRespItem>> {
+    RANDOMNESS_REQUESTS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(amount as usize)
+        .map(|x| x.map(|(id, request)| RandomnessRequestsRespItem { id, request }))
+        .collect()
+}
+
+pub fn query_rakeback(deps: Deps, _env: Env) -> StdResult<Option<RakebackConfig>> {
+    Ok(RAKEBACK_CONFIG.may_load(deps.storage)?.flatten())
+}
+
+pub fn query_user_rakeback(deps: Deps, env: Env, address: String) -> StdResult<UserRakebackResp> {
+    let address = deps.api.addr_validate(&address)?;
+    let block_time = env.block.time.seconds();
+    let stats = USERS.load(deps.storage, &address).unwrap_or_default().stats;
+    let config = RAKEBACK_CONFIG.may_load(deps.storage)?.flatten();
+    let (rakeback, _) = load_rakeback(deps.storage, &address, &stats, block_time)?;
+
+    Ok(match rakeback {
+        Some(x) => UserRakebackResp {
+            epoch: x.epoch,
+            accrued: x.accrued,
+            pending: config
+                .map(|y| x.get_net_loss(&stats).mul_floor(y.rate))
+                .unwrap_or_default(),
+        },
+        None => UserRakebackResp {
+            epoch: 0,
+            accrued: Uint128::zero(),
+            pending: Uint128::zero(),
+        },
+    })
+}
+
+pub fn query_user_balance(deps: Deps, _env: Env, address: String) -> StdResult<Uint128> {
+    let address = deps.api.addr_validate(&address)?;
+
+    Ok(USER_BALANCES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default())
+}
+
+pub fn query_user_bonus(deps: Deps, _env: Env, address: String) -> StdResult<BonusInfo> {
+    let address = deps.api.addr_validate(&address)?;
+
+    Ok(BONUS_BALANCES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default())
+}
+
+pub fn query_signed_nonce(deps: Deps, _env: Env, address: String) -> StdResult<u64> {
+    let address = deps.api.addr_validate(&address)?;
+
+    Ok(SIGNED_NONCES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default())
+}
+
+pub fn query_ibc_sender(deps: Deps, _env: Env, address: String) -> StdResult<Option<IbcTarget>> {
+    let address = deps.api.addr_validate(&address)?;
+
+    IBC_SENDERS.may_load(deps.storage, &address)
+}
+
+pub fn query_session_grants(
+    deps: Deps,
+    _env: Env,
+    owner: String,
+    amount: u32,
+    start_after: Option<String>,
+) -> StdResult<Vec<SessionGrantsRespItem>> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let binding;
+    let start_bound = match start_after {
+        Some(addr) => {
+            binding = deps.api.addr_validate(&addr)?;
+            Some(Bound::exclusive(&binding))
+        }
+        None => None,
+    };
+
+    SESSION_GRANTS
+        .prefix(&owner)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(amount as usize)
+        .map(|x| x.map(|(session, grant)| SessionGrantsRespItem { session, grant }))
+        .collect()
+}
+
+pub fn query_payout_queue_position(
+    deps: Deps,
+    _env: Env,
+    address: String,
+) -> StdResult<PayoutQueuePositionResp> {
+    let address = deps.api.addr_validate(&address)?;
+    let unclaimed = USERS
+        .load(deps.storage, &address)
+        .unwrap_or_default()
+        .unclaimed;
+    let length = PAYOUT_QUEUE_LENGTH
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    // the position is calculated from the ids without the queue scan
+    let head = PAYOUT_QUEUE
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?;
+    let position = match (PAYOUT_QUEUE_IDS.may_load(deps.storage, &address)?, head) {
+        (Some(id), Some(head)) => Some((id - head + 1) as u32),
+        _ => None,
+    };
+
+    Ok(PayoutQueuePositionResp {
+        position,
+        length,
+        unclaimed,
+    })
+}
+
+pub fn query_contract_callers(deps: Deps, _env: Env) -> StdResult<Option<Vec<Addr>>> {
+    CONTRACT_CALLERS.load(deps.storage)
+}
+
+pub fn query_deferred_only(deps: Deps, _env: Env) -> StdResult<bool> {
+    Ok(DEFERRED_ONLY.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_insurance(deps: Deps, _env: Env) -> StdResult<InsuranceResp> {
+    let config = INSURANCE_CONFIG.may_load(deps.storage)?.flatten();
+    let reserve = APP_INFO.load(deps.storage)?.insurance;
+
+    Ok(InsuranceResp {
+        available_to_withdraw: calc_insurance_to_withdraw(&config, reserve),
+        config,
+        reserve,
+    })
+}
+
+pub fn query_nft_perks(deps: Deps, _env: Env) -> StdResult<Vec<NftPerksRespItem>> {
+    NFT_PERKS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| x.map(|(collection, perk)| NftPerksRespItem { collection, perk }))
+        .collect()
+}
+
+pub fn query_revenue_split(deps: Deps, _env: Env) -> StdResult<Vec<RevenueSplitRespItem>> {
+    REVENUE_SPLIT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|x| {
+            let address = Addr::unchecked(x.address);
+            let distributed = DISTRIBUTED_REVENUE
+                .may_load(deps.storage, &address)?
+                .unwrap_or_default();
+
+            Ok(RevenueSplitRespItem {
+                address,
+                weight: x.weight,
+                distributed,
+            })
+        })
+        .collect()
+}
+
+pub fn query_buyback(deps: Deps, _env: Env) -> StdResult<Option<BuybackConfig>> {
+    Ok(BUYBACK_CONFIG.may_load(deps.storage)?.flatten())
+}
+
+pub fn query_oracle(deps: Deps, _env: Env) -> StdResult<Option<OracleConfig>> {
+    Ok(ORACLE_CONFIG.may_load(deps.storage)?.flatten())
+}
+
+pub fn query_bet_range(deps: Deps, env: Env) -> StdResult<BetRangeResp> {
+    let config = CONFIG.load(deps.storage)?;
+    let (bet, price) = load_bet_range(deps, &config, env.block.time.seconds())?;
+
+    Ok(BetRangeResp { bet, price })
+}
+
+pub fn query_promo_code(deps: Deps, _env: Env, hash: HexBinary) -> StdResult<Option<PromoCode>> {
+    PROMO_CODES.may_load(deps.storage, hash.as_slice())
+}
+
+pub fn query_buyback_list(
+    deps: Deps,
+    _env: Env,
+    amount: u32,
+    start_after: Option<u64>,
+) -> StdResult<Vec<BuybackListRespItem>> {
+    BUYBACKS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(amount as usize)
+        .map(|x| x.map(|(id, buyback)| BuybackListRespItem { id, buyback }))
+        .collect()
+}
+
+pub fn query_nft_floor_prices(deps: Deps, _env: Env) -> StdResult<Vec<NftFloorPriceRespItem>> {
+    NFT_FLOOR_PRICES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| x.map(|(collection, price)| NftFloorPriceRespItem { collection, price }))
+        .collect()
+}
+
+pub fn query_house_nfts(
+    deps: Deps,
+    _env: Env,
+    amount: u32,
+    start_after: Option<Nft>,
+) -> StdResult<Vec<HouseNftRespItem>> {
+    let binding;
+    let start_bound = match &start_after {
+        Some(x) => {
+            binding = deps.api.addr_validate(&x.collection)?;
+            Some(Bound::exclusive((&binding, x.token_id.as_str())))
+        }
+        None => None,
+    };
+
+    HOUSE_NFTS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(amount as usize)
+        .map(|x| {
+            x.map(|((collection, token_id), price)| HouseNftRespItem {
+                collection,
+                token_id,
+                price,
+            })
+        })
+        .collect()
+}
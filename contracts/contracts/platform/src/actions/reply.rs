@@ -0,0 +1,103 @@
+use cosmwasm_std::{from_json, DepsMut, Env, Order, Reply, Response, StdError, SubMsgResult};
+
+use cf_base::{
+    error::ContractError,
+    platform::{
+        state::{BUYBACKS, BUYBACK_BALANCE, IBC_PAYOUTS},
+        types::IbcPayout,
+    },
+    utils::{get_burn_msg, query_token_balance, Attrs},
+};
+
+use crate::helpers::{parse_transfer_sequence, return_ibc_payout};
+
+/// Completes the last buyback burning the tokens received from the pair
+pub fn reply_buyback(deps: DepsMut, env: Env, _reply: Reply) -> Result<Response, ContractError> {
+    let balance_before = BUYBACK_BALANCE.load(deps.storage)?;
+    BUYBACK_BALANCE.remove(deps.storage);
+
+    let (id, mut buyback) = BUYBACKS
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .ok_or(StdError::not_found("buyback"))?;
+    let token = buyback.token.verify(deps.api)?;
+    let amount_out = query_token_balance(deps.querier, env.contract.address, &token)?
+        .saturating_sub(balance_before);
+
+    if amount_out.is_zero() {
+        Err(ContractError::ZeroAmount)?;
+    }
+
+    buyback.amount_out = amount_out;
+    BUYBACKS.save(deps.storage, id, &buyback)?;
+
+    Ok(Response::new()
+        .add_message(get_burn_msg(amount_out, &token)?)
+        .add_attribute("action", "reply_buyback")
+        .add_event(Attrs::burn(id, &token, amount_out)))
+}
+
+/// Records the callback failure, the flip and its payout aren't reverted
+pub fn reply_flip_callback(
+    _deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let id: u64 = from_json(&reply.payload)?;
+    let SubMsgResult::Err(error) = reply.result else {
+        return Ok(Response::new());
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "reply_flip_callback")
+        .add_event(Attrs::flip_callback_failed(id, &error)))
+}
+
+/// Records the callback failure, the randomness request isn't reverted
+pub fn reply_randomness_callback(
+    _deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let id: u64 = from_json(&reply.payload)?;
+    let SubMsgResult::Err(error) = reply.result else {
+        return Ok(Response::new());
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "reply_randomness_callback")
+        .add_event(Attrs::randomness_callback_failed(id, &error)))
+}
+
+/// Saves the sent ICS-20 payout by its packet sequence to return it if the transfer fails
+pub fn reply_ibc_payout(deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    let payout: IbcPayout = from_json(&reply.payload)?;
+    let response = match reply.result {
+        SubMsgResult::Ok(x) => x,
+        // the rejected transfer is reverted, so the funds are kept by the contract
+        SubMsgResult::Err(_) => {
+            let (user, app_info) = return_ibc_payout(deps.storage, &payout)?;
+
+            return Ok(Response::new()
+                .add_attribute("action", "reply_ibc_payout")
+                .add_event(
+                    Attrs::return_ibc_payout(None, &payout)
+                        .user_info(&user)
+                        .app_info(&app_info),
+                ));
+        }
+    };
+
+    #[allow(deprecated)]
+    let data = match response.msg_responses.first() {
+        Some(x) => x.value.clone(),
+        None => response.data.unwrap_or_default(),
+    };
+    let sequence =
+        parse_transfer_sequence(&data).ok_or(StdError::not_found("transfer sequence"))?;
+
+    IBC_PAYOUTS.save(deps.storage, (&payout.channel_id, sequence), &payout)?;
+
+    Ok(Response::new())
+}
@@ -0,0 +1,47 @@
+use cosmwasm_std::{DepsMut, Env, Response};
+
+use cf_base::{
+    error::ContractError,
+    platform::{msg::IbcLifecycleComplete, state::IBC_PAYOUTS},
+    utils::Attrs,
+};
+
+use crate::helpers::return_ibc_payout;
+
+/// Completes the ICS-20 payout. The funds of failed or timed out transfer are refunded to the
+/// contract by the transfer module, they are returned to the user unclaimed
+pub fn sudo_ibc_lifecycle_complete(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcLifecycleComplete,
+) -> Result<Response, ContractError> {
+    let (channel, sequence, is_failed) = match msg {
+        IbcLifecycleComplete::IbcAck {
+            channel,
+            sequence,
+            success,
+            ..
+        } => (channel, sequence, !success),
+        IbcLifecycleComplete::IbcTimeout { channel, sequence } => (channel, sequence, true),
+    };
+
+    // transfers sent without the payout aren't tracked
+    let Some(payout) = IBC_PAYOUTS.may_load(deps.storage, (&channel, sequence))? else {
+        return Ok(Response::new());
+    };
+    IBC_PAYOUTS.remove(deps.storage, (&channel, sequence));
+
+    if !is_failed {
+        return Ok(Response::new());
+    }
+
+    let (user, app_info) = return_ibc_payout(deps.storage, &payout)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sudo_ibc_lifecycle_complete")
+        .add_event(
+            Attrs::return_ibc_payout(Some(sequence), &payout)
+                .user_info(&user)
+                .app_info(&app_info),
+        ))
+}
@@ -6,11 +6,18 @@ use cosmwasm_std::{
 
 use cf_base::{
     error::ContractError,
-    platform::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    platform::{
+        msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
+        state::{
+            BUYBACK_REPLY_ID, FLIP_CALLBACK_REPLY_ID, IBC_PAYOUT_REPLY_ID,
+            RANDOMNESS_CALLBACK_REPLY_ID,
+        },
+    },
 };
 
 use crate::actions::{
-    execute as e, instantiate::try_instantiate, migrate::migrate_contract, query as q,
+    execute as e, instantiate::try_instantiate, migrate::migrate_contract, query as q, reply as r,
+    sudo as s,
 };
 
 /// Creates a new contract with the specified parameters packed in the "msg" variable
@@ -33,16 +40,117 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Flip { side } => e::try_flip(deps, env, info, side),
+        ExecuteMsg::Flip {
+            side,
+            nft,
+            client_seed,
+            callback,
+            amount,
+            payout_to,
+        } => e::try_flip(
+            deps,
+            env,
+            info,
+            side,
+            nft,
+            client_seed,
+            callback,
+            amount,
+            None,
+            payout_to,
+        ),
+
+        ExecuteMsg::SessionFlip {
+            owner,
+            side,
+            client_seed,
+            amount,
+        } => e::try_flip(
+            deps,
+            env,
+            info,
+            side,
+            None,
+            client_seed,
+            None,
+            amount,
+            Some(e::FlipDelegate::Session { owner }),
+            None,
+        ),
+
+        ExecuteMsg::FlipSigned {
+            payload,
+            pubkey,
+            signature,
+        } => e::try_flip_signed(deps, env, info, payload, pubkey, signature),
+
+        ExecuteMsg::GrantSession {
+            session,
+            spend_cap,
+            duration,
+            side,
+            bet,
+        } => e::try_grant_session(deps, env, info, session, spend_cap, duration, side, bet),
+
+        ExecuteMsg::RevokeSession { session } => e::try_revoke_session(deps, env, info, session),
+
+        ExecuteMsg::BonusFlip { side, amount } => e::try_bonus_flip(deps, env, info, side, amount),
+
+        ExecuteMsg::RedeemPromoCode { code } => e::try_redeem_promo_code(deps, env, info, code),
+
+        ExecuteMsg::TopUp {} => e::try_top_up(deps, env, info),
+
+        ExecuteMsg::WithdrawBalance { amount } => e::try_withdraw_balance(deps, env, info, amount),
+
+        ExecuteMsg::SettleFlip { id } => e::try_settle_flip(deps, env, info, id),
+
+        ExecuteMsg::RefundFlip { id } => e::try_refund_flip(deps, env, info, id),
+
+        ExecuteMsg::RequestRandomness {
+            job_id,
+            callback,
+            deferred,
+        } => e::try_request_randomness(deps, env, info, job_id, callback, deferred),
+
+        ExecuteMsg::FulfillRandomness { id } => e::try_fulfill_randomness(deps, env, info, id),
+
+        ExecuteMsg::RefundRandomness { id } => e::try_refund_randomness(deps, env, info, id),
 
         ExecuteMsg::Claim {} => e::try_claim(deps, env, info),
 
+        ExecuteMsg::ClaimRakeback {} => e::try_claim_rakeback(deps, env, info),
+
+        ExecuteMsg::ProcessPayouts { limit } => e::try_process_payouts(deps, env, info, limit),
+
+        ExecuteMsg::DistributeRevenue {} => e::try_distribute_revenue(deps, env, info),
+
+        ExecuteMsg::ReceiveNft(msg) => e::try_receive_nft(deps, env, info, msg),
+
         ExecuteMsg::Deposit {} => e::try_deposit(deps, env, info),
 
         ExecuteMsg::Withdraw { amount, recipient } => {
             e::try_withdraw(deps, env, info, amount, recipient)
         }
 
+        ExecuteMsg::DepositInsurance {} => e::try_deposit_insurance(deps, env, info),
+
+        ExecuteMsg::WithdrawInsurance { amount, recipient } => {
+            e::try_withdraw_insurance(deps, env, info, amount, recipient)
+        }
+
+        ExecuteMsg::Buyback {
+            belief_price,
+            max_spread,
+        } => e::try_buyback(deps, env, info, belief_price, max_spread),
+
+        ExecuteMsg::CommitSeedChain { head } => e::try_commit_seed_chain(deps, env, info, head),
+
+        ExecuteMsg::RevealSeed { seed } => e::try_reveal_seed(deps, env, info, seed),
+
+        ExecuteMsg::EnqueuePayouts { start_after, limit } => {
+            e::try_enqueue_payouts(deps, env, info, start_after, limit)
+        }
+
         ExecuteMsg::AcceptAdminRole {} => e::try_accept_admin_role(deps, env, info),
 
         ExecuteMsg::UpdateConfig {
@@ -52,6 +160,62 @@ pub fn execute(
             platform_fee,
         } => e::try_update_config(deps, env, info, admin, worker, bet, platform_fee),
 
+        ExecuteMsg::UpdateTiers { tiers } => e::try_update_tiers(deps, env, info, tiers),
+
+        ExecuteMsg::SetUserTier { address, tier } => {
+            e::try_set_user_tier(deps, env, info, address, tier)
+        }
+
+        ExecuteMsg::UpdateRakeback { rakeback } => {
+            e::try_update_rakeback(deps, env, info, rakeback)
+        }
+
+        ExecuteMsg::UpdateInsurance { insurance } => {
+            e::try_update_insurance(deps, env, info, insurance)
+        }
+
+        ExecuteMsg::UpdateContractCallers { whitelist } => {
+            e::try_update_contract_callers(deps, env, info, whitelist)
+        }
+
+        ExecuteMsg::UpdateDeferredOnly { enabled } => {
+            e::try_update_deferred_only(deps, env, info, enabled)
+        }
+
+        ExecuteMsg::UpdateRandomnessFee { fee } => {
+            e::try_update_randomness_fee(deps, env, info, fee)
+        }
+
+        ExecuteMsg::UpdateNftPerk { collection, perk } => {
+            e::try_update_nft_perk(deps, env, info, collection, perk)
+        }
+
+        ExecuteMsg::UpdateRevenueSplit { recipients } => {
+            e::try_update_revenue_split(deps, env, info, recipients)
+        }
+
+        ExecuteMsg::UpdateBuyback { buyback } => e::try_update_buyback(deps, env, info, buyback),
+
+        ExecuteMsg::UpdateOracle { oracle } => e::try_update_oracle(deps, env, info, oracle),
+
+        ExecuteMsg::CreditBonus { credits, wagering } => {
+            e::try_credit_bonus(deps, env, info, credits, wagering)
+        }
+
+        ExecuteMsg::UpdatePromoCode { hash, promo } => {
+            e::try_update_promo_code(deps, env, info, hash, promo)
+        }
+
+        ExecuteMsg::UpdateNftFloorPrice { collection, price } => {
+            e::try_update_nft_floor_price(deps, env, info, collection, price)
+        }
+
+        ExecuteMsg::WithdrawNft {
+            collection,
+            token_id,
+            recipient,
+        } => e::try_withdraw_nft(deps, env, info, collection, token_id, recipient),
+
         ExecuteMsg::Pause {} => e::try_pause(deps, env, info),
 
         ExecuteMsg::Unpause {} => e::try_unpause(deps, env, info),
@@ -78,15 +242,121 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             amount,
             start_after,
         } => to_json_binary(&q::query_user_list(deps, env, amount, start_after)?),
+
+        QueryMsg::Tiers {} => to_json_binary(&q::query_tiers(deps, env)?),
+
+        QueryMsg::UserTier { address } => to_json_binary(&q::query_user_tier(deps, env, address)?),
+
+        QueryMsg::UserBalance { address } => {
+            to_json_binary(&q::query_user_balance(deps, env, address)?)
+        }
+
+        QueryMsg::UserBonus { address } => {
+            to_json_binary(&q::query_user_bonus(deps, env, address)?)
+        }
+
+        QueryMsg::SignedNonce { address } => {
+            to_json_binary(&q::query_signed_nonce(deps, env, address)?)
+        }
+
+        QueryMsg::IbcSender { address } => {
+            to_json_binary(&q::query_ibc_sender(deps, env, address)?)
+        }
+
+        QueryMsg::SessionGrants {
+            owner,
+            amount,
+            start_after,
+        } => to_json_binary(&q::query_session_grants(
+            deps,
+            env,
+            owner,
+            amount,
+            start_after,
+        )?),
+
+        QueryMsg::PayoutQueuePosition { address } => {
+            to_json_binary(&q::query_payout_queue_position(deps, env, address)?)
+        }
+
+        QueryMsg::SeedChain {} => to_json_binary(&q::query_seed_chain(deps, env)?),
+
+        QueryMsg::RevealedSeed { round } => {
+            to_json_binary(&q::query_revealed_seed(deps, env, round)?)
+        }
+
+        QueryMsg::PendingFlips {
+            amount,
+            start_after,
+        } => to_json_binary(&q::query_pending_flips(deps, env, amount, start_after)?),
+
+        QueryMsg::RandomnessFee {} => to_json_binary(&q::query_randomness_fee(deps, env)?),
+
+        QueryMsg::RandomnessRequests {
+            amount,
+            start_after,
+        } => to_json_binary(&q::query_randomness_requests(
+            deps,
+            env,
+            amount,
+            start_after,
+        )?),
+
+        QueryMsg::Rakeback {} => to_json_binary(&q::query_rakeback(deps, env)?),
+
+        QueryMsg::UserRakeback { address } => {
+            to_json_binary(&q::query_user_rakeback(deps, env, address)?)
+        }
+
+        QueryMsg::Insurance {} => to_json_binary(&q::query_insurance(deps, env)?),
+
+        QueryMsg::ContractCallers {} => to_json_binary(&q::query_contract_callers(deps, env)?),
+        QueryMsg::DeferredOnly {} => to_json_binary(&q::query_deferred_only(deps, env)?),
+
+        QueryMsg::NftPerks {} => to_json_binary(&q::query_nft_perks(deps, env)?),
+
+        QueryMsg::RevenueSplit {} => to_json_binary(&q::query_revenue_split(deps, env)?),
+
+        QueryMsg::Buyback {} => to_json_binary(&q::query_buyback(deps, env)?),
+
+        QueryMsg::Oracle {} => to_json_binary(&q::query_oracle(deps, env)?),
+
+        QueryMsg::BetRange {} => to_json_binary(&q::query_bet_range(deps, env)?),
+
+        QueryMsg::PromoCode { hash } => to_json_binary(&q::query_promo_code(deps, env, hash)?),
+
+        QueryMsg::BuybackList {
+            amount,
+            start_after,
+        } => to_json_binary(&q::query_buyback_list(deps, env, amount, start_after)?),
+
+        QueryMsg::NftFloorPrices {} => to_json_binary(&q::query_nft_floor_prices(deps, env)?),
+
+        QueryMsg::HouseNfts {
+            amount,
+            start_after,
+        } => to_json_binary(&q::query_house_nfts(deps, env, amount, start_after)?),
     }
 }
 
 /// Exposes all the replies available in the contract
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(_deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
-    let Reply { .. } = reply;
+pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
+    match reply.id {
+        BUYBACK_REPLY_ID => r::reply_buyback(deps, env, reply),
+        FLIP_CALLBACK_REPLY_ID => r::reply_flip_callback(deps, env, reply),
+        RANDOMNESS_CALLBACK_REPLY_ID => r::reply_randomness_callback(deps, env, reply),
+        IBC_PAYOUT_REPLY_ID => r::reply_ibc_payout(deps, env, reply),
+        _ => Err(ContractError::UndefinedReplyId),
+    }
+}
 
-    Err(ContractError::UndefinedReplyId)
+/// Exposes ibc-hooks callbacks of the sent ICS-20 payouts
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::IbcLifecycleComplete(x) => s::sudo_ibc_lifecycle_complete(deps, env, x),
+    }
 }
 
 /// Used for contract migration
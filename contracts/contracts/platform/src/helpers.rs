@@ -1,15 +1,40 @@
-use cosmwasm_std::{Addr, Decimal, Env, Int256, StdResult, Storage, Uint128};
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, to_json_vec, Addr, Api, Binary, CanonicalAddr, ContractResult,
+    Decimal, Deps, Env, HexBinary, IbcMsg, IbcTimeout, Int256, Order, QueryRequest, StdError,
+    StdResult, Storage, SubMsg, SystemError, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
+};
 
 use cf_base::{
+    assets::Token,
     converters::{address_to_salt, str_to_dec},
     error::ContractError,
     hash_generator::types::Hash,
-    platform::state::IS_PAUSED,
+    oracle::{OracleQueryMsg, PriceResp},
+    platform::{
+        msg::{CallbackMsg, SignedFlipPayload},
+        state::{
+            APP_INFO, ASSIGNED_TIERS, CALLBACK_GAS_LIMIT, CONFIG, CONTRACT_CALLERS, DEFERRED_ONLY,
+            FLIP_CALLBACK_REPLY_ID, IBC_PAYOUT_REPLY_ID, IBC_SENDERS, IBC_TRANSFER_TIMEOUT,
+            IS_PAUSED, MAX_REVENUE_RECIPIENTS, MAX_TIERS, NFT_PERKS, ORACLE_CONFIG, PAYOUT_QUEUE,
+            PAYOUT_QUEUE_ID, PAYOUT_QUEUE_IDS, PAYOUT_QUEUE_LENGTH, RAKEBACK, RAKEBACK_CONFIG,
+            RANDOMNESS_CALLBACK_REPLY_ID, TIERS, USERS, USER_BALANCES,
+        },
+        types::{
+            AppInfo, Bet, Callback, Config, IbcPayout, IbcTarget, InsuranceConfig, Nft, NftPerk,
+            Outcome, RakebackInfo, Range, RevenueRecipient, Stats, Tier, UserInfo,
+        },
+    },
+    utils::{get_transfer_msg, query_nft_owner},
+};
+use hashing_helper::base::{
+    calc_hash_bytes, calc_ibc_hooks_sender_hash, calc_pubkey_hash, calc_sha256,
 };
-use hashing_helper::base::calc_hash_bytes;
+
+/// length of secp256k1 compressed public key
+const PUBKEY_LEN: usize = 33;
 
 /// user actions are disabled when the contract is paused
-pub fn check_pause_state(storage: &dyn Storage) -> StdResult<()> {
+pub fn check_pause_state(storage: &dyn Storage) -> Result<(), ContractError> {
     if IS_PAUSED.load(storage)? {
         Err(ContractError::ContractIsPaused)?;
     }
@@ -17,16 +42,289 @@ pub fn check_pause_state(storage: &dyn Storage) -> StdResult<()> {
     Ok(())
 }
 
-pub fn get_random_weight(
+/// Verifies the signature of the payload hash and returns the signer address derived from
+/// the public key like in Cosmos SDK. The payload must be made for this contract and chain
+/// and relayed before it expires
+pub fn verify_signed_flip(
+    deps: Deps,
+    env: &Env,
+    payload: &Binary,
+    pubkey: &Binary,
+    signature: &Binary,
+) -> Result<(Addr, SignedFlipPayload), ContractError> {
+    if pubkey.len() != PUBKEY_LEN
+        || !deps
+            .api
+            .secp256k1_verify(&calc_sha256(payload), signature, pubkey)
+            .unwrap_or_default()
+    {
+        Err(ContractError::InvalidSignature)?;
+    }
+
+    let payload: SignedFlipPayload =
+        from_json(payload).map_err(|_| ContractError::ImproperSignedPayload)?;
+    if payload.contract != env.contract.address.as_str() || payload.chain_id != env.block.chain_id {
+        Err(ContractError::ImproperSignedPayload)?;
+    }
+
+    if env.block.time.seconds() >= payload.expires_at {
+        Err(ContractError::SignedPayloadIsExpired)?;
+    }
+
+    let user = deps
+        .api
+        .addr_humanize(&CanonicalAddr::from(calc_pubkey_hash(pubkey)))?;
+
+    Ok((user, payload))
+}
+
+/// The sender must be ibc-hooks intermediate address of the target, then the target is saved
+/// to return the sender payouts to the original chain
+pub fn register_ibc_sender(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    target: &IbcTarget,
+) -> Result<(), ContractError> {
+    let intermediate_sender = api.addr_humanize(&CanonicalAddr::from(
+        calc_ibc_hooks_sender_hash(&target.channel_id, &target.receiver),
+    ))?;
+
+    if intermediate_sender != sender {
+        Err(ContractError::ImproperIbcTarget)?;
+    }
+
+    IBC_SENDERS.save(storage, sender, target)?;
+
+    Ok(())
+}
+
+/// Payouts of ibc-hooks senders are returned to the original chain with ICS-20 transfer,
+/// other users receive bank transfers. The transfer requests ibc-hooks callback, its sequence is
+/// saved in the reply to return the payout if the transfer fails. The transfer rejected by the
+/// chain doesn't revert the tx, the payout is returned in the reply as well
+pub fn get_payout_msg(
+    storage: &dyn Storage,
+    env: &Env,
+    user: &Addr,
+    amount: Uint128,
+    denom: &str,
+) -> Result<SubMsg, ContractError> {
+    let Some(target) = IBC_SENDERS.may_load(storage, user)? else {
+        return Ok(SubMsg::new(get_transfer_msg(
+            user,
+            amount,
+            &Token::new_native(denom),
+        )?));
+    };
+
+    let msg = IbcMsg::Transfer {
+        channel_id: target.channel_id.clone(),
+        to_address: target.receiver,
+        amount: coin(amount.u128(), denom),
+        timeout: IbcTimeout::with_timestamp(Timestamp::from_seconds(
+            env.block.time.seconds() + IBC_TRANSFER_TIMEOUT,
+        )),
+        memo: Some(format!(r#"{{"ibc_callback":"{}"}}"#, env.contract.address)),
+    };
+    let payload = to_json_binary(&IbcPayout {
+        user: user.to_owned(),
+        channel_id: target.channel_id,
+        amount,
+    })?;
+
+    Ok(SubMsg::reply_always(msg, IBC_PAYOUT_REPLY_ID).with_payload(payload))
+}
+
+/// Returns the failed ICS-20 payout to the user unclaimed and adds the user to the end of the
+/// payout queue, so the failed payout doesn't block the queue
+pub fn return_ibc_payout(
+    storage: &mut dyn Storage,
+    payout: &IbcPayout,
+) -> Result<(UserInfo, AppInfo), ContractError> {
+    let mut app_info = APP_INFO.load(storage)?;
+    let mut user = USERS.may_load(storage, &payout.user)?.unwrap_or_default();
+
+    app_info.return_payout(payout.amount);
+    user.unclaimed += payout.amount;
+    enqueue_payout(storage, &payout.user)?;
+
+    USERS.save(storage, &payout.user, &user)?;
+    APP_INFO.save(storage, &app_info)?;
+
+    Ok((user, app_info))
+}
+
+/// Reads the packet sequence from protobuf encoded `MsgTransferResponse`
+pub fn parse_transfer_sequence(data: &[u8]) -> Option<u64> {
+    // field 1, varint
+    let (&tag, mut bytes) = data.split_first()?;
+    if tag != 0x08 {
+        return None;
+    }
+
+    let mut sequence: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        sequence |= u64::from(byte & 0x7f) << shift;
+        bytes = rest;
+
+        if byte & 0x80 == 0 {
+            return Some(sequence);
+        }
+    }
+
+    None
+}
+
+/// Contracts can inspect the flip outcome in a submessage reply and revert the tx on a loss,
+/// so only whitelisted contracts can flip. Non-contract senders aren't affected, though they can
+/// still follow the flip with a message to their own contract reverting the tx on a loss as the
+/// outcome is known in the same tx. `check_deferred_only` protects from it
+pub fn check_contract_caller(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let Some(whitelist) = CONTRACT_CALLERS.load(deps.storage)? else {
+        return Ok(());
+    };
+
+    if !whitelist.contains(sender) && is_contract(deps, sender)? {
+        Err(ContractError::ContractCallerIsNotAllowed)?;
+    }
+
+    Ok(())
+}
+
+/// The outcome of flip settled in the request tx can be checked by the next message of the tx
+/// reverting it on a loss, so only deferred settlement is allowed if it's required
+pub fn check_deferred_only(storage: &dyn Storage) -> Result<(), ContractError> {
+    if DEFERRED_ONLY.may_load(storage)?.unwrap_or_default() {
+        Err(ContractError::ImmediateSettlementIsDisabled)?;
+    }
+
+    Ok(())
+}
+
+/// Only the missing contract means the address isn't a contract, other errors are returned
+/// instead of letting the sender through. wasmd reports it as `NoSuchContract`, cw-multi-test
+/// returns not found query error
+fn is_contract(deps: Deps, address: &Addr) -> Result<bool, ContractError> {
+    let request: QueryRequest = WasmQuery::ContractInfo {
+        contract_addr: address.to_string(),
+    }
+    .into();
+
+    match deps.querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Err(SystemError::NoSuchContract { .. }) => Ok(false),
+        SystemResult::Ok(ContractResult::Err(e)) if e.ends_with("not found") => Ok(false),
+        SystemResult::Ok(ContractResult::Err(e)) => Err(StdError::generic_err(e))?,
+        SystemResult::Err(e) => Err(StdError::generic_err(e.to_string()))?,
+        SystemResult::Ok(ContractResult::Ok(_)) => Ok(true),
+    }
+}
+
+/// Callback of flip settled in the same tx could revert the flip exceeding the tx gas on lose,
+/// so the contract must be whitelisted
+pub fn check_callback(deps: Deps, contract: &Addr) -> Result<(), ContractError> {
+    match CONTRACT_CALLERS.load(deps.storage)? {
+        Some(whitelist) if !whitelist.contains(contract) => {
+            Err(ContractError::CallbackIsNotAllowed)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Notifies the callback contract with the flip result. The submessage has limited gas and its
+/// failure is caught in the reply
+pub fn get_flip_callback_msg(
+    id: u64,
+    bet: &Bet,
+    prize: Uint128,
+) -> Result<Option<SubMsg>, ContractError> {
+    let Some(callback) = &bet.callback else {
+        return Ok(None);
+    };
+
+    let msg = WasmMsg::Execute {
+        contract_addr: callback.contract.to_string(),
+        msg: to_json_binary(&CallbackMsg::FlipCallback {
+            flip_id: id,
+            user: bet.user.to_owned(),
+            outcome: if prize.is_zero() {
+                Outcome::Lose
+            } else {
+                Outcome::Win
+            },
+            prize,
+            msg: callback.msg.to_owned(),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Some(
+        SubMsg::reply_on_error(msg, FLIP_CALLBACK_REPLY_ID)
+            .with_gas_limit(CALLBACK_GAS_LIMIT)
+            .with_payload(to_json_binary(&id)?),
+    ))
+}
+
+/// Sends the randomness to the callback contract. The submessage has limited gas and its
+/// failure is caught in the reply
+pub fn get_randomness_callback_msg(
+    id: u64,
+    job_id: &str,
+    callback: &Callback,
+    randomness: &HexBinary,
+) -> Result<SubMsg, ContractError> {
+    let msg = WasmMsg::Execute {
+        contract_addr: callback.contract.to_owned(),
+        msg: to_json_binary(&CallbackMsg::RandomnessCallback {
+            request_id: id,
+            job_id: job_id.to_owned(),
+            randomness: randomness.to_owned(),
+            msg: callback.msg.to_owned(),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(SubMsg::reply_on_error(msg, RANDOMNESS_CALLBACK_REPLY_ID)
+        .with_gas_limit(CALLBACK_GAS_LIMIT)
+        .with_payload(to_json_binary(&id)?))
+}
+
+/// Next hash of the entropy chain, the chain is continued by its normalized decimal
+pub fn get_random_hash(
     env: &Env,
     sender_address: &Addr,
     previous_weight: &Decimal,
-) -> StdResult<Decimal> {
+) -> Result<Hash, ContractError> {
     let password = &format!("{}{}", previous_weight, env.block.time.nanos());
     let salt = &address_to_salt(sender_address);
     let hash_bytes = calc_hash_bytes(password, salt)?;
 
-    Ok(Hash::from(hash_bytes).to_norm_dec())
+    Ok(Hash::from(hash_bytes))
+}
+
+pub fn get_random_weight(
+    env: &Env,
+    sender_address: &Addr,
+    previous_weight: &Decimal,
+) -> Result<Decimal, ContractError> {
+    Ok(get_random_hash(env, sender_address, previous_weight)?.to_norm_dec())
+}
+
+/// Seeded flips outcome can be verified by anyone: sha256 of `{seed}:{client_seed}:{nonce}`
+/// where the seed is hex encoded
+pub fn get_seeded_random_weight(seed: &HexBinary, client_seed: &str, nonce: u64) -> Decimal {
+    let data = format!("{}:{}:{}", seed.to_hex(), client_seed, nonce);
+
+    Hash::from(calc_sha256(data.as_bytes())).to_norm_dec()
+}
+
+/// Deferred randomness can be verified by anyone: sha256 of `{seed}:{job_id}:{id}` where the seed
+/// is hex encoded
+pub fn get_seeded_randomness(seed: &HexBinary, job_id: &str, id: u64) -> HexBinary {
+    let data = format!("{}:{}:{}", seed.to_hex(), job_id, id);
+
+    HexBinary::from(calc_sha256(data.as_bytes()))
 }
 
 pub fn calc_required_to_deposit(balance: Uint128, total_unclaimed: Uint128) -> Uint128 {
@@ -37,6 +335,8 @@ pub fn calc_required_to_deposit(balance: Uint128, total_unclaimed: Uint128) -> U
     }
 }
 
+/// User liabilities (unclaimed, rakeback, user balances) and the insurance are excluded by the
+/// balance formula
 pub fn calc_available_to_withdraw(deposited: Uint128, revenue_current: Int256) -> Uint128 {
     let available_to_withdraw = Int256::from(deposited) + revenue_current;
 
@@ -46,3 +346,282 @@ pub fn calc_available_to_withdraw(deposited: Uint128, revenue_current: Int256) -
         str_to_dec(&available_to_withdraw.to_string()).to_uint_floor()
     }
 }
+
+/// Adds the amount to the user balance returning the updated balance
+pub fn credit_user_balance(
+    storage: &mut dyn Storage,
+    user: &Addr,
+    amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    let balance = USER_BALANCES.may_load(storage, user)?.unwrap_or_default() + amount;
+    USER_BALANCES.save(storage, user, &balance)?;
+
+    Ok(balance)
+}
+
+/// Adds the user with unclaimed rewards to the end of the payout queue if the user isn't queued
+pub fn enqueue_payout(storage: &mut dyn Storage, user: &Addr) -> Result<(), ContractError> {
+    if PAYOUT_QUEUE_IDS.has(storage, user) {
+        return Ok(());
+    }
+
+    let id = PAYOUT_QUEUE_ID.may_load(storage)?.unwrap_or_default();
+    let length = PAYOUT_QUEUE_LENGTH.may_load(storage)?.unwrap_or_default();
+    PAYOUT_QUEUE.save(storage, id, user)?;
+    PAYOUT_QUEUE_IDS.save(storage, user, &id)?;
+    PAYOUT_QUEUE_ID.save(storage, &(id + 1))?;
+    PAYOUT_QUEUE_LENGTH.save(storage, &(length + 1))?;
+
+    Ok(())
+}
+
+pub fn dequeue_payout(storage: &mut dyn Storage, user: &Addr) -> Result<(), ContractError> {
+    if let Some(id) = PAYOUT_QUEUE_IDS.may_load(storage, user)? {
+        PAYOUT_QUEUE.remove(storage, id);
+        PAYOUT_QUEUE_IDS.remove(storage, user);
+        PAYOUT_QUEUE_LENGTH.update(storage, |x| -> StdResult<u32> { Ok(x.saturating_sub(1)) })?;
+    }
+
+    Ok(())
+}
+
+/// Pays unclaimed rewards of queued users in FIFO order. It stops when the limit is reached or
+/// the balance isn't enough for the next user, the users behind can't be paid first
+pub fn process_payout_queue(
+    storage: &mut dyn Storage,
+    app_info: &mut AppInfo,
+    limit: u32,
+) -> Result<Vec<(Addr, Uint128)>, ContractError> {
+    let queue = PAYOUT_QUEUE
+        .range(storage, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut payouts: Vec<(Addr, Uint128)> = vec![];
+
+    for (_, address) in queue {
+        let mut user = USERS.load(storage, &address)?;
+
+        if user.unclaimed > app_info.payable() {
+            break;
+        }
+
+        if !user.unclaimed.is_zero() {
+            app_info.claim(user.unclaimed);
+            payouts.push((address.to_owned(), user.unclaimed));
+            user.unclaimed = Uint128::zero();
+            USERS.save(storage, &address, &user)?;
+        }
+
+        dequeue_payout(storage, &address)?;
+    }
+
+    Ok(payouts)
+}
+
+/// Only the insurance excess above the target can be withdrawn
+pub fn calc_insurance_to_withdraw(config: &Option<InsuranceConfig>, insurance: Uint128) -> Uint128 {
+    match config {
+        Some(x) => insurance.saturating_sub(x.target),
+        None => insurance,
+    }
+}
+
+/// Realized revenue which can be sent without touching deposited funds and user rewards
+pub fn calc_distributable_revenue(app_info: &AppInfo) -> Uint128 {
+    let reserve = app_info.deposited
+        + app_info.user_unclaimed
+        + app_info.user_rakeback
+        + app_info.insurance
+        + app_info.user_balances;
+
+    calc_available_to_withdraw(Uint128::zero(), app_info.revenue.current)
+        .min(app_info.balance.saturating_sub(reserve))
+}
+
+/// Validates the recipients returning them with normalized addresses
+pub fn check_revenue_split(
+    api: &dyn Api,
+    recipients: &[RevenueRecipient],
+) -> Result<Vec<RevenueRecipient>, ContractError> {
+    if recipients.len() > MAX_REVENUE_RECIPIENTS {
+        Err(ContractError::RevenueRecipientLimit)?;
+    }
+
+    let mut address_list: Vec<Addr> = vec![];
+    let mut weight_sum = Decimal::zero();
+
+    for recipient in recipients {
+        let address = api.addr_validate(&recipient.address)?;
+
+        if recipient.weight.is_zero() || address_list.contains(&address) {
+            Err(ContractError::ImproperRevenueSplit)?;
+        }
+
+        address_list.push(address);
+        weight_sum += recipient.weight;
+    }
+
+    if !recipients.is_empty() && weight_sum != Decimal::one() {
+        Err(ContractError::ImproperRevenueSplit)?;
+    }
+
+    Ok(address_list
+        .into_iter()
+        .zip(recipients)
+        .map(|(address, x)| RevenueRecipient {
+            address: address.to_string(),
+            weight: x.weight,
+        })
+        .collect())
+}
+
+pub fn check_tiers(tiers: &[Tier]) -> Result<(), ContractError> {
+    if tiers.len() > MAX_TIERS {
+        Err(ContractError::TierLimit)?;
+    }
+
+    if tiers.first().is_some_and(|x| !x.threshold.is_zero())
+        || tiers.windows(2).any(|x| x[0].threshold >= x[1].threshold)
+    {
+        Err(ContractError::ImproperTierThresholds)?;
+    }
+
+    for tier in tiers {
+        if tier.bet.min > tier.bet.max {
+            Err(ContractError::ImproperMinBet)?;
+        }
+
+        if tier.bet.max.is_zero() {
+            Err(ContractError::ZeroMaxBet)?;
+        }
+
+        if tier.fee_discount > Decimal::one() || tier.cashback.is_some_and(|x| x > Decimal::one()) {
+            Err(ContractError::TierRateIsOutOfRange)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the level reached by lifetime bets value
+pub fn get_reached_level(tiers: &[Tier], bets_value: Uint128) -> u8 {
+    tiers
+        .iter()
+        .rposition(|x| bets_value >= x.threshold)
+        .unwrap_or_default() as u8
+}
+
+/// Assigned level overrides reached level, base tier is used if tiers aren't specified
+pub fn get_user_tier(
+    tiers: &[Tier],
+    config: &Config,
+    bets_value: Uint128,
+    assigned_level: Option<u8>,
+) -> (u8, Tier) {
+    let level = match assigned_level {
+        Some(x) => x.min(tiers.len().saturating_sub(1) as u8),
+        None => get_reached_level(tiers, bets_value),
+    };
+
+    match tiers.get(level as usize) {
+        Some(x) => (level, x.to_owned()),
+        None => (0, Tier::from_config(config)),
+    }
+}
+
+/// Returns the base bet range and the oracle price it was converted with. `Config::bet` is
+/// returned if the oracle isn't specified, fails or its price is stale
+pub fn load_bet_range(
+    deps: Deps,
+    config: &Config,
+    block_time: u64,
+) -> Result<(Range, Option<Decimal>), ContractError> {
+    let fallback = (config.bet.to_owned(), None);
+    let Some(oracle) = ORACLE_CONFIG.may_load(deps.storage)?.flatten() else {
+        return Ok(fallback);
+    };
+
+    let resp: Option<PriceResp> = deps
+        .querier
+        .query_wasm_smart(
+            &oracle.contract,
+            &OracleQueryMsg::Price {
+                denom: config.denom.to_owned(),
+            },
+        )
+        .ok();
+
+    Ok(match resp {
+        Some(x)
+            if !x.price.is_zero()
+                && x.updated_at <= block_time
+                && block_time - x.updated_at <= oracle.max_age =>
+        {
+            match oracle.calc_bet(x.price) {
+                Some(bet) => (bet, Some(x.price)),
+                None => fallback,
+            }
+        }
+        _ => fallback,
+    })
+}
+
+pub fn load_user_tier(
+    deps: Deps,
+    user: &Addr,
+    bets_value: Uint128,
+    block_time: u64,
+) -> Result<(u8, Tier), ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let tiers = TIERS.may_load(deps.storage)?.unwrap_or_default();
+    let assigned_level = ASSIGNED_TIERS.may_load(deps.storage, user)?;
+
+    // the oracle range replaces the static one for users without tiers and bounds tier ranges
+    let (bet, price) = load_bet_range(deps, &config, block_time)?;
+    config.bet = bet;
+    let (level, mut tier) = get_user_tier(&tiers, &config, bets_value, assigned_level);
+
+    if price.is_some() {
+        tier.bet = tier.bet.clamp(&config.bet);
+    }
+
+    Ok((level, tier))
+}
+
+/// Loads user rakeback and settles finished epoch. Must be called before updating user stats. \
+/// Returns `None` if rakeback is disabled and the user doesn't have rakeback state
+pub fn load_rakeback(
+    storage: &dyn Storage,
+    user: &Addr,
+    stats: &Stats,
+    block_time: u64,
+) -> Result<(Option<RakebackInfo>, Uint128), ContractError> {
+    let config = RAKEBACK_CONFIG.may_load(storage)?.flatten();
+    let rakeback = RAKEBACK.may_load(storage, user)?;
+
+    Ok(match (config, rakeback) {
+        (Some(config), Some(mut rakeback)) => {
+            let amount = rakeback.settle(&config, stats, config.get_epoch(block_time));
+            (Some(rakeback), amount)
+        }
+        (Some(config), None) => (
+            Some(RakebackInfo::new(config.get_epoch(block_time), stats)),
+            Uint128::zero(),
+        ),
+        (None, rakeback) => (rakeback, Uint128::zero()),
+    })
+}
+
+/// Returns the perk of registered collection if the sender owns the NFT
+pub fn load_nft_perk(deps: Deps, sender: &Addr, nft: &Nft) -> Result<NftPerk, ContractError> {
+    let collection = deps.api.addr_validate(&nft.collection)?;
+    let perk = NFT_PERKS
+        .may_load(deps.storage, &collection)?
+        .ok_or(ContractError::NftCollectionIsNotFound)?;
+
+    if query_nft_owner(deps.querier, &collection, &nft.token_id)? != sender.as_str() {
+        Err(ContractError::NftIsNotOwned)?;
+    }
+
+    Ok(perk)
+}
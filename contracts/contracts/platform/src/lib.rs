@@ -6,4 +6,6 @@ pub mod actions {
     pub mod instantiate;
     pub mod migrate;
     pub mod query;
+    pub mod reply;
+    pub mod sudo;
 }
@@ -0,0 +1,39 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::assets::Token;
+
+/// Astroport compatible pair messages used for buyback swaps
+#[cw_serde]
+pub enum PairExecuteMsg {
+    Swap {
+        offer_asset: Asset,
+        ask_asset_info: Option<AssetInfo>,
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+        to: Option<String>,
+    },
+}
+
+#[cw_serde]
+pub struct Asset {
+    pub info: AssetInfo,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum AssetInfo {
+    Token { contract_addr: String },
+    NativeToken { denom: String },
+}
+
+impl From<Token> for AssetInfo {
+    fn from(token: Token) -> Self {
+        match token {
+            Token::Native { denom } => Self::NativeToken { denom },
+            Token::Cw20 { address } => Self::Token {
+                contract_addr: address.to_string(),
+            },
+        }
+    }
+}
@@ -1,11 +1,18 @@
-use cosmwasm_std::StdError;
+use std::fmt;
+
+use cosmwasm_std::{from_json, to_json_vec, Binary, StdError};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Marks the machine-readable part of an error message: `[cf_error:{code}:{payload}]`
+pub const ERROR_PAYLOAD_TAG: &str = "[cf_error:";
+
 impl From<StdError> for ContractError {
     fn from(std_error: StdError) -> Self {
-        Self::CustomError {
-            val: std_error.to_string(),
-        }
+        let val = std_error.to_string();
+
+        // restore the original error if it was converted to StdError earlier
+        decode_err(&val).unwrap_or(Self::CustomError { val })
     }
 }
 
@@ -22,87 +29,407 @@ pub fn parse_err(err: anyhow::Error) -> StdError {
     StdError::generic_err(format!("{}\n{}", context, source))
 }
 
+/// Restores the contract error encoded at the end of any error message (contract response,
+/// tx log, etc.). Only the last tag is decoded, so a tag injected into the description of
+/// the error (e.g. with `CustomError` value) is ignored
+pub fn decode_err(msg: &str) -> Option<ContractError> {
+    let (_, tail) = msg.trim_end().rsplit_once(ERROR_PAYLOAD_TAG)?;
+    let (code, tail) = tail.split_once(':')?;
+    let payload = tail.strip_suffix(']')?;
+
+    let error: ContractError = from_json(Binary::from_base64(payload).ok()?).ok()?;
+
+    // the code is duplicated in plain text for integrators, ensure it wasn't changed
+    if code.parse::<u32>().ok()? != error.code() {
+        return None;
+    }
+
+    Some(error)
+}
+
 /// Never is a placeholder to ensure we don't return any errors
 #[derive(Error, Debug)]
 pub enum Never {}
 
-#[derive(Error, Debug, PartialEq)]
+/// Message format is `{description} [cf_error:{code}:{payload}]` where `payload` is
+/// base64 encoded JSON of the error. Use `decode_err` to restore the error from the message
+#[derive(Error, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ContractError {
-    #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
 
-    #[error("Sender does not have access permissions!")]
     Unauthorized,
 
-    #[error("Parameters are not provided!")]
     NoParameters,
 
-    #[error("It's too late to accept admin role!")]
     TransferAdminDeadline,
 
-    #[error("Not enough liquidity to withdraw funds!")]
     NotEnoughLiquidity,
 
-    #[error("Chain ID is not found!")]
     ChainIdIsNotFound,
 
-    #[error("Zero amount to send!")]
     ZeroAmount,
 
-    #[error("Undefined Reply ID!")]
     UndefinedReplyId,
 
-    #[error("Asset is not found!")]
     AssetIsNotFound,
 
-    #[error("Wrong asset type!")]
     WrongAssetType,
 
-    #[error("Wrong message type!")]
     WrongMessageType,
 
-    #[error("Wrong action type!")]
     WrongActionType,
 
-    #[error("Wrong funds combination!")]
     WrongFundsCombination,
 
-    #[error("{value:?} config is not found!")]
     ParameterIsNotFound { value: String },
 
-    #[error("The contract is paused")]
     ContractIsPaused,
 
-    #[error("Denom already exists!")]
     DenomExists,
 
-    #[error("Exceeded tokens per owner limit!")]
     TokenLimit,
 
-    #[error("Bet is out of range!")]
     BetIsOutOfRange,
 
-    #[error("Min bet is great than max bet!")]
     ImproperMinBet,
 
-    #[error("Max bet can't be zero!")]
     ZeroMaxBet,
 
-    #[error("The user doesn't have rewards!")]
     ZeroRewardsAmount,
 
-    #[error("A user can't flip multiple coins in single tx!")]
     MultipleFlipsPerTx,
 
-    #[error("Platform fee must be from 0 to 1!")]
     FeeIsOutOfRange,
 
-    #[error("Parsing previous version error!")]
     ParsingPrevVersion,
 
-    #[error("Parsing new version error!")]
     ParsingNewVersion,
 
-    #[error("Msg version is not equal contract new version!")]
     ImproperMsgVersion,
+
+    ImproperTierThresholds,
+
+    TierRateIsOutOfRange,
+
+    TierLimit,
+
+    TierIsNotFound,
+
+    RakebackRateIsOutOfRange,
+
+    ZeroRakebackEpoch,
+
+    NftCollectionLimit,
+
+    NftCollectionIsNotFound,
+
+    NftIsNotOwned,
+
+    NftDiscountIsOutOfRange,
+
+    NftCollectionIsNotWhitelisted,
+
+    HouseNftIsNotFound,
+
+    ImproperRevenueSplit,
+
+    RevenueRecipientLimit,
+
+    RevenueSplitIsNotFound,
+
+    BuybackIsDisabled,
+
+    ImproperBuybackToken,
+
+    BuybackShareIsOutOfRange,
+
+    InsuranceRateIsOutOfRange,
+
+    PayoutQueueIsEmpty,
+
+    ContractCallerIsNotAllowed,
+
+    SeedChainIsNotFound,
+
+    ImproperSeed,
+
+    SeedIsRevealedInBlock,
+
+    PendingFlipsExist,
+
+    PendingFlipIsNotFound,
+
+    SeedIsNotRevealed,
+
+    RefundIsNotAvailable,
+
+    ImproperClientSeed,
+
+    CallbackIsNotAllowed,
+
+    RandomnessIsDisabled,
+
+    ImproperRandomnessFee,
+
+    ImproperJobId,
+
+    RandomnessRequestIsNotFound,
+
+    InsufficientUserBalance,
+
+    SessionIsNotFound,
+
+    SessionIsExpired,
+
+    ImproperSessionDuration,
+
+    SessionBetIsNotAllowed,
+
+    SessionSpendCapIsExceeded,
+
+    InvalidSignature,
+
+    ImproperSignedPayload,
+
+    ImproperSignedNonce,
+
+    ImproperIbcTarget,
+
+    ImproperOracleMaxAge,
+
+    InsufficientBonusBalance,
+
+    BonusCreditLimit,
+
+    ImproperPromoCodeHash,
+
+    PromoCodeIsNotFound,
+
+    PromoCodeIsRedeemed,
+
+    ImmediateSettlementIsDisabled,
+
+    SignedPayloadIsExpired,
+}
+
+impl ContractError {
+    /// Stable numeric code. Codes of removed variants must never be reused
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::CustomError { .. } => 1,
+            Self::Unauthorized => 2,
+            Self::NoParameters => 3,
+            Self::TransferAdminDeadline => 4,
+            Self::NotEnoughLiquidity => 5,
+            Self::ChainIdIsNotFound => 6,
+            Self::ZeroAmount => 7,
+            Self::UndefinedReplyId => 8,
+            Self::AssetIsNotFound => 9,
+            Self::WrongAssetType => 10,
+            Self::WrongMessageType => 11,
+            Self::WrongActionType => 12,
+            Self::WrongFundsCombination => 13,
+            Self::ParameterIsNotFound { .. } => 14,
+            Self::ContractIsPaused => 15,
+            Self::DenomExists => 16,
+            Self::TokenLimit => 17,
+            Self::BetIsOutOfRange => 18,
+            Self::ImproperMinBet => 19,
+            Self::ZeroMaxBet => 20,
+            Self::ZeroRewardsAmount => 21,
+            Self::MultipleFlipsPerTx => 22,
+            Self::FeeIsOutOfRange => 23,
+            Self::ParsingPrevVersion => 24,
+            Self::ParsingNewVersion => 25,
+            Self::ImproperMsgVersion => 26,
+            Self::ImproperTierThresholds => 27,
+            Self::TierRateIsOutOfRange => 28,
+            Self::TierLimit => 29,
+            Self::TierIsNotFound => 30,
+            Self::RakebackRateIsOutOfRange => 31,
+            Self::ZeroRakebackEpoch => 32,
+            Self::NftCollectionLimit => 33,
+            Self::NftCollectionIsNotFound => 34,
+            Self::NftIsNotOwned => 35,
+            Self::NftDiscountIsOutOfRange => 36,
+            Self::NftCollectionIsNotWhitelisted => 37,
+            Self::HouseNftIsNotFound => 38,
+            Self::ImproperRevenueSplit => 39,
+            Self::RevenueRecipientLimit => 40,
+            Self::RevenueSplitIsNotFound => 41,
+            Self::BuybackIsDisabled => 42,
+            Self::ImproperBuybackToken => 43,
+            Self::BuybackShareIsOutOfRange => 44,
+            Self::InsuranceRateIsOutOfRange => 45,
+            Self::PayoutQueueIsEmpty => 46,
+            Self::ContractCallerIsNotAllowed => 47,
+            Self::SeedChainIsNotFound => 48,
+            Self::ImproperSeed => 49,
+            Self::SeedIsRevealedInBlock => 50,
+            Self::PendingFlipsExist => 51,
+            Self::PendingFlipIsNotFound => 52,
+            Self::SeedIsNotRevealed => 53,
+            Self::RefundIsNotAvailable => 54,
+            Self::ImproperClientSeed => 55,
+            Self::CallbackIsNotAllowed => 56,
+            Self::RandomnessIsDisabled => 57,
+            Self::ImproperRandomnessFee => 58,
+            Self::ImproperJobId => 59,
+            Self::RandomnessRequestIsNotFound => 60,
+            Self::InsufficientUserBalance => 61,
+            Self::SessionIsNotFound => 62,
+            Self::SessionIsExpired => 63,
+            Self::ImproperSessionDuration => 64,
+            Self::SessionBetIsNotAllowed => 65,
+            Self::SessionSpendCapIsExceeded => 66,
+            Self::InvalidSignature => 67,
+            Self::ImproperSignedPayload => 68,
+            Self::ImproperSignedNonce => 69,
+            Self::ImproperIbcTarget => 70,
+            Self::ImproperOracleMaxAge => 71,
+            Self::InsufficientBonusBalance => 72,
+            Self::BonusCreditLimit => 73,
+            Self::ImproperPromoCodeHash => 74,
+            Self::PromoCodeIsNotFound => 75,
+            Self::PromoCodeIsRedeemed => 76,
+            Self::ImmediateSettlementIsDisabled => 77,
+            Self::SignedPayloadIsExpired => 78,
+        }
+    }
+
+    /// Human-readable part of the error message
+    pub fn description(&self) -> String {
+        match self {
+            Self::CustomError { val } => format!("Custom Error val: {:?}", val),
+            Self::Unauthorized => "Sender does not have access permissions!".to_string(),
+            Self::NoParameters => "Parameters are not provided!".to_string(),
+            Self::TransferAdminDeadline => "It's too late to accept admin role!".to_string(),
+            Self::NotEnoughLiquidity => "Not enough liquidity to withdraw funds!".to_string(),
+            Self::ChainIdIsNotFound => "Chain ID is not found!".to_string(),
+            Self::ZeroAmount => "Zero amount to send!".to_string(),
+            Self::UndefinedReplyId => "Undefined Reply ID!".to_string(),
+            Self::AssetIsNotFound => "Asset is not found!".to_string(),
+            Self::WrongAssetType => "Wrong asset type!".to_string(),
+            Self::WrongMessageType => "Wrong message type!".to_string(),
+            Self::WrongActionType => "Wrong action type!".to_string(),
+            Self::WrongFundsCombination => "Wrong funds combination!".to_string(),
+            Self::ParameterIsNotFound { value } => format!("{:?} config is not found!", value),
+            Self::ContractIsPaused => "The contract is paused".to_string(),
+            Self::DenomExists => "Denom already exists!".to_string(),
+            Self::TokenLimit => "Exceeded tokens per owner limit!".to_string(),
+            Self::BetIsOutOfRange => "Bet is out of range!".to_string(),
+            Self::ImproperMinBet => "Min bet is great than max bet!".to_string(),
+            Self::ZeroMaxBet => "Max bet can't be zero!".to_string(),
+            Self::ZeroRewardsAmount => "The user doesn't have rewards!".to_string(),
+            Self::MultipleFlipsPerTx => {
+                "A user can't flip multiple coins in single tx!".to_string()
+            }
+            Self::FeeIsOutOfRange => "Platform fee must be from 0 to 1!".to_string(),
+            Self::ParsingPrevVersion => "Parsing previous version error!".to_string(),
+            Self::ParsingNewVersion => "Parsing new version error!".to_string(),
+            Self::ImproperMsgVersion => {
+                "Msg version is not equal contract new version!".to_string()
+            }
+            Self::ImproperTierThresholds => {
+                "Tier thresholds must start from zero and increase!".to_string()
+            }
+            Self::TierRateIsOutOfRange => {
+                "Tier fee discount and cashback must be from 0 to 1!".to_string()
+            }
+            Self::TierLimit => "Exceeded tiers limit!".to_string(),
+            Self::TierIsNotFound => "Tier is not found!".to_string(),
+            Self::RakebackRateIsOutOfRange => "Rakeback rate must be from 0 to 1!".to_string(),
+            Self::ZeroRakebackEpoch => "Rakeback epoch can't be zero!".to_string(),
+            Self::NftCollectionLimit => "Exceeded NFT collections limit!".to_string(),
+            Self::NftCollectionIsNotFound => "NFT collection is not found!".to_string(),
+            Self::NftIsNotOwned => "The sender isn't NFT owner!".to_string(),
+            Self::NftDiscountIsOutOfRange => "NFT fee discount must be from 0 to 1!".to_string(),
+            Self::NftCollectionIsNotWhitelisted => {
+                "NFT collection isn't whitelisted for NFT flips!".to_string()
+            }
+            Self::HouseNftIsNotFound => "House NFT is not found!".to_string(),
+            Self::ImproperRevenueSplit => {
+                "Revenue recipients must be unique with weights summing to one!".to_string()
+            }
+            Self::RevenueRecipientLimit => "Exceeded revenue recipients limit!".to_string(),
+            Self::RevenueSplitIsNotFound => "Revenue split is not specified!".to_string(),
+            Self::BuybackIsDisabled => "Buyback is disabled!".to_string(),
+            Self::ImproperBuybackToken => "Buyback token can't be the bet denom!".to_string(),
+            Self::BuybackShareIsOutOfRange => "Buyback share must be from 0 to 1!".to_string(),
+            Self::InsuranceRateIsOutOfRange => "Insurance rate must be from 0 to 1!".to_string(),
+            Self::PayoutQueueIsEmpty => "Payout queue is empty!".to_string(),
+            Self::ContractCallerIsNotAllowed => {
+                "Contracts can't flip unless whitelisted!".to_string()
+            }
+            Self::SeedChainIsNotFound => "Seed chain isn't committed!".to_string(),
+            Self::ImproperSeed => "Seed doesn't hash to the chain head!".to_string(),
+            Self::SeedIsRevealedInBlock => {
+                "Only one seed can be revealed or committed per block!".to_string()
+            }
+            Self::PendingFlipsExist => {
+                "Seed chain can't be replaced while seeded flips or randomness requests are pending!"
+                    .to_string()
+            }
+            Self::PendingFlipIsNotFound => "Pending flip is not found!".to_string(),
+            Self::SeedIsNotRevealed => "Seed of the flip round isn't revealed!".to_string(),
+            Self::RefundIsNotAvailable => {
+                "The flip can be refunded only if the seed isn't revealed in time!".to_string()
+            }
+            Self::ImproperClientSeed => {
+                "Client seed length must be from 1 to 64 characters!".to_string()
+            }
+            Self::CallbackIsNotAllowed => {
+                "Callback contract must be whitelisted unless the flip is seeded!".to_string()
+            }
+            Self::RandomnessIsDisabled => "Randomness service is disabled!".to_string(),
+            Self::ImproperRandomnessFee => "Randomness fee must be paid exactly!".to_string(),
+            Self::ImproperJobId => "Job id length must be from 1 to 64 characters!".to_string(),
+            Self::RandomnessRequestIsNotFound => "Randomness request is not found!".to_string(),
+            Self::InsufficientUserBalance => "User balance isn't enough!".to_string(),
+            Self::SessionIsNotFound => "Session is not found!".to_string(),
+            Self::SessionIsExpired => "Session is expired!".to_string(),
+            Self::ImproperSessionDuration => {
+                "Session duration must be from 1 second to 1 day!".to_string()
+            }
+            Self::SessionBetIsNotAllowed => "Bet isn't allowed by the session!".to_string(),
+            Self::SessionSpendCapIsExceeded => "Session spend cap is exceeded!".to_string(),
+            Self::InvalidSignature => "Signature is invalid!".to_string(),
+            Self::ImproperSignedPayload => "Signed payload is improper!".to_string(),
+            Self::ImproperSignedNonce => "Signed nonce is improper!".to_string(),
+            Self::ImproperIbcTarget => {
+                "IBC target doesn't match ibc-hooks sender!".to_string()
+            }
+            Self::ImproperOracleMaxAge => "Oracle max age is improper!".to_string(),
+            Self::InsufficientBonusBalance => "Bonus balance isn't enough!".to_string(),
+            Self::BonusCreditLimit => "Exceeded bonus credits limit!".to_string(),
+            Self::ImproperPromoCodeHash => "Promo code hash must be 32 bytes!".to_string(),
+            Self::PromoCodeIsNotFound => "Promo code is not found!".to_string(),
+            Self::PromoCodeIsRedeemed => "Promo code is already redeemed!".to_string(),
+            Self::ImmediateSettlementIsDisabled => {
+                "Immediate settlement is disabled, use seeded flips!".to_string()
+            }
+            Self::SignedPayloadIsExpired => "Signed payload is expired!".to_string(),
+        }
+    }
+
+    /// Base64 encoded JSON of the error
+    pub fn payload(&self) -> String {
+        to_json_vec(self)
+            .map(|x| Binary::from(x).to_base64())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for ContractError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}{}:{}]",
+            self.description(),
+            ERROR_PAYLOAD_TAG,
+            self.code(),
+            self.payload()
+        )
+    }
 }
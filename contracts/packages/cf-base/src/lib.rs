@@ -1,8 +1,10 @@
 pub mod assets;
 pub mod constants;
 pub mod converters;
+pub mod dex;
 pub mod error;
 pub mod math;
+pub mod oracle;
 pub mod utils;
 
 pub mod platform {
@@ -0,0 +1,18 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Decimal;
+
+/// Price oracle queries used to convert bet limits from the reference currency
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum OracleQueryMsg {
+    #[returns(PriceResp)]
+    Price { denom: String },
+}
+
+#[cw_serde]
+pub struct PriceResp {
+    /// price of the denom in the reference currency
+    pub price: Decimal,
+    /// block time of the price update, seconds
+    pub updated_at: u64,
+}
@@ -1,7 +1,69 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, HexBinary, Uint128};
 
-use super::types::{Range, Side, UserInfo};
+use super::types::{
+    BonusCredit, Buyback, BuybackConfig, Callback, IbcTarget, InsuranceConfig, Nft, NftPerk,
+    OracleConfig, Outcome, PendingFlip, PromoCode, RakebackConfig, RandomnessRequest, Range,
+    RevenueRecipient, SeedChain, SessionGrant, Side, Tier, UserInfo,
+};
+
+/// Execute message sent to `Callback::contract` after the flip settlement or randomness delivery
+#[cw_serde]
+pub enum CallbackMsg {
+    FlipCallback {
+        flip_id: u64,
+        user: Addr,
+        outcome: Outcome,
+        prize: Uint128,
+        /// `Callback::msg`
+        msg: Binary,
+    },
+
+    /// sent to `Callback::contract` of the randomness request
+    RandomnessCallback {
+        request_id: u64,
+        job_id: String,
+        randomness: HexBinary,
+        /// `Callback::msg`
+        msg: Binary,
+    },
+}
+
+/// JSON signed by the user for `FlipSigned`. The bet is taken from the user balance, \
+/// `contract`, `chain_id` and `nonce` prevent the replay, `expires_at` limits the time
+/// the worker can hold the payload before relaying it
+#[cw_serde]
+pub struct SignedFlipPayload {
+    pub contract: String,
+    pub chain_id: String,
+    /// amount of signed flips relayed for the user before
+    pub nonce: u64,
+    /// block time in seconds since which the payload can't be relayed
+    pub expires_at: u64,
+    pub side: Side,
+    pub amount: Uint128,
+    pub client_seed: Option<String>,
+}
+
+/// ibc-hooks notifies the contract about its ICS-20 transfers carrying `ibc_callback` memo
+#[cw_serde]
+pub enum SudoMsg {
+    #[serde(rename = "ibc_lifecycle_complete")]
+    IbcLifecycleComplete(IbcLifecycleComplete),
+}
+
+#[cw_serde]
+pub enum IbcLifecycleComplete {
+    #[serde(rename = "ibc_ack")]
+    IbcAck {
+        channel: String,
+        sequence: u64,
+        ack: String,
+        success: bool,
+    },
+    #[serde(rename = "ibc_timeout")]
+    IbcTimeout { channel: String, sequence: u64 },
+}
 
 #[cw_serde]
 pub struct MigrateMsg {
@@ -20,14 +82,130 @@ pub enum ExecuteMsg {
     // users
     Flip {
         side: Side,
+        /// NFT of registered collection to apply its perks
+        nft: Option<Nft>,
+        /// makes the flip provably fair: it's settled by the seed revealed by the worker later, \
+        /// the outcome is derived from the seed, the client seed and the user nonce
+        client_seed: Option<String>,
+        /// the contract is notified with the flip result, its failure doesn't affect the flip. \
+        /// It must be whitelisted as contract caller unless the flip is seeded
+        callback: Option<Callback>,
+        /// the bet is taken from the user balance, funds must not be attached. \
+        /// The prize is credited to the balance instead of the transfer
+        amount: Option<Uint128>,
+        /// original chain sender of ibc-hooks flip, the sender must be its intermediate \
+        /// address. Payouts of the sender are returned to it with ICS-20 transfers
+        payout_to: Option<IbcTarget>,
+    },
+
+    /// the session key flips on behalf of the owner within the grant, the bet is taken from \
+    /// the owner balance if `amount` is specified or from attached funds. NFT perks and \
+    /// callbacks aren't supported, winnings always go to the owner
+    SessionFlip {
+        owner: String,
+        side: Side,
+        client_seed: Option<String>,
+        amount: Option<Uint128>,
+    },
+
+    /// the worker relays the flip signed by the user who may hold no gas tokens. The signature \
+    /// of sha256 hash of `SignedFlipPayload` JSON is verified with secp256k1 compressed `pubkey`, \
+    /// the user address is derived from it
+    FlipSigned {
+        payload: Binary,
+        pubkey: Binary,
+        signature: Binary,
+    },
+
+    /// allows the session key to flip on behalf of the sender until the grant expires \
+    /// or the spend cap is reached, the previous grant of the key is replaced
+    GrantSession {
+        session: String,
+        spend_cap: Uint128,
+        /// in seconds, up to `MAX_SESSION_DURATION`
+        duration: u64,
+        side: Option<Side>,
+        bet: Option<Range>,
+    },
+
+    RevokeSession {
+        session: String,
+    },
+
+    /// flips the stake from the bonus balance within the user tier bet range. The stake returns \
+    /// to the bonus balance on win, only the winnings beyond it are paid. The winnings are
+    /// credited to the bonus balance until the wagering requirement is met
+    BonusFlip {
+        side: Side,
+        amount: Uint128,
+    },
+
+    /// credits the promo code bonus to the sender, the code can be redeemed once per user
+    RedeemPromoCode {
+        code: String,
+    },
+
+    /// credits the attached funds to the user balance
+    TopUp {},
+
+    /// `None` withdraws the whole user balance
+    WithdrawBalance {
+        amount: Option<Uint128>,
+    },
+
+    /// permissionless, settles the seeded flip when the seed of its round is revealed
+    SettleFlip {
+        id: u64,
+    },
+
+    /// permissionless, refunds the seeded flip bet to the user if the seed of its round \
+    /// isn't revealed until the deadline
+    RefundFlip {
+        id: u64,
+    },
+
+    /// paid by `RandomnessFee`, 32 random bytes are sent to the callback contract. \
+    /// Deferred request is fulfilled by the seed revealed later, so the requester can't \
+    /// front-run it, otherwise the callback contract must be whitelisted as contract caller
+    RequestRandomness {
+        job_id: String,
+        callback: Callback,
+        deferred: bool,
+    },
+
+    /// permissionless, fulfills the deferred randomness request when the seed of its round \
+    /// is revealed
+    FulfillRandomness {
+        id: u64,
+    },
+
+    /// permissionless, refunds the fee of deferred randomness request to the requester if the \
+    /// seed of its round isn't revealed until the deadline
+    RefundRandomness {
+        id: u64,
     },
 
     Claim {},
 
+    ClaimRakeback {},
+
+    /// permissionless, sends realized revenue to the recipients according to their weights
+    DistributeRevenue {},
+
+    /// permissionless, pays unclaimed rewards of queued users in FIFO order, \
+    /// the limit is capped by `MAX_PAYOUT_BATCH`
+    ProcessPayouts {
+        limit: u32,
+    },
+
+    /// cw721 hook, `SendNft` of whitelisted collection with `ReceiveNftMsg` escrows the NFT
+    ReceiveNft(Cw721ReceiveMsg),
+
     // new_admin
     AcceptAdminRole {},
 
     // admin, worker
+    /// pays the payout queue batch after the deposit
     Deposit {},
 
     Withdraw {
@@ -35,6 +213,38 @@ pub enum ExecuteMsg {
         recipient: Option<String>,
     },
 
+    DepositInsurance {},
+
+    /// admin only, the insurance can't be withdrawn below the target
+    WithdrawInsurance {
+        amount: Option<Uint128>,
+        recipient: Option<String>,
+    },
+
+    /// swaps the share of realized revenue into the buyback token and burns it, \
+    /// slippage limits are passed to the pair
+    Buyback {
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+    },
+
+    /// commits the hash chain head, it can't be replaced while seeded flips are pending
+    CommitSeedChain {
+        head: HexBinary,
+    },
+
+    /// reveals the next seed of the chain, it must hash to the previous one
+    RevealSeed {
+        seed: HexBinary,
+    },
+
+    /// queues users with unclaimed rewards accrued before the payout queue was added. Users are \
+    /// scanned in address order after `start_after`, the limit is capped by `MAX_PAYOUT_BATCH`
+    EnqueuePayouts {
+        start_after: Option<String>,
+        limit: u32,
+    },
+
     UpdateConfig {
         admin: Option<String>,
         worker: Option<String>,
@@ -42,11 +252,113 @@ pub enum ExecuteMsg {
         platform_fee: Option<Decimal>,
     },
 
+    /// replaces all tiers, empty list disables them
+    UpdateTiers {
+        tiers: Vec<Tier>,
+    },
+
+    /// assigns the tier level to the user, `None` returns reached level
+    SetUserTier {
+        address: String,
+        tier: Option<u8>,
+    },
+
+    /// `None` disables rakeback, accrued rakeback can be claimed anyway
+    UpdateRakeback {
+        rakeback: Option<RakebackConfig>,
+    },
+
+    /// `None` stops filling the insurance, the reserve is kept until it's withdrawn
+    UpdateInsurance {
+        insurance: Option<InsuranceConfig>,
+    },
+
+    /// contracts can revert lost flips in a submessage reply, so they are rejected unless \
+    /// whitelisted. `None` allows any contract to flip. It doesn't stop a sender following the \
+    /// flip with a message to its contract reverting the tx on a loss, see `UpdateDeferredOnly`
+    UpdateContractCallers {
+        whitelist: Option<Vec<String>>,
+    },
+
+    /// rejects flips settled in the same tx (immediate, NFT and bonus flips) and immediate \
+    /// randomness requests if enabled. Their outcome is known in the tx, so the sender can revert \
+    /// it on a loss with the next message. Seeded flips and deferred requests aren't affected
+    UpdateDeferredOnly {
+        enabled: bool,
+    },
+
+    /// `None` disables randomness service, fulfilling of deferred requests isn't affected
+    UpdateRandomnessFee {
+        fee: Option<Uint128>,
+    },
+
+    /// registers the collection or updates its perk, `None` removes the collection
+    UpdateNftPerk {
+        collection: String,
+        perk: Option<NftPerk>,
+    },
+
+    /// replaces the revenue recipients, empty list disables the distribution
+    UpdateRevenueSplit {
+        recipients: Vec<RevenueRecipient>,
+    },
+
+    /// `None` disables buyback
+    UpdateBuyback {
+        buyback: Option<BuybackConfig>,
+    },
+
+    /// `None` disables the oracle, `Config::bet` is used as the base bet range
+    UpdateOracle {
+        oracle: Option<OracleConfig>,
+    },
+
+    /// credits non-withdrawable bonuses, up to `MAX_BONUS_CREDITS` users. The wagering \
+    /// requirement of each user is increased by `wagering * amount`
+    CreditBonus {
+        credits: Vec<BonusCredit>,
+        wagering: u32,
+    },
+
+    /// adds or updates the promo code by sha256 hash of the code, `None` removes it
+    UpdatePromoCode {
+        hash: HexBinary,
+        promo: Option<PromoCode>,
+    },
+
+    /// sets the floor price of the collection which is paid as the prize of NFT flip. \
+    /// Only the admin can whitelist the collection or remove it with `None`
+    UpdateNftFloorPrice {
+        collection: String,
+        price: Option<Uint128>,
+    },
+
+    /// withdraws the NFT lost by the user, the admin is the default recipient
+    WithdrawNft {
+        collection: String,
+        token_id: String,
+        recipient: Option<String>,
+    },
+
     Pause {},
 
     Unpause {},
 }
 
+/// Hook message sent by cw721 contract on `SendNft`, the same as `cw721::Cw721ReceiveMsg`
+#[cw_serde]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+#[cw_serde]
+pub enum ReceiveNftMsg {
+    /// the NFT is returned with the floor price prize on win and is kept by the house on lose
+    Flip { side: Side },
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -70,6 +382,108 @@ pub enum QueryMsg {
         amount: u32,
         start_after: Option<String>,
     },
+
+    #[returns(Vec<Tier>)]
+    Tiers {},
+
+    #[returns(UserTierResp)]
+    UserTier { address: String },
+
+    #[returns(Uint128)]
+    UserBalance { address: String },
+
+    #[returns(super::types::BonusInfo)]
+    UserBonus { address: String },
+
+    /// nonce of the next signed flip payload of the user
+    #[returns(u64)]
+    SignedNonce { address: String },
+
+    /// original chain sender of ibc-hooks intermediate address
+    #[returns(Option<IbcTarget>)]
+    IbcSender { address: String },
+
+    #[returns(Vec<SessionGrantsRespItem>)]
+    SessionGrants {
+        owner: String,
+        amount: u32,
+        start_after: Option<String>,
+    },
+
+    #[returns(PayoutQueuePositionResp)]
+    PayoutQueuePosition { address: String },
+
+    #[returns(Option<SeedChain>)]
+    SeedChain {},
+
+    #[returns(Option<HexBinary>)]
+    RevealedSeed { round: u64 },
+
+    #[returns(Vec<PendingFlipsRespItem>)]
+    PendingFlips {
+        amount: u32,
+        start_after: Option<u64>,
+    },
+
+    #[returns(Option<Uint128>)]
+    RandomnessFee {},
+
+    #[returns(Vec<RandomnessRequestsRespItem>)]
+    RandomnessRequests {
+        amount: u32,
+        start_after: Option<u64>,
+    },
+
+    #[returns(Option<RakebackConfig>)]
+    Rakeback {},
+
+    #[returns(UserRakebackResp)]
+    UserRakeback { address: String },
+
+    #[returns(InsuranceResp)]
+    Insurance {},
+
+    #[returns(Option<Vec<Addr>>)]
+    ContractCallers {},
+
+    #[returns(bool)]
+    DeferredOnly {},
+
+    #[returns(Vec<NftPerksRespItem>)]
+    NftPerks {},
+
+    #[returns(Vec<RevenueSplitRespItem>)]
+    RevenueSplit {},
+
+    #[returns(Option<BuybackConfig>)]
+    Buyback {},
+
+    #[returns(Option<OracleConfig>)]
+    Oracle {},
+
+    /// base bet range converted with the oracle price, it's used while tiers aren't set and bounds
+    /// tier ranges
+    #[returns(BetRangeResp)]
+    BetRange {},
+
+    /// promo code by sha256 hash of the code
+    #[returns(Option<PromoCode>)]
+    PromoCode { hash: HexBinary },
+
+    #[returns(Vec<BuybackListRespItem>)]
+    BuybackList {
+        amount: u32,
+        start_after: Option<u64>,
+    },
+
+    #[returns(Vec<NftFloorPriceRespItem>)]
+    NftFloorPrices {},
+
+    #[returns(Vec<HouseNftRespItem>)]
+    HouseNfts {
+        amount: u32,
+        start_after: Option<Nft>,
+    },
 }
 
 #[cw_serde]
@@ -77,3 +491,110 @@ pub struct UserListRespItem {
     pub address: Addr,
     pub info: UserInfo,
 }
+
+#[cw_serde]
+pub struct UserTierResp {
+    pub level: u8,
+    pub tier: Tier,
+    /// the level is assigned by the admin
+    pub is_assigned: bool,
+    /// lifetime bets value
+    pub bets_value: Uint128,
+    /// progress to the next tier by lifetime bets value
+    pub next: Option<TierProgress>,
+}
+
+#[cw_serde]
+pub struct TierProgress {
+    pub level: u8,
+    pub threshold: Uint128,
+    pub remaining: Uint128,
+    /// progress = bets_value / threshold
+    pub progress: Decimal,
+}
+
+#[cw_serde]
+pub struct PayoutQueuePositionResp {
+    /// starts from 1, `None` if the user isn't in the queue. It's the distance from the queue
+    /// head, so users ahead who claimed by themselves are counted until the head passes them
+    pub position: Option<u32>,
+    /// queued users
+    pub length: u32,
+    pub unclaimed: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingFlipsRespItem {
+    pub id: u64,
+    pub flip: PendingFlip,
+}
+
+#[cw_serde]
+pub struct RandomnessRequestsRespItem {
+    pub id: u64,
+    pub request: RandomnessRequest,
+}
+
+#[cw_serde]
+pub struct SessionGrantsRespItem {
+    pub session: Addr,
+    pub grant: SessionGrant,
+}
+
+#[cw_serde]
+pub struct UserRakebackResp {
+    pub epoch: u64,
+    /// claimable rakeback including finished and not settled epochs
+    pub accrued: Uint128,
+    /// rakeback for the current epoch net loss, it will be accrued when the epoch is finished
+    pub pending: Uint128,
+}
+
+#[cw_serde]
+pub struct InsuranceResp {
+    pub config: Option<InsuranceConfig>,
+    pub reserve: Uint128,
+    /// reserve excess above the target
+    pub available_to_withdraw: Uint128,
+}
+
+#[cw_serde]
+pub struct NftPerksRespItem {
+    pub collection: Addr,
+    pub perk: NftPerk,
+}
+
+#[cw_serde]
+pub struct RevenueSplitRespItem {
+    pub address: Addr,
+    pub weight: Decimal,
+    /// lifetime distributed revenue
+    pub distributed: Uint128,
+}
+
+#[cw_serde]
+pub struct BetRangeResp {
+    pub bet: Range,
+    /// `None` if `Config::bet` is used: the oracle isn't specified, fails or its price is stale
+    pub price: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct BuybackListRespItem {
+    pub id: u64,
+    pub buyback: Buyback,
+}
+
+#[cw_serde]
+pub struct NftFloorPriceRespItem {
+    pub collection: Addr,
+    pub price: Uint128,
+}
+
+#[cw_serde]
+pub struct HouseNftRespItem {
+    pub collection: Addr,
+    pub token_id: String,
+    /// floor price at the moment of the flip
+    pub price: Uint128,
+}
@@ -1,7 +1,11 @@
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Uint128};
 use cw_storage_plus::{Item, Map};
 
-use super::types::{AppInfo, Config, TransferAdminState, UserInfo};
+use super::types::{
+    AppInfo, BonusInfo, Buyback, BuybackConfig, Config, IbcPayout, IbcTarget, InsuranceConfig,
+    NftPerk, OracleConfig, PendingFlip, PromoCode, RakebackConfig, RakebackInfo, RandomnessRequest,
+    RevenueRecipient, SeedChain, SessionGrant, Tier, TransferAdminState, UserInfo,
+};
 
 pub const CONTRACT_NAME: &str = "CryptoGopniks: OMies CoinFlip";
 
@@ -12,11 +16,110 @@ pub const DENOM: &str = "uom";
 pub const PLATFORM_FEE: &str = "0.1";
 pub const FLIP_COOLDOWN: u64 = 3;
 pub const TRANSFER_ADMIN_TIMEOUT: u64 = 7 * 24 * 3_600;
+pub const MAX_TIERS: usize = 10;
+pub const MAX_NFT_COLLECTIONS: usize = 5;
+pub const MAX_REVENUE_RECIPIENTS: usize = 5;
+/// users credited by single `CreditBonus`
+pub const MAX_BONUS_CREDITS: usize = 50;
+/// users paid from the payout queue on deposit
+pub const PAYOUT_BATCH: u32 = 10;
+/// `ProcessPayouts` limit is capped by it
+pub const MAX_PAYOUT_BATCH: u32 = 30;
+/// seeded flip bet can be refunded if the seed isn't revealed in time
+pub const REVEAL_TIMEOUT: u64 = 3_600;
+pub const MAX_CLIENT_SEED_LEN: usize = 64;
+pub const MAX_JOB_ID_LEN: usize = 64;
+/// ICS-20 payout timeout, the funds are returned to the user unclaimed after it
+pub const IBC_TRANSFER_TIMEOUT: u64 = 3_600;
+/// session keys are short-lived
+pub const MAX_SESSION_DURATION: u64 = 24 * 3_600;
+
+/// callback can't consume the flip tx gas
+pub const CALLBACK_GAS_LIMIT: u64 = 500_000;
+
+pub const BUYBACK_REPLY_ID: u64 = 1;
+pub const FLIP_CALLBACK_REPLY_ID: u64 = 2;
+pub const RANDOMNESS_CALLBACK_REPLY_ID: u64 = 3;
+pub const IBC_PAYOUT_REPLY_ID: u64 = 4;
 
 pub const IS_PAUSED: Item<bool> = Item::new("is_paused");
 pub const TRANSFER_ADMIN_STATE: Item<TransferAdminState> = Item::new("transfer_admin_state");
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const NORMALIZED_DECIMAL: Item<Decimal> = Item::new("normalized_decimal");
 pub const APP_INFO: Item<AppInfo> = Item::new("app_info");
+/// sorted by threshold, `Config::bet` is used if empty
+pub const TIERS: Item<Vec<Tier>> = Item::new("tiers");
+/// rakeback is disabled if it isn't specified
+pub const RAKEBACK_CONFIG: Item<Option<RakebackConfig>> = Item::new("rakeback_config");
+/// the insurance isn't filled and can be fully withdrawn if it isn't specified
+pub const INSURANCE_CONFIG: Item<Option<InsuranceConfig>> = Item::new("insurance_config");
+/// `Config::bet` is used if it isn't specified
+pub const ORACLE_CONFIG: Item<Option<OracleConfig>> = Item::new("oracle_config");
+/// buyback is disabled if it isn't specified
+pub const BUYBACK_CONFIG: Item<Option<BuybackConfig>> = Item::new("buyback_config");
+/// token balance before the swap, it's removed in the reply
+pub const BUYBACK_BALANCE: Item<Uint128> = Item::new("buyback_balance");
+/// id of the next user added to the payout queue
+pub const PAYOUT_QUEUE_ID: Item<u64> = Item::new("payout_queue_id");
+/// amount of queued users, it's counted on enqueue and dequeue to avoid the queue scan
+pub const PAYOUT_QUEUE_LENGTH: Item<u32> = Item::new("payout_queue_length");
+/// contracts allowed to flip, other contracts are rejected. Any contract can flip if it isn't
+/// specified
+pub const CONTRACT_CALLERS: Item<Option<Vec<Addr>>> = Item::new("contract_callers");
+/// flips and randomness settled in the request tx are rejected if it's enabled, their result
+/// could be used to revert the tx on a loss. It's disabled if it isn't specified
+pub const DEFERRED_ONLY: Item<bool> = Item::new("deferred_only");
+/// seeded flips are disabled if it isn't specified
+pub const SEED_CHAIN: Item<Option<SeedChain>> = Item::new("seed_chain");
+/// id of the next seeded flip or flip with callback
+pub const FLIP_ID: Item<u64> = Item::new("flip_id");
+/// randomness service is disabled if it isn't specified
+pub const RANDOMNESS_FEE: Item<Option<Uint128>> = Item::new("randomness_fee");
+/// id of the next randomness request
+pub const RANDOMNESS_REQUEST_ID: Item<u64> = Item::new("randomness_request_id");
+/// weights sum to one, revenue can't be distributed if it's empty
+pub const REVENUE_SPLIT: Item<Vec<RevenueRecipient>> = Item::new("revenue_split");
 
 pub const USERS: Map<&Addr, UserInfo> = Map::new("users");
+/// tier levels assigned by the admin, they override reached levels
+pub const ASSIGNED_TIERS: Map<&Addr, u8> = Map::new("assigned_tiers");
+pub const RAKEBACK: Map<&Addr, RakebackInfo> = Map::new("rakeback");
+/// FIFO queue of users with unclaimed rewards by queue id
+pub const PAYOUT_QUEUE: Map<u64, Addr> = Map::new("payout_queue");
+/// queue id of the user, it's removed when unclaimed rewards are paid
+pub const PAYOUT_QUEUE_IDS: Map<&Addr, u64> = Map::new("payout_queue_ids");
+/// seeded flips by id, they are removed when settled or refunded
+pub const PENDING_FLIPS: Map<u64, PendingFlip> = Map::new("pending_flips");
+/// revealed seeds by round
+pub const REVEALED_SEEDS: Map<u64, HexBinary> = Map::new("revealed_seeds");
+/// in-contract user balances, flips can be made from them without attached funds
+pub const USER_BALANCES: Map<&Addr, Uint128> = Map::new("user_balances");
+/// non-withdrawable user bonuses
+pub const BONUS_BALANCES: Map<&Addr, BonusInfo> = Map::new("bonus_balances");
+/// promo codes by sha256 hash of the code
+pub const PROMO_CODES: Map<&[u8], PromoCode> = Map::new("promo_codes");
+/// redemption time by promo code hash and user, the code can be redeemed once per user
+pub const PROMO_REDEMPTIONS: Map<(&[u8], &Addr), u64> = Map::new("promo_redemptions");
+/// original chain senders of ibc-hooks intermediate addresses
+pub const IBC_SENDERS: Map<&Addr, IbcTarget> = Map::new("ibc_senders");
+/// sent ICS-20 payouts by channel and packet sequence, they are removed on the acknowledgement
+/// or timeout
+pub const IBC_PAYOUTS: Map<(&str, u64), IbcPayout> = Map::new("ibc_payouts");
+/// session grants by owner and session key
+pub const SESSION_GRANTS: Map<(&Addr, &Addr), SessionGrant> = Map::new("session_grants");
+/// amount of signed flips relayed for the user, it's the nonce of the next signed payload
+pub const SIGNED_NONCES: Map<&Addr, u64> = Map::new("signed_nonces");
+/// amount of seeded flips made by the user
+pub const USER_NONCES: Map<&Addr, u64> = Map::new("user_nonces");
+/// deferred randomness requests by id, they are removed when fulfilled
+pub const RANDOMNESS_REQUESTS: Map<u64, RandomnessRequest> = Map::new("randomness_requests");
+/// registered cw721 collections granting perks to holders
+pub const NFT_PERKS: Map<&Addr, NftPerk> = Map::new("nft_perks");
+/// cw721 collections whitelisted for NFT flips with their floor prices
+pub const NFT_FLOOR_PRICES: Map<&Addr, Uint128> = Map::new("nft_floor_prices");
+/// NFTs lost by users with floor prices at the moment of the flip
+pub const HOUSE_NFTS: Map<(&Addr, &str), Uint128> = Map::new("house_nfts");
+/// lifetime distributed revenue per recipient, it's kept after removing the recipient
+pub const DISTRIBUTED_REVENUE: Map<&Addr, Uint128> = Map::new("distributed_revenue");
+/// buyback history by id, the last record is completed in the swap reply
+pub const BUYBACKS: Map<u64, Buyback> = Map::new("buybacks");
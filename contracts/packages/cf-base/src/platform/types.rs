@@ -1,7 +1,12 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Int256, SignedDecimal, StdResult, Uint128};
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    Addr, Binary, Decimal, HexBinary, Int256, SignedDecimal, StdError, StdResult, Uint128,
+};
 
 use crate::{
+    assets::TokenUnverified,
     converters::{str_to_dec, str_to_sdec, u128_to_dec},
     error::ContractError,
 };
@@ -12,6 +17,27 @@ pub enum Side {
     Tail,
 }
 
+impl std::fmt::Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Head => write!(f, "head"),
+            Self::Tail => write!(f, "tail"),
+        }
+    }
+}
+
+impl FromStr for Side {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> StdResult<Self> {
+        match s {
+            "head" => Ok(Self::Head),
+            "tail" => Ok(Self::Tail),
+            _ => Err(StdError::parse_err("Side", s)),
+        }
+    }
+}
+
 impl Side {
     pub fn is_winner(&self, random_weight: Decimal, platform_fee: Decimal) -> bool {
         let offset = platform_fee / u128_to_dec(2_u128);
@@ -25,6 +51,65 @@ impl Side {
     }
 }
 
+/// How the prize was paid
+#[cw_serde]
+pub enum Payout {
+    /// the user has lost
+    None,
+    /// the prize was sent or credited to the user balance in the same tx
+    AutoPaid,
+    /// the prize was added to user unclaimed because of liquidity deficiency
+    Unclaimed,
+}
+
+impl std::fmt::Display for Payout {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::AutoPaid => write!(f, "auto_paid"),
+            Self::Unclaimed => write!(f, "unclaimed"),
+        }
+    }
+}
+
+impl FromStr for Payout {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> StdResult<Self> {
+        match s {
+            "none" => Ok(Self::None),
+            "auto_paid" => Ok(Self::AutoPaid),
+            "unclaimed" => Ok(Self::Unclaimed),
+            _ => Err(StdError::parse_err("Payout", s)),
+        }
+    }
+}
+
+#[cw_serde]
+pub enum Outcome {
+    Win,
+    Lose,
+}
+
+impl From<&Payout> for Outcome {
+    fn from(payout: &Payout) -> Self {
+        if payout == &Payout::None {
+            Self::Lose
+        } else {
+            Self::Win
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Win => write!(f, "win"),
+            Self::Lose => write!(f, "lose"),
+        }
+    }
+}
+
 #[derive(Default)]
 #[cw_serde]
 pub struct Stats {
@@ -69,6 +154,48 @@ impl UserInfo {
     pub fn update_roi(&mut self) {
         self.roi = get_user_roi(&self.stats.bets, &self.stats.wins);
     }
+
+    /// Must be called after `AppInfo::settle_flip` with its payout
+    pub fn settle_flip(&mut self, bet: Uint128, prize: Uint128, payout: &Payout, block_time: u64) {
+        if payout == &Payout::Unclaimed {
+            self.unclaimed += prize;
+        }
+
+        if !prize.is_zero() {
+            self.stats.wins.increase(prize);
+        }
+
+        self.stats.bets.increase(bet);
+        self.update_roi();
+        self.last_flip_date = block_time;
+    }
+
+    /// Must be called after `AppInfo::add_cashback`
+    pub fn add_cashback(&mut self, amount: Uint128) {
+        self.unclaimed += amount;
+    }
+
+    /// Must be called after `AppInfo::settle_nft_flip` with its payout
+    pub fn settle_nft_flip(&mut self, prize: Uint128, payout: &Payout, block_time: u64) {
+        if payout == &Payout::Unclaimed {
+            self.unclaimed += prize;
+        }
+
+        self.last_flip_date = block_time;
+    }
+
+    /// Must be called after `AppInfo::pay_reveal_penalty` with its payout
+    pub fn add_reveal_penalty(&mut self, penalty: Uint128, payout: &Payout) {
+        if payout == &Payout::Unclaimed {
+            self.unclaimed += penalty;
+        }
+    }
+
+    /// Must be called after `AppInfo::settle_bonus_flip` with its payout, only the winnings beyond
+    /// the stake are added to unclaimed
+    pub fn settle_bonus_flip(&mut self, winnings: Uint128, payout: &Payout, block_time: u64) {
+        self.settle_nft_flip(winnings, payout, block_time);
+    }
 }
 
 #[derive(Default)]
@@ -83,19 +210,44 @@ pub struct Revenue {
 #[derive(Default)]
 #[cw_serde]
 pub struct AppInfo {
+    // fields added after 1.0.0 are defaulted to read the state saved by it
     /// total user stats
     pub user_stats: Stats,
     /// total user unclaimed
     pub user_unclaimed: Uint128,
 
+    /// total accrued and not claimed user rakeback
+    #[serde(default)]
+    pub user_rakeback: Uint128,
+
+    /// reserve used to pay winners if the rest of the balance isn't enough
+    #[serde(default)]
+    pub insurance: Uint128,
+
+    /// bets of seeded flips and fees of deferred randomness requests waiting for the seed reveal, \
+    /// they aren't included in the balance
+    #[serde(default)]
+    pub pending_bets: Uint128,
+
+    /// total in-contract user balances, they can be withdrawn any time
+    #[serde(default)]
+    pub user_balances: Uint128,
+
+    /// total user bonus balances, they aren't backed by tokens and can't be withdrawn, \
+    /// so they aren't included in the balance
+    #[serde(default)]
+    pub bonus_balances: Uint128,
+
     /// average_fee = 1 - user_wins / user_bets
     pub average_fee: SignedDecimal,
     /// increased on deposit
     /// decreased on withdraw
     pub deposited: Uint128,
-    /// balance = revenue_current + deposited + user_unclaimed
-    /// increased on deposit, flip-lose
-    /// decreased on withdraw, flip-win (with auto claim), claim
+    /// balance = revenue_current + deposited + user_unclaimed + user_rakeback + insurance +
+    /// user_balances
+    /// increased on deposit, insurance deposit, flip-lose, randomness fee, top up
+    /// decreased on withdraw, insurance withdraw, flip-win (with auto claim), nft-flip-win,
+    /// bonus-flip-win, claim, claim rakeback, revenue distribution, balance withdrawal
     pub balance: Uint128,
     /// revenue_total ≈ platform_fee * total_bets
     pub revenue: Revenue,
@@ -105,6 +257,219 @@ impl AppInfo {
     pub fn update_average_fee(&mut self) {
         self.average_fee = -get_user_roi(&self.user_stats.bets, &self.user_stats.wins);
     }
+
+    /// Accepts the bet and the prize (zero on lose), returns how the prize must be paid. \
+    /// The prize is paid automatically if the balance is enough, otherwise it's added to unclaimed
+    pub fn settle_flip(&mut self, bet: Uint128, prize: Uint128) -> Payout {
+        let mut payout = Payout::None;
+
+        self.revenue.total += Int256::from(bet);
+        self.revenue.current += Int256::from(bet);
+        self.balance += bet;
+
+        if !prize.is_zero() {
+            self.revenue.total -= Int256::from(prize);
+            self.revenue.current -= Int256::from(prize);
+            payout = self.pay_prize(prize);
+            self.user_stats.wins.increase(prize);
+        }
+
+        self.user_stats.bets.increase(bet);
+        self.update_average_fee();
+
+        payout
+    }
+
+    /// Accepts the prize of NFT flip (zero on lose), returns how the prize must be paid. \
+    /// NFT flips aren't included in the stats as the bet isn't paid in the denom
+    pub fn settle_nft_flip(&mut self, prize: Uint128) -> Payout {
+        if prize.is_zero() {
+            return Payout::None;
+        }
+
+        self.revenue.total -= Int256::from(prize);
+        self.revenue.current -= Int256::from(prize);
+        self.pay_prize(prize)
+    }
+
+    /// Accepts the stake of bonus flip and the prize (zero on lose), returns how the winnings must
+    /// be paid. The stake returns to the bonus balance on win, only the winnings beyond it are paid
+    /// from the revenue. Locked winnings are credited to the bonus balance instead until the
+    /// wagering requirement is met. Bonus flips aren't included in the stats as the bet isn't paid
+    /// in the denom
+    pub fn settle_bonus_flip(&mut self, stake: Uint128, prize: Uint128, is_locked: bool) -> Payout {
+        if prize.is_zero() {
+            self.bonus_balances -= stake;
+            return Payout::None;
+        }
+
+        let winnings = prize - stake;
+        if is_locked {
+            self.credit_bonus(winnings);
+            return Payout::None;
+        }
+
+        self.revenue.total -= Int256::from(winnings);
+        self.revenue.current -= Int256::from(winnings);
+        self.pay_prize(winnings)
+    }
+
+    /// Accepts the penalty for the missed reveal of refunded seeded flip, returns how it must be
+    /// paid. The house pays the bet as the winnings, so withholding the seed of a lost flip
+    /// never saves it the prize
+    pub fn pay_reveal_penalty(&mut self, penalty: Uint128) -> Payout {
+        self.revenue.total -= Int256::from(penalty);
+        self.revenue.current -= Int256::from(penalty);
+        self.pay_prize(penalty)
+    }
+
+    /// Bonus is credited without the funds, the balance isn't changed
+    pub fn credit_bonus(&mut self, amount: Uint128) {
+        self.bonus_balances += amount;
+    }
+
+    /// Liquidity for prizes and claims. User balances and accrued rakeback are reserved for their
+    /// owners, the insurance is included as it covers the shortfall
+    pub fn payable(&self) -> Uint128 {
+        self.balance
+            .saturating_sub(self.user_balances + self.user_rakeback)
+    }
+
+    /// The prize is paid automatically if the payable liquidity is enough, otherwise it's added
+    /// to unclaimed
+    fn pay_prize(&mut self, prize: Uint128) -> Payout {
+        if self.payable() < prize {
+            self.user_unclaimed += prize;
+            return Payout::Unclaimed;
+        }
+
+        self.spend(prize);
+        Payout::AutoPaid
+    }
+
+    /// Spends the payable liquidity excluding the insurance first, the insurance covers the
+    /// shortfall moving it to the revenue to keep the balance formula
+    fn spend(&mut self, amount: Uint128) {
+        let shortfall = amount.saturating_sub(self.payable().saturating_sub(self.insurance));
+
+        self.insurance -= shortfall;
+        self.revenue.current += Int256::from(shortfall);
+        self.balance -= amount;
+    }
+
+    /// Moves part of the lost bet from the revenue to the insurance, the balance isn't changed
+    pub fn fill_insurance(&mut self, amount: Uint128) {
+        self.revenue.current -= Int256::from(amount);
+        self.insurance += amount;
+    }
+
+    pub fn deposit_insurance(&mut self, amount: Uint128) {
+        self.insurance += amount;
+        self.balance += amount;
+    }
+
+    pub fn withdraw_insurance(&mut self, amount: Uint128) {
+        self.insurance -= amount;
+        self.balance -= amount;
+    }
+
+    /// Moves part of the lost bet from the revenue to user unclaimed, the balance isn't changed
+    pub fn add_cashback(&mut self, amount: Uint128) {
+        self.revenue.total -= Int256::from(amount);
+        self.revenue.current -= Int256::from(amount);
+        self.user_unclaimed += amount;
+    }
+
+    pub fn claim(&mut self, amount: Uint128) {
+        self.spend(amount);
+        self.user_unclaimed -= amount;
+    }
+
+    /// Accepts the funds of failed ICS-20 payout refunded by the transfer module, they are owed
+    /// to the user again
+    pub fn return_payout(&mut self, amount: Uint128) {
+        self.balance += amount;
+        self.user_unclaimed += amount;
+    }
+
+    /// Moves accrued rakeback from the revenue to user rakeback, the balance isn't changed
+    pub fn add_rakeback(&mut self, amount: Uint128) {
+        self.revenue.total -= Int256::from(amount);
+        self.revenue.current -= Int256::from(amount);
+        self.user_rakeback += amount;
+    }
+
+    /// Randomness fee is the revenue without the bet
+    pub fn add_randomness_fee(&mut self, amount: Uint128) {
+        self.revenue.total += Int256::from(amount);
+        self.revenue.current += Int256::from(amount);
+        self.balance += amount;
+    }
+
+    pub fn top_up(&mut self, amount: Uint128) {
+        self.user_balances += amount;
+        self.balance += amount;
+    }
+
+    /// User balances are always backed, so the withdrawal doesn't touch other funds
+    pub fn withdraw_user_balance(&mut self, amount: Uint128) {
+        self.user_balances -= amount;
+        self.balance -= amount;
+    }
+
+    /// The bet from the user balance is taken as if it's withdrawn, then it's settled or pending
+    /// as the bet sent with the flip. Auto paid prize is credited back with `top_up`
+    pub fn take_balance_bet(&mut self, amount: Uint128) {
+        self.withdraw_user_balance(amount);
+    }
+
+    /// The bet of seeded flip is added to the balance when the flip is settled, the fee of deferred
+    /// randomness request is added to the revenue when the request is fulfilled
+    pub fn add_pending_bet(&mut self, amount: Uint128) {
+        self.pending_bets += amount;
+    }
+
+    /// Removes the bet of settled or refunded seeded flip or the fee of fulfilled or refunded
+    /// randomness request
+    pub fn remove_pending_bet(&mut self, amount: Uint128) {
+        self.pending_bets -= amount;
+    }
+
+    /// Liquidity for rakeback claims, user balances, unclaimed winnings and the insurance aren't
+    /// used. Pending bets aren't included in the balance until they're settled
+    pub fn rakeback_payable(&self) -> Uint128 {
+        self.balance
+            .saturating_sub(self.user_balances + self.user_unclaimed + self.insurance)
+    }
+
+    pub fn claim_rakeback(&mut self, amount: Uint128) {
+        self.user_rakeback -= amount;
+        self.balance -= amount;
+    }
+
+    /// Sends realized revenue to the recipients, deposited funds aren't changed
+    pub fn distribute_revenue(&mut self, amount: Uint128) {
+        self.balance -= amount;
+        self.revenue.current -= Int256::from(amount);
+    }
+
+    pub fn deposit(&mut self, amount: Uint128) {
+        self.deposited += amount;
+        self.balance += amount;
+    }
+
+    /// Withdraws deposited first, then current revenue
+    pub fn withdraw(&mut self, amount: Uint128) {
+        self.balance -= amount;
+
+        if self.deposited >= amount {
+            self.deposited -= amount;
+        } else {
+            let diff = Int256::from(amount - self.deposited);
+            self.deposited = Uint128::zero();
+            self.revenue.current -= diff;
+        }
+    }
 }
 
 #[cw_serde]
@@ -124,13 +489,21 @@ impl Range {
         }
     }
 
-    pub fn validate(&self, bet: Uint128) -> StdResult<()> {
+    pub fn validate(&self, bet: Uint128) -> Result<(), ContractError> {
         if bet < self.min || bet > self.max {
             Err(ContractError::BetIsOutOfRange)?;
         }
 
         Ok(())
     }
+
+    /// Moves both limits into the bounds, the result is never empty
+    pub fn clamp(&self, bounds: &Range) -> Self {
+        Self {
+            min: self.min.clamp(bounds.min, bounds.max),
+            max: self.max.clamp(bounds.min, bounds.max),
+        }
+    }
 }
 
 #[cw_serde]
@@ -142,8 +515,400 @@ pub struct Config {
     pub platform_fee: Decimal,
 }
 
+/// User tier (VIP level) reached by lifetime bets value or assigned by the admin
+#[cw_serde]
+pub struct Tier {
+    /// lifetime bets value required to reach the tier, the first tier must start from zero
+    pub threshold: Uint128,
+    pub bet: Range,
+    /// part of `platform_fee` the user doesn't pay, from 0 to 1
+    pub fee_discount: Decimal,
+    /// part of the lost bet added to user unclaimed, from 0 to 1
+    pub cashback: Option<Decimal>,
+}
+
+impl Tier {
+    /// Base tier used when tiers aren't specified
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            threshold: Uint128::zero(),
+            bet: config.bet.to_owned(),
+            fee_discount: Decimal::zero(),
+            cashback: None,
+        }
+    }
+
+    /// fee = platform_fee * (1 - fee_discount)
+    pub fn get_fee(&self, platform_fee: Decimal) -> Decimal {
+        platform_fee * (Decimal::one() - self.fee_discount)
+    }
+
+    pub fn calc_cashback(&self, bet: Uint128) -> Uint128 {
+        self.cashback.map(|x| bet.mul_floor(x)).unwrap_or_default()
+    }
+}
+
+/// NFT granting perks, it must be owned by the sender
+#[cw_serde]
+pub struct Nft {
+    pub collection: String,
+    pub token_id: String,
+}
+
+/// Contract receiving `CallbackMsg::FlipCallback` after the flip settlement
+#[cw_serde]
+pub struct Callback {
+    pub contract: String,
+    /// passed back to the contract as is
+    pub msg: Binary,
+}
+
+/// Perks for holders of registered cw721 collection
+#[cw_serde]
+pub struct NftPerk {
+    /// part of `platform_fee` the holder doesn't pay in addition to tier discount, from 0 to 1
+    pub fee_discount: Decimal,
+    /// max bet for the holder, it's used if it's greater than tier max bet
+    pub bet_max: Option<Uint128>,
+}
+
+impl NftPerk {
+    /// Combines the perk with user tier: fee_discount = 1 - (1 - tier_discount) * (1 - nft_discount)
+    pub fn apply(&self, tier: Tier) -> Tier {
+        let bet_max = match self.bet_max {
+            Some(x) if x > tier.bet.max => x,
+            _ => tier.bet.max,
+        };
+
+        Tier {
+            bet: Range {
+                min: tier.bet.min,
+                max: bet_max,
+            },
+            fee_discount: Decimal::one()
+                - (Decimal::one() - tier.fee_discount) * (Decimal::one() - self.fee_discount),
+            ..tier
+        }
+    }
+}
+
+/// Part of user net loss (bets - wins) per epoch returned as rakeback
+#[cw_serde]
+pub struct RakebackConfig {
+    /// from 0 to 1
+    pub rate: Decimal,
+    /// epoch duration in seconds, epochs are counted from unix epoch
+    pub epoch: u64,
+}
+
+impl RakebackConfig {
+    pub fn get_epoch(&self, block_time: u64) -> u64 {
+        block_time / self.epoch
+    }
+}
+
+/// Reserve filled by part of the lost bets to pay winners when the balance isn't enough
+#[cw_serde]
+pub struct InsuranceConfig {
+    /// part of the lost bet moved to the insurance, from 0 to 1
+    pub rate: Decimal,
+    /// the insurance isn't filled above the target, only the excess can be withdrawn
+    pub target: Uint128,
+}
+
+impl InsuranceConfig {
+    pub fn calc_fill(&self, bet: Uint128, insurance: Uint128) -> Uint128 {
+        bet.mul_floor(self.rate)
+            .min(self.target.saturating_sub(insurance))
+    }
+}
+
+/// Hash chain committed by the worker. Seeds are revealed backwards along the chain, each
+/// revealed seed must hash to the previous one
+#[cw_serde]
+pub struct SeedChain {
+    /// the last revealed seed or the committed head
+    pub head: HexBinary,
+    /// round of the last revealed seed, rounds continue when a new chain is committed
+    pub round: u64,
+    /// block height of the last reveal or commit, only one seed can be revealed per block
+    pub height: u64,
+}
+
+impl SeedChain {
+    /// Flips are settled by the seed after the next one, so the round doesn't depend on tx
+    /// order in the block and the seed can't be revealed in the block of the flip
+    pub fn get_flip_round(&self, height: u64) -> u64 {
+        if self.height == height {
+            self.round + 1
+        } else {
+            self.round + 2
+        }
+    }
+}
+
+/// Accepted bet with the flip terms at the moment of the bet
+#[cw_serde]
+pub struct Bet {
+    pub user: Addr,
+    pub side: Side,
+    pub amount: Uint128,
+    /// platform fee with tier and NFT perk discounts
+    pub fee: Decimal,
+    /// paid on lose
+    pub cashback: Uint128,
+    pub nft: Option<Nft>,
+    pub callback: Option<Callback>,
+    /// the bet is taken from the user balance, the prize is credited to it
+    #[serde(default)]
+    pub from_balance: bool,
+    /// session key which placed the bet on behalf of the user
+    pub session: Option<Addr>,
+}
+
+/// Seeded flip waiting for the seed of its round
+#[cw_serde]
+pub struct PendingFlip {
+    pub bet: Bet,
+    pub client_seed: String,
+    /// per user counter of seeded flips
+    pub nonce: u64,
+    pub round: u64,
+    /// the bet can be refunded if the seed isn't revealed until `created_at + REVEAL_TIMEOUT`
+    pub created_at: u64,
+}
+
+/// Deferred randomness request, it's fulfilled when the seed of the `round` is revealed
+#[cw_serde]
+pub struct RandomnessRequest {
+    pub requester: Addr,
+    /// requester defined id passed back with the randomness
+    pub job_id: String,
+    pub callback: Callback,
+    pub round: u64,
+    /// the fee is kept as pending bet until the request is fulfilled
+    pub fee: Uint128,
+    /// the fee can be refunded if the seed isn't revealed until `created_at + REVEAL_TIMEOUT`
+    pub created_at: u64,
+}
+
+/// Non-withdrawable user bonus, it can be flipped with `ExecuteMsg::BonusFlip` only
+#[derive(Default)]
+#[cw_serde]
+pub struct BonusInfo {
+    pub balance: Uint128,
+    /// sum of bonus flip stakes
+    pub wagered: Uint128,
+    /// sum of credited bonuses multiplied by their wagering multipliers, bonus flip winnings are
+    /// paid only when `wagered` reaches it
+    pub wagering_requirement: Uint128,
+}
+
+impl BonusInfo {
+    pub fn credit(&mut self, amount: Uint128, wagering: u32) {
+        self.balance += amount;
+        self.wagering_requirement += amount * Uint128::from(wagering);
+    }
+
+    /// Takes the stake of bonus flip, it's returned with `credit` on win
+    pub fn wager(&mut self, stake: Uint128) -> Result<(), ContractError> {
+        if stake > self.balance {
+            Err(ContractError::InsufficientBonusBalance)?;
+        }
+
+        self.balance -= stake;
+        self.wagered += stake;
+        Ok(())
+    }
+
+    /// Winnings of bonus flips are credited to the bonus balance until the wagering requirement
+    /// is met, they can't be withdrawn before it
+    pub fn is_locked(&self) -> bool {
+        self.wagered < self.wagering_requirement
+    }
+}
+
+/// Bonus credited to the user address by the admin
+#[cw_serde]
+pub struct BonusCredit {
+    pub address: String,
+    pub amount: Uint128,
+}
+
+/// Bonus redeemed once per user, the code is stored by its sha256 hash
+#[cw_serde]
+pub struct PromoCode {
+    pub amount: Uint128,
+    /// wagering requirement multiplier of the credited bonus
+    pub wagering: u32,
+    /// the code is removed after the last redemption
+    pub redemptions: u32,
+}
+
+/// Original chain sender of ibc-hooks intermediate address, ICS-20 payouts are sent to it
+#[cw_serde]
+pub struct IbcTarget {
+    /// channel on this chain the transfer was received from
+    pub channel_id: String,
+    pub receiver: String,
+}
+
+/// ICS-20 payout waiting for the acknowledgement, it's returned to the user unclaimed if the
+/// transfer fails or times out
+#[cw_serde]
+pub struct IbcPayout {
+    /// ibc-hooks intermediate address
+    pub user: Addr,
+    pub channel_id: String,
+    pub amount: Uint128,
+}
+
+/// Permission of the session key to flip on behalf of the owner, winnings always go to the owner
+#[cw_serde]
+pub struct SessionGrant {
+    /// max sum of bets placed by the session key
+    pub spend_cap: Uint128,
+    pub spent: Uint128,
+    pub expiration: u64,
+    /// any side is allowed if it isn't specified
+    pub side: Option<Side>,
+    /// allowed bet amounts, the user tier bet range is applied anyway
+    pub bet: Option<Range>,
+}
+
+impl SessionGrant {
+    /// Checks the bet against the grant and adds it to the spent amount
+    pub fn spend(
+        &mut self,
+        side: &Side,
+        amount: Uint128,
+        block_time: u64,
+    ) -> Result<(), ContractError> {
+        if block_time >= self.expiration {
+            Err(ContractError::SessionIsExpired)?;
+        }
+
+        if self.side.as_ref().is_some_and(|x| x != side)
+            || self
+                .bet
+                .as_ref()
+                .is_some_and(|x| x.validate(amount).is_err())
+        {
+            Err(ContractError::SessionBetIsNotAllowed)?;
+        }
+
+        if self.spent + amount > self.spend_cap {
+            Err(ContractError::SessionSpendCapIsExceeded)?;
+        }
+
+        self.spent += amount;
+        Ok(())
+    }
+}
+
+/// User rakeback state. Finished epochs are settled lazily on the next flip or claim
+#[cw_serde]
+pub struct RakebackInfo {
+    /// current epoch
+    pub epoch: u64,
+    /// `stats.bets.value` at the start of the epoch
+    pub bets_value: Uint128,
+    /// `stats.wins.value` at the start of the epoch
+    pub wins_value: Uint128,
+    /// settled and not claimed rakeback
+    pub accrued: Uint128,
+}
+
+impl RakebackInfo {
+    /// Epoch is started with current stats to exclude the history before rakeback was enabled
+    pub fn new(epoch: u64, stats: &Stats) -> Self {
+        Self {
+            epoch,
+            bets_value: stats.bets.value,
+            wins_value: stats.wins.value,
+            accrued: Uint128::zero(),
+        }
+    }
+
+    /// net_loss = epoch_bets - epoch_wins
+    pub fn get_net_loss(&self, stats: &Stats) -> Uint128 {
+        (stats.bets.value - self.bets_value).saturating_sub(stats.wins.value - self.wins_value)
+    }
+
+    /// Must be called before updating user stats. If the epoch is finished, settles it,
+    /// starts the current one and returns settled rakeback
+    pub fn settle(&mut self, config: &RakebackConfig, stats: &Stats, epoch: u64) -> Uint128 {
+        if epoch <= self.epoch {
+            return Uint128::zero();
+        }
+
+        let amount = self.get_net_loss(stats).mul_floor(config.rate);
+        *self = Self {
+            accrued: self.accrued + amount,
+            ..Self::new(epoch, stats)
+        };
+
+        amount
+    }
+}
+
 #[cw_serde]
 pub struct TransferAdminState {
     pub new_admin: Addr,
     pub deadline: u64,
 }
+
+/// Revenue recipient (team, treasury, LP, etc.) with its share of distributed revenue
+#[cw_serde]
+pub struct RevenueRecipient {
+    pub address: String,
+    pub weight: Decimal,
+}
+
+/// Bet limits in the reference currency converted to `config.denom` with the oracle price
+#[cw_serde]
+pub struct OracleConfig {
+    /// contract answering `OracleQueryMsg::Price` for `config.denom`
+    pub contract: String,
+    /// bet limits in the reference currency micro-units
+    pub bet: Range,
+    /// older price is stale, `config.bet` is used instead, seconds
+    pub max_age: u64,
+}
+
+impl OracleConfig {
+    /// Converts the limits with the price, the min bet is rounded up. Returns `None` if the
+    /// converted range is empty or overflows
+    pub fn calc_bet(&self, price: Decimal) -> Option<Range> {
+        let bet = Range {
+            min: self.bet.min.checked_div_ceil(price).ok()?,
+            max: self.bet.max.checked_div_floor(price).ok()?,
+        };
+
+        if bet.min > bet.max || bet.max.is_zero() {
+            return None;
+        }
+
+        Some(bet)
+    }
+}
+
+/// Part of realized revenue is swapped through the pair into the token and burned
+#[cw_serde]
+pub struct BuybackConfig {
+    /// Astroport compatible pair contract
+    pub pair: String,
+    pub token: TokenUnverified,
+    /// part of realized revenue spent on each buyback
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct Buyback {
+    pub time: u64,
+    pub token: TokenUnverified,
+    /// spent revenue
+    pub amount_in: Uint128,
+    /// bought and burned tokens
+    pub amount_out: Uint128,
+}
@@ -2,11 +2,20 @@ use std::fmt::Debug;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    coin, to_json_binary, Addr, Api, BankMsg, Coin, CosmosMsg, Deps, MessageInfo, QuerierWrapper,
-    StdError, StdResult, Uint128, WasmMsg,
+    coin, to_json_binary, Addr, Api, BankMsg, Coin, CosmosMsg, Decimal, Deps, Event, HexBinary,
+    MessageInfo, QuerierWrapper, StdError, StdResult, Uint128, WasmMsg,
 };
 
-use crate::{assets::Token, error::ContractError};
+use crate::{
+    assets::Token,
+    error::ContractError,
+    platform::types::{
+        AppInfo, Bet, BonusInfo, BuybackConfig, Callback, Config, IbcPayout, IbcTarget,
+        InsuranceConfig, Nft, NftPerk, OracleConfig, Outcome, Payout, PendingFlip, PromoCode,
+        RakebackConfig, RandomnessRequest, RevenueRecipient, SeedChain, SessionGrant, Side, Tier,
+        UserInfo,
+    },
+};
 
 #[cw_serde]
 pub enum FundsType {
@@ -33,7 +42,7 @@ pub fn check_authorization(
     admin: &Addr,
     worker: &Option<Addr>,
     auth_type: AuthType,
-) -> StdResult<()> {
+) -> Result<(), ContractError> {
     let worker = unwrap_field(worker.to_owned(), "worker");
 
     match auth_type {
@@ -103,13 +112,742 @@ pub fn check_authorization(
     Ok(())
 }
 
-#[cw_serde]
-pub struct Attrs {}
+/// Event type, indexers receive it as `wasm-coinflip`
+pub const EVENT_TYPE: &str = "coinflip";
+
+/// Builds `wasm-coinflip` event. Every handler emits single event starting with `action`
+/// attribute, followed by handler specific attributes and (if state was changed) `AppInfo` snapshot:
+/// `balance`, `deposited`, `revenue_total`, `revenue_current`, `user_unclaimed`, `insurance`,
+/// `pending_bets`, `user_balances`, `bonus_balances`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attrs {
+    event: Event,
+}
 
 impl Attrs {
     pub fn init(action: &str) -> Vec<(String, String)> {
         vec![("action".to_string(), action.to_string())]
     }
+
+    pub fn new(action: &str) -> Self {
+        Self {
+            event: Event::new(EVENT_TYPE).add_attribute("action", action),
+        }
+    }
+
+    pub fn instantiate(config: &Config) -> Self {
+        Self::new("instantiate").config(config)
+    }
+
+    /// `outcome` is `win` or `lose`, `payout` is `auto_paid`, `unclaimed` or `none`
+    pub fn flip(
+        user: &Addr,
+        side: &Side,
+        amount: Uint128,
+        random_weight: Decimal,
+        payout: &Payout,
+        prize: Uint128,
+    ) -> Self {
+        Self::new("flip")
+            .add("user", user)
+            .add("side", side)
+            .add("amount", amount)
+            .add("random_weight", random_weight)
+            .add("outcome", Outcome::from(payout))
+            .add("payout", payout)
+            .add("prize", prize)
+    }
+
+    /// The bet is the NFT, `prize` is the floor price on win
+    pub fn nft_flip(
+        user: &Addr,
+        side: &Side,
+        nft: &Nft,
+        random_weight: Decimal,
+        payout: &Payout,
+        prize: Uint128,
+    ) -> Self {
+        Self::new("nft_flip")
+            .add("user", user)
+            .add("side", side)
+            .add("nft_collection", &nft.collection)
+            .add("nft_token_id", &nft.token_id)
+            .add("random_weight", random_weight)
+            .add("outcome", Outcome::from(payout))
+            .add("payout", payout)
+            .add("prize", prize)
+    }
+
+    /// `prize` is the winnings beyond the stake, the stake returns to `bonus_balance` on win
+    pub fn bonus_flip(
+        user: &Addr,
+        side: &Side,
+        amount: Uint128,
+        random_weight: Decimal,
+        payout: &Payout,
+        prize: Uint128,
+        bonus: &BonusInfo,
+    ) -> Self {
+        let outcome = if prize.is_zero() {
+            Outcome::Lose
+        } else {
+            Outcome::Win
+        };
+        Self::new("bonus_flip")
+            .add("user", user)
+            .add("side", side)
+            .add("amount", amount)
+            .add("random_weight", random_weight)
+            .add("outcome", outcome)
+            .add("payout", payout)
+            .add("prize", prize)
+            .add("bonus_balance", bonus.balance)
+            .add("wagered", bonus.wagered)
+    }
+
+    /// Seeded flip accepted with the bet, it's settled by the seed of the `round`
+    pub fn pending_flip(id: u64, flip: &PendingFlip) -> Self {
+        Self::new("pending_flip")
+            .add("user", &flip.bet.user)
+            .add("id", id)
+            .add("side", &flip.bet.side)
+            .add("amount", flip.bet.amount)
+            .add("client_seed", &flip.client_seed)
+            .add("nonce", flip.nonce)
+            .add("round", flip.round)
+            .nft(&flip.bet.nft)
+            .callback(&flip.bet.callback)
+            .from_balance(flip.bet.from_balance)
+            .session(&flip.bet.session)
+    }
+
+    /// Adds the data to verify the outcome of settled seeded flip
+    pub fn seeded(self, id: u64, flip: &PendingFlip, seed: &HexBinary) -> Self {
+        self.add("flip_id", id)
+            .add("round", flip.round)
+            .add("seed", seed)
+            .add("client_seed", &flip.client_seed)
+            .add("nonce", flip.nonce)
+    }
+
+    /// Emitted in the reply if the callback contract has failed, the flip isn't affected
+    pub fn flip_callback_failed(id: u64, error: &str) -> Self {
+        Self::new("flip_callback_failed")
+            .add("flip_id", id)
+            .add("error", error)
+    }
+
+    /// The bet from the user balance is credited back to it
+    /// `payout` is `auto_paid` if the penalty is refunded with the bet, otherwise it's `unclaimed`
+    pub fn refund_flip(id: u64, bet: &Bet, penalty: Uint128, payout: &Payout) -> Self {
+        Self::new("refund_flip")
+            .add("user", &bet.user)
+            .add("id", id)
+            .add("amount", bet.amount)
+            .add("penalty", penalty)
+            .add("payout", payout)
+            .from_balance(bet.from_balance)
+    }
+
+    pub fn top_up(user: &Addr, amount: Uint128, user_balance: Uint128) -> Self {
+        Self::new("top_up")
+            .add("user", user)
+            .add("amount", amount)
+            .add("user_balance", user_balance)
+    }
+
+    /// `side`, `bet_min` and `bet_max` are omitted if they aren't limited
+    pub fn grant_session(owner: &Addr, session: &Addr, grant: &SessionGrant) -> Self {
+        let attrs = Self::new("grant_session")
+            .add("owner", owner)
+            .add("session", session)
+            .add("spend_cap", grant.spend_cap)
+            .add("expiration", grant.expiration);
+        let attrs = match &grant.side {
+            Some(x) => attrs.add("side", x),
+            None => attrs,
+        };
+
+        match &grant.bet {
+            Some(x) => attrs.add("bet_min", x.min).add("bet_max", x.max),
+            None => attrs,
+        }
+    }
+
+    pub fn revoke_session(owner: &Addr, session: &Addr) -> Self {
+        Self::new("revoke_session")
+            .add("owner", owner)
+            .add("session", session)
+    }
+
+    pub fn withdraw_balance(user: &Addr, amount: Uint128, user_balance: Uint128) -> Self {
+        Self::new("withdraw_balance")
+            .add("user", user)
+            .add("amount", amount)
+            .add("user_balance", user_balance)
+    }
+
+    /// Paid randomness request, `round` is added if it's deferred, otherwise `randomness` is
+    /// added
+    pub fn request_randomness(
+        id: u64,
+        requester: &Addr,
+        job_id: &str,
+        callback: &Callback,
+        fee: Uint128,
+    ) -> Self {
+        Self::new("request_randomness")
+            .add("requester", requester)
+            .add("id", id)
+            .add("job_id", job_id)
+            .add("callback", &callback.contract)
+            .add("fee", fee)
+    }
+
+    pub fn deferred(self, round: u64) -> Self {
+        self.add("round", round)
+    }
+
+    pub fn randomness(self, randomness: &HexBinary) -> Self {
+        self.add("randomness", randomness)
+    }
+
+    /// Deferred request fulfilled by the seed of its round
+    pub fn fulfill_randomness(
+        id: u64,
+        request: &RandomnessRequest,
+        seed: &HexBinary,
+        randomness: &HexBinary,
+    ) -> Self {
+        Self::new("fulfill_randomness")
+            .add("requester", &request.requester)
+            .add("id", id)
+            .add("job_id", &request.job_id)
+            .add("callback", &request.callback.contract)
+            .add("fee", request.fee)
+            .add("round", request.round)
+            .add("seed", seed)
+            .add("randomness", randomness)
+    }
+
+    pub fn refund_randomness(id: u64, request: &RandomnessRequest) -> Self {
+        Self::new("refund_randomness")
+            .add("requester", &request.requester)
+            .add("id", id)
+            .add("job_id", &request.job_id)
+            .add("fee", request.fee)
+    }
+
+    /// Emitted in the reply if the callback contract has failed, the fee isn't refunded
+    pub fn randomness_callback_failed(id: u64, error: &str) -> Self {
+        Self::new("randomness_callback_failed")
+            .add("id", id)
+            .add("error", error)
+    }
+
+    pub fn commit_seed_chain(sender: &Addr, seed_chain: &SeedChain) -> Self {
+        Self::new("commit_seed_chain")
+            .add("sender", sender)
+            .add("head", &seed_chain.head)
+            .add("round", seed_chain.round)
+    }
+
+    pub fn reveal_seed(sender: &Addr, seed_chain: &SeedChain) -> Self {
+        Self::new("reveal_seed")
+            .add("sender", sender)
+            .add("round", seed_chain.round)
+            .add("seed", &seed_chain.head)
+    }
+
+    /// Adds `cashback` if it's paid
+    pub fn cashback(self, amount: Uint128) -> Self {
+        if amount.is_zero() {
+            return self;
+        }
+
+        self.add("cashback", amount)
+    }
+
+    /// Adds `nft_collection` and `nft_token_id` if NFT perk was applied
+    pub fn nft(self, nft: &Option<Nft>) -> Self {
+        match nft {
+            Some(x) => self
+                .add("nft_collection", &x.collection)
+                .add("nft_token_id", &x.token_id),
+            None => self,
+        }
+    }
+
+    /// Adds `from_balance` if the bet was taken from the user balance
+    pub fn from_balance(self, from_balance: bool) -> Self {
+        if !from_balance {
+            return self;
+        }
+
+        self.add("from_balance", from_balance)
+    }
+
+    /// Adds `locked` if bonus flip winnings were credited to the bonus balance as the wagering
+    /// requirement isn't met
+    pub fn locked(self, is_locked: bool) -> Self {
+        if !is_locked {
+            return self;
+        }
+
+        self.add("locked", is_locked)
+    }
+
+    /// Adds `session` if the bet was placed by the session key
+    pub fn session(self, session: &Option<Addr>) -> Self {
+        match session {
+            Some(x) => self.add("session", x),
+            None => self,
+        }
+    }
+
+    /// Adds `ibc_channel` and `ibc_receiver` if the flip was sent with ibc-hooks
+    pub fn ibc_target(self, target: &Option<IbcTarget>) -> Self {
+        match target {
+            Some(x) => self
+                .add("ibc_channel", &x.channel_id)
+                .add("ibc_receiver", &x.receiver),
+            None => self,
+        }
+    }
+
+    /// Adds `flip_id` if the flip was given an id
+    pub fn flip_id(self, id: Option<u64>) -> Self {
+        match id {
+            Some(x) => self.add("flip_id", x),
+            None => self,
+        }
+    }
+
+    /// Adds `callback` contract if it's notified with the flip result
+    pub fn callback(self, callback: &Option<Callback>) -> Self {
+        match callback {
+            Some(x) => self.add("callback", &x.contract),
+            None => self,
+        }
+    }
+
+    /// Adds `rakeback` if finished epoch was settled
+    pub fn rakeback(self, amount: Uint128) -> Self {
+        if amount.is_zero() {
+            return self;
+        }
+
+        self.add("rakeback", amount)
+    }
+
+    /// Adds part of the lost bet moved to the insurance
+    pub fn insurance_fill(self, amount: Uint128) -> Self {
+        if amount.is_zero() {
+            return self;
+        }
+
+        self.add("insurance_fill", amount)
+    }
+
+    pub fn claim(user: &Addr, amount: Uint128) -> Self {
+        Self::new("claim").add("user", user).add("amount", amount)
+    }
+
+    /// Failed or timed out ICS-20 payout is returned to the user unclaimed, `sequence` is omitted
+    /// if the transfer was rejected without sending the packet
+    pub fn return_ibc_payout(sequence: Option<u64>, payout: &IbcPayout) -> Self {
+        let attrs = Self::new("return_ibc_payout")
+            .add("user", &payout.user)
+            .add("channel_id", &payout.channel_id);
+        let attrs = match sequence {
+            Some(x) => attrs.add("sequence", x),
+            None => attrs,
+        };
+
+        attrs.add("amount", payout.amount)
+    }
+
+    /// `rakeback` is settled amount if finished epoch was settled on claim
+    pub fn claim_rakeback(user: &Addr, amount: Uint128) -> Self {
+        Self::new("claim_rakeback")
+            .add("user", user)
+            .add("amount", amount)
+    }
+
+    pub fn deposit(sender: &Addr, amount: Uint128) -> Self {
+        Self::new("deposit")
+            .add("sender", sender)
+            .add("amount", amount)
+    }
+
+    pub fn withdraw(sender: &Addr, recipient: &Addr, amount: Uint128) -> Self {
+        Self::new("withdraw")
+            .add("sender", sender)
+            .add("recipient", recipient)
+            .add("amount", amount)
+    }
+
+    pub fn deposit_insurance(sender: &Addr, amount: Uint128) -> Self {
+        Self::new("deposit_insurance")
+            .add("sender", sender)
+            .add("amount", amount)
+    }
+
+    pub fn withdraw_insurance(sender: &Addr, recipient: &Addr, amount: Uint128) -> Self {
+        Self::new("withdraw_insurance")
+            .add("sender", sender)
+            .add("recipient", recipient)
+            .add("amount", amount)
+    }
+
+    pub fn accept_admin_role(admin: &Addr) -> Self {
+        Self::new("accept_admin_role").add("admin", admin)
+    }
+
+    pub fn update_config(sender: &Addr, config: &Config, new_admin: &Option<Addr>) -> Self {
+        let attrs = Self::new("update_config").add("sender", sender);
+
+        match new_admin {
+            Some(x) => attrs.add("new_admin", x),
+            None => attrs,
+        }
+        .config(config)
+    }
+
+    /// `tiers` is amount of tiers, `tier_{n}` is `threshold:bet_min:bet_max:fee_discount:cashback`
+    pub fn update_tiers(sender: &Addr, tiers: &[Tier]) -> Self {
+        tiers.iter().enumerate().fold(
+            Self::new("update_tiers")
+                .add("sender", sender)
+                .add("tiers", tiers.len()),
+            |acc, (i, x)| {
+                acc.add(
+                    &format!("tier_{}", i),
+                    format!(
+                        "{}:{}:{}:{}:{}",
+                        x.threshold,
+                        x.bet.min,
+                        x.bet.max,
+                        x.fee_discount,
+                        x.cashback.unwrap_or_default()
+                    ),
+                )
+            },
+        )
+    }
+
+    /// `tier` is omitted if the assigned level is removed
+    pub fn set_user_tier(sender: &Addr, user: &Addr, tier: Option<u8>) -> Self {
+        let attrs = Self::new("set_user_tier")
+            .add("sender", sender)
+            .add("user", user);
+
+        match tier {
+            Some(x) => attrs.add("tier", x),
+            None => attrs,
+        }
+    }
+
+    /// `recipient_{n}` is `address:weight`
+    pub fn update_revenue_split(sender: &Addr, recipients: &[RevenueRecipient]) -> Self {
+        recipients.iter().enumerate().fold(
+            Self::new("update_revenue_split")
+                .add("sender", sender)
+                .add("recipients", recipients.len()),
+            |acc, (i, x)| {
+                acc.add(
+                    &format!("recipient_{}", i),
+                    format!("{}:{}", x.address, x.weight),
+                )
+            },
+        )
+    }
+
+    /// `recipient_{n}` is `address:amount`
+    pub fn distribute_revenue(sender: &Addr, amount: Uint128, shares: &[(Addr, Uint128)]) -> Self {
+        shares.iter().enumerate().fold(
+            Self::new("distribute_revenue")
+                .add("sender", sender)
+                .add("amount", amount),
+            |acc, (i, (address, share))| {
+                acc.add(
+                    &format!("recipient_{}", i),
+                    format!("{}:{}", address, share),
+                )
+            },
+        )
+    }
+
+    pub fn process_payouts(sender: &Addr, payouts: &[(Addr, Uint128)]) -> Self {
+        let amount: Uint128 = payouts.iter().map(|(_, x)| x).sum();
+
+        Self::new("process_payouts")
+            .add("sender", sender)
+            .add("amount", amount)
+            .payouts(payouts)
+    }
+
+    /// `last` is the last scanned user, the backfill is finished when it's absent
+    pub fn enqueue_payouts(sender: &Addr, users: u32, last: &Option<Addr>) -> Self {
+        let attrs = Self::new("enqueue_payouts")
+            .add("sender", sender)
+            .add("users", users);
+
+        match last {
+            Some(x) => attrs.add("last", x),
+            None => attrs,
+        }
+    }
+
+    /// Adds users paid from the payout queue as `payout_{n}` (`address:amount`), nothing is added
+    /// to the deposit event if the queue wasn't processed
+    pub fn payouts(self, payouts: &[(Addr, Uint128)]) -> Self {
+        if payouts.is_empty() {
+            return self;
+        }
+
+        payouts.iter().enumerate().fold(
+            self.add("payouts", payouts.len()),
+            |acc, (i, (address, amount))| {
+                acc.add(&format!("payout_{}", i), format!("{}:{}", address, amount))
+            },
+        )
+    }
+
+    /// `credits` is the amount of credited users, `credit_{n}` is `address:amount`
+    pub fn credit_bonus(sender: &Addr, wagering: u32, credits: &[(Addr, Uint128)]) -> Self {
+        let amount: Uint128 = credits.iter().map(|(_, x)| x).sum();
+
+        credits.iter().enumerate().fold(
+            Self::new("credit_bonus")
+                .add("sender", sender)
+                .add("amount", amount)
+                .add("wagering", wagering)
+                .add("credits", credits.len()),
+            |acc, (i, (address, amount))| {
+                acc.add(&format!("credit_{}", i), format!("{}:{}", address, amount))
+            },
+        )
+    }
+
+    /// The promo code terms are omitted if it's removed
+    pub fn update_promo_code(sender: &Addr, hash: &HexBinary, promo: &Option<PromoCode>) -> Self {
+        let attrs = Self::new("update_promo_code")
+            .add("sender", sender)
+            .add("hash", hash);
+
+        match promo {
+            Some(x) => attrs
+                .add("amount", x.amount)
+                .add("wagering", x.wagering)
+                .add("redemptions", x.redemptions),
+            None => attrs,
+        }
+    }
+
+    pub fn redeem_promo_code(
+        user: &Addr,
+        hash: &HexBinary,
+        promo: &PromoCode,
+        bonus: &BonusInfo,
+    ) -> Self {
+        Self::new("redeem_promo_code")
+            .add("user", user)
+            .add("hash", hash)
+            .add("amount", promo.amount)
+            .add("wagering", promo.wagering)
+            .add("bonus_balance", bonus.balance)
+    }
+
+    /// `rate` and `epoch` are omitted if rakeback is disabled
+    pub fn update_rakeback(sender: &Addr, rakeback: &Option<RakebackConfig>) -> Self {
+        let attrs = Self::new("update_rakeback").add("sender", sender);
+
+        match rakeback {
+            Some(x) => attrs.add("rate", x.rate).add("epoch", x.epoch),
+            None => attrs,
+        }
+    }
+
+    pub fn update_deferred_only(sender: &Addr, enabled: bool) -> Self {
+        Self::new("update_deferred_only")
+            .add("sender", sender)
+            .add("enabled", enabled)
+    }
+
+    /// `fee` is omitted if randomness service is disabled
+    pub fn update_randomness_fee(sender: &Addr, fee: &Option<Uint128>) -> Self {
+        let attrs = Self::new("update_randomness_fee").add("sender", sender);
+
+        match fee {
+            Some(x) => attrs.add("fee", x),
+            None => attrs,
+        }
+    }
+
+    /// `rate` and `target` are omitted if the insurance is disabled
+    pub fn update_insurance(sender: &Addr, insurance: &Option<InsuranceConfig>) -> Self {
+        let attrs = Self::new("update_insurance").add("sender", sender);
+
+        match insurance {
+            Some(x) => attrs.add("rate", x.rate).add("target", x.target),
+            None => attrs,
+        }
+    }
+
+    /// `contract_{n}` are omitted if any contract can flip
+    pub fn update_contract_callers(sender: &Addr, whitelist: &Option<Vec<Addr>>) -> Self {
+        let attrs = Self::new("update_contract_callers").add("sender", sender);
+
+        match whitelist {
+            Some(x) => x
+                .iter()
+                .enumerate()
+                .fold(attrs.add("contracts", x.len()), |acc, (i, address)| {
+                    acc.add(&format!("contract_{}", i), address)
+                }),
+            None => attrs,
+        }
+    }
+
+    /// `fee_discount` and `bet_max` are omitted if the collection is removed
+    pub fn update_nft_perk(sender: &Addr, collection: &Addr, perk: &Option<NftPerk>) -> Self {
+        let attrs = Self::new("update_nft_perk")
+            .add("sender", sender)
+            .add("collection", collection);
+
+        match perk {
+            Some(NftPerk {
+                fee_discount,
+                bet_max,
+            }) => {
+                let attrs = attrs.add("fee_discount", fee_discount);
+
+                match bet_max {
+                    Some(x) => attrs.add("bet_max", x),
+                    None => attrs,
+                }
+            }
+            None => attrs,
+        }
+    }
+
+    /// `price` is omitted if the collection is removed
+    pub fn update_nft_floor_price(
+        sender: &Addr,
+        collection: &Addr,
+        price: Option<Uint128>,
+    ) -> Self {
+        let attrs = Self::new("update_nft_floor_price")
+            .add("sender", sender)
+            .add("collection", collection);
+
+        match price {
+            Some(x) => attrs.add("price", x),
+            None => attrs,
+        }
+    }
+
+    pub fn withdraw_nft(sender: &Addr, recipient: &Addr, nft: &Nft) -> Self {
+        Self::new("withdraw_nft")
+            .add("sender", sender)
+            .add("recipient", recipient)
+            .add("nft_collection", &nft.collection)
+            .add("nft_token_id", &nft.token_id)
+    }
+
+    pub fn buyback(sender: &Addr, pair: &str, amount_in: Uint128) -> Self {
+        Self::new("buyback")
+            .add("sender", sender)
+            .add("pair", pair)
+            .add("amount_in", amount_in)
+    }
+
+    /// Emitted in the swap reply
+    pub fn burn(id: u64, token: &Token, amount: Uint128) -> Self {
+        Self::new("burn")
+            .add("id", id)
+            .add("token", token.get_denom_or_address())
+            .add("amount", amount)
+    }
+
+    /// `pair`, `token` and `share` are omitted if buyback is disabled
+    pub fn update_buyback(sender: &Addr, buyback: &Option<BuybackConfig>) -> Self {
+        let attrs = Self::new("update_buyback").add("sender", sender);
+
+        match buyback {
+            Some(x) => attrs
+                .add("pair", &x.pair)
+                .add("token", x.token.get_symbol())
+                .add("share", x.share),
+            None => attrs,
+        }
+    }
+
+    pub fn update_oracle(sender: &Addr, oracle: &Option<OracleConfig>) -> Self {
+        let attrs = Self::new("update_oracle").add("sender", sender);
+
+        match oracle {
+            Some(x) => attrs
+                .add("contract", &x.contract)
+                .add("bet_min", x.bet.min)
+                .add("bet_max", x.bet.max)
+                .add("max_age", x.max_age),
+            None => attrs,
+        }
+    }
+
+    pub fn pause(sender: &Addr) -> Self {
+        Self::new("pause").add("sender", sender)
+    }
+
+    pub fn unpause(sender: &Addr) -> Self {
+        Self::new("unpause").add("sender", sender)
+    }
+
+    /// Adds updated user state
+    pub fn user_info(self, user: &UserInfo) -> Self {
+        self.add("unclaimed", user.unclaimed)
+            .add("last_flip_date", user.last_flip_date)
+    }
+
+    /// Adds updated app state
+    pub fn app_info(self, app_info: &AppInfo) -> Self {
+        self.add("balance", app_info.balance)
+            .add("deposited", app_info.deposited)
+            .add("revenue_total", app_info.revenue.total)
+            .add("revenue_current", app_info.revenue.current)
+            .add("user_unclaimed", app_info.user_unclaimed)
+            .add("insurance", app_info.insurance)
+            .add("pending_bets", app_info.pending_bets)
+            .add("user_balances", app_info.user_balances)
+            .add("bonus_balances", app_info.bonus_balances)
+    }
+
+    fn config(self, config: &Config) -> Self {
+        let attrs = self.add("admin", &config.admin);
+
+        match &config.worker {
+            Some(x) => attrs.add("worker", x),
+            None => attrs,
+        }
+        .add("bet_min", config.bet.min)
+        .add("bet_max", config.bet.max)
+        .add("denom", &config.denom)
+        .add("platform_fee", config.platform_fee)
+    }
+
+    fn add(self, key: &str, value: impl ToString) -> Self {
+        Self {
+            event: self.event.add_attribute(key, value.to_string()),
+        }
+    }
+}
+
+impl From<Attrs> for Event {
+    fn from(attrs: Attrs) -> Self {
+        attrs.event
+    }
 }
 
 pub fn add_attr<T: Debug + Clone>(
@@ -279,6 +1017,73 @@ fn nonpayable(info: &MessageInfo) -> StdResult<()> {
     Ok(())
 }
 
+/// Returns NFT owner using cw721 `OwnerOf` query
+pub fn query_nft_owner(
+    querier: QuerierWrapper,
+    collection: impl ToString,
+    token_id: impl ToString,
+) -> StdResult<String> {
+    let cw721::OwnerOfResponse { owner, .. } = querier.query_wasm_smart(
+        collection.to_string(),
+        &cw721::Cw721QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            include_expired: None,
+        },
+    )?;
+
+    Ok(owner)
+}
+
+pub fn get_burn_msg(amount: Uint128, token: &Token) -> StdResult<CosmosMsg> {
+    Ok(match token {
+        Token::Native { denom } => CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![coin(amount.u128(), denom)],
+        }),
+        Token::Cw20 { address } => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: address.to_string(),
+            msg: to_json_binary(&cw20::Cw20ExecuteMsg::Burn { amount })?,
+            funds: vec![],
+        }),
+    })
+}
+
+pub fn query_token_balance(
+    querier: QuerierWrapper,
+    address: impl ToString,
+    token: &Token,
+) -> StdResult<Uint128> {
+    Ok(match token {
+        Token::Native { denom } => querier.query_balance(address.to_string(), denom)?.amount,
+        Token::Cw20 {
+            address: token_address,
+        } => {
+            let cw20::BalanceResponse { balance } = querier.query_wasm_smart(
+                token_address,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )?;
+
+            balance
+        }
+    })
+}
+
+pub fn get_nft_transfer_msg(
+    collection: impl ToString,
+    recipient: impl ToString,
+    token_id: impl ToString,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    }))
+}
+
 pub fn get_collection_operator_approvals(
     querier: QuerierWrapper,
     collection_list: &[impl ToString],
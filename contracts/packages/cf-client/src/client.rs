@@ -0,0 +1,896 @@
+use anyhow::Result;
+use cosmwasm_std::{coins, to_json_binary, Addr, Binary, Coin, Decimal, HexBinary, Uint128};
+
+use cf_base::{
+    error::{decode_err, ContractError},
+    platform::{
+        msg::{
+            BetRangeResp, BuybackListRespItem, ExecuteMsg, HouseNftRespItem, InsuranceResp,
+            NftFloorPriceRespItem, NftPerksRespItem, PayoutQueuePositionResp, PendingFlipsRespItem,
+            QueryMsg, RandomnessRequestsRespItem, RevenueSplitRespItem, SessionGrantsRespItem,
+            SignedFlipPayload, UserListRespItem, UserRakebackResp, UserTierResp,
+        },
+        state::DENOM,
+        types::{
+            AppInfo, BonusCredit, BonusInfo, BuybackConfig, Callback, Config, IbcTarget,
+            InsuranceConfig, Nft, NftPerk, OracleConfig, PromoCode, RakebackConfig, Range,
+            RevenueRecipient, SeedChain, Side, Tier, UserInfo,
+        },
+    },
+};
+
+use crate::transport::{ExecuteTransport, QueryTransport};
+
+/// Typed wrapper over platform contract messages. Execute methods are available
+/// for any `ExecuteTransport`, query methods - for any `QueryTransport`
+pub struct PlatformClient<T> {
+    pub transport: T,
+    pub contract: String,
+    pub denom: String,
+}
+
+impl<T> PlatformClient<T> {
+    pub fn new(transport: T, contract: impl ToString) -> Self {
+        Self {
+            transport,
+            contract: contract.to_string(),
+            denom: DENOM.to_string(),
+        }
+    }
+
+    pub fn with_denom(self, denom: impl ToString) -> Self {
+        Self {
+            denom: denom.to_string(),
+            ..self
+        }
+    }
+
+    fn funds(&self, amount: Uint128) -> Vec<Coin> {
+        coins(amount.u128(), &self.denom)
+    }
+}
+
+impl<T: ExecuteTransport> PlatformClient<T> {
+    pub fn execute(
+        &mut self,
+        sender: impl ToString,
+        msg: &ExecuteMsg,
+        funds: &[Coin],
+    ) -> Result<T::Output> {
+        self.transport
+            .execute(&sender.to_string(), &self.contract, msg, funds)
+    }
+
+    // users
+    pub fn flip(
+        &mut self,
+        sender: impl ToString,
+        side: Side,
+        amount: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(
+            sender,
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed: None,
+                callback: None,
+                amount: None,
+                payout_to: None,
+            },
+            &funds,
+        )
+    }
+
+    pub fn flip_seeded(
+        &mut self,
+        sender: impl ToString,
+        side: Side,
+        amount: impl Into<Uint128>,
+        client_seed: impl ToString,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(
+            sender,
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed: Some(client_seed.to_string()),
+                callback: None,
+                amount: None,
+                payout_to: None,
+            },
+            &funds,
+        )
+    }
+
+    /// The callback contract is notified with the flip result, the flip is seeded if the client
+    /// seed is specified
+    pub fn flip_with_callback(
+        &mut self,
+        sender: impl ToString,
+        side: Side,
+        amount: impl Into<Uint128>,
+        client_seed: Option<String>,
+        callback: Callback,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(
+            sender,
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed,
+                callback: Some(callback),
+                amount: None,
+                payout_to: None,
+            },
+            &funds,
+        )
+    }
+
+    pub fn settle_flip(&mut self, sender: impl ToString, id: u64) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::SettleFlip { id }, &[])
+    }
+
+    pub fn refund_flip(&mut self, sender: impl ToString, id: u64) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::RefundFlip { id }, &[])
+    }
+
+    pub fn request_randomness(
+        &mut self,
+        sender: impl ToString,
+        job_id: impl ToString,
+        callback: Callback,
+        deferred: bool,
+        fee: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        let funds = self.funds(fee.into());
+        self.execute(
+            sender,
+            &ExecuteMsg::RequestRandomness {
+                job_id: job_id.to_string(),
+                callback,
+                deferred,
+            },
+            &funds,
+        )
+    }
+
+    pub fn fulfill_randomness(&mut self, sender: impl ToString, id: u64) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::FulfillRandomness { id }, &[])
+    }
+
+    pub fn refund_randomness(&mut self, sender: impl ToString, id: u64) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::RefundRandomness { id }, &[])
+    }
+
+    pub fn flip_with_nft(
+        &mut self,
+        sender: impl ToString,
+        side: Side,
+        amount: impl Into<Uint128>,
+        nft: Nft,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(
+            sender,
+            &ExecuteMsg::Flip {
+                side,
+                nft: Some(nft),
+                client_seed: None,
+                callback: None,
+                amount: None,
+                payout_to: None,
+            },
+            &funds,
+        )
+    }
+
+    /// The bet is taken from the user balance, the prize is credited to it
+    pub fn flip_from_balance(
+        &mut self,
+        sender: impl ToString,
+        side: Side,
+        amount: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed: None,
+                callback: None,
+                amount: Some(amount.into()),
+                payout_to: None,
+            },
+            &[],
+        )
+    }
+
+    /// The session key flips on behalf of the owner, the bet is taken from the owner balance
+    /// if `amount` is specified, otherwise `funds` are attached
+    pub fn session_flip(
+        &mut self,
+        sender: impl ToString,
+        owner: impl ToString,
+        side: Side,
+        amount: Option<Uint128>,
+        funds: Option<Uint128>,
+    ) -> Result<T::Output> {
+        let funds = funds.map(|x| self.funds(x)).unwrap_or_default();
+        self.execute(
+            sender,
+            &ExecuteMsg::SessionFlip {
+                owner: owner.to_string(),
+                side,
+                client_seed: None,
+                amount,
+            },
+            &funds,
+        )
+    }
+
+    /// The worker relays the flip signed by the user
+    pub fn flip_signed(
+        &mut self,
+        sender: impl ToString,
+        payload: &SignedFlipPayload,
+        pubkey: impl Into<Binary>,
+        signature: impl Into<Binary>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::FlipSigned {
+                payload: to_json_binary(payload)?,
+                pubkey: pubkey.into(),
+                signature: signature.into(),
+            },
+            &[],
+        )
+    }
+
+    pub fn grant_session(
+        &mut self,
+        sender: impl ToString,
+        session: impl ToString,
+        spend_cap: impl Into<Uint128>,
+        duration: u64,
+        side: Option<Side>,
+        bet: Option<Range>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::GrantSession {
+                session: session.to_string(),
+                spend_cap: spend_cap.into(),
+                duration,
+                side,
+                bet,
+            },
+            &[],
+        )
+    }
+
+    pub fn revoke_session(
+        &mut self,
+        sender: impl ToString,
+        session: impl ToString,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::RevokeSession {
+                session: session.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn top_up(
+        &mut self,
+        sender: impl ToString,
+        amount: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(sender, &ExecuteMsg::TopUp {}, &funds)
+    }
+
+    pub fn withdraw_balance(
+        &mut self,
+        sender: impl ToString,
+        amount: Option<Uint128>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::WithdrawBalance { amount }, &[])
+    }
+
+    pub fn bonus_flip(
+        &mut self,
+        sender: impl ToString,
+        side: Side,
+        amount: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::BonusFlip {
+                side,
+                amount: amount.into(),
+            },
+            &[],
+        )
+    }
+
+    pub fn redeem_promo_code(
+        &mut self,
+        sender: impl ToString,
+        code: impl ToString,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::RedeemPromoCode {
+                code: code.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn claim(&mut self, sender: impl ToString) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::Claim {}, &[])
+    }
+
+    pub fn claim_rakeback(&mut self, sender: impl ToString) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::ClaimRakeback {}, &[])
+    }
+
+    pub fn process_payouts(&mut self, sender: impl ToString, limit: u32) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::ProcessPayouts { limit }, &[])
+    }
+
+    pub fn distribute_revenue(&mut self, sender: impl ToString) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::DistributeRevenue {}, &[])
+    }
+
+    // new_admin
+    pub fn accept_admin_role(&mut self, sender: impl ToString) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::AcceptAdminRole {}, &[])
+    }
+
+    // admin, worker
+    pub fn deposit(
+        &mut self,
+        sender: impl ToString,
+        amount: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(sender, &ExecuteMsg::Deposit {}, &funds)
+    }
+
+    pub fn withdraw(
+        &mut self,
+        sender: impl ToString,
+        amount: Option<Uint128>,
+        recipient: Option<String>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::Withdraw { amount, recipient }, &[])
+    }
+
+    pub fn deposit_insurance(
+        &mut self,
+        sender: impl ToString,
+        amount: impl Into<Uint128>,
+    ) -> Result<T::Output> {
+        let funds = self.funds(amount.into());
+        self.execute(sender, &ExecuteMsg::DepositInsurance {}, &funds)
+    }
+
+    pub fn withdraw_insurance(
+        &mut self,
+        sender: impl ToString,
+        amount: Option<Uint128>,
+        recipient: Option<String>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::WithdrawInsurance { amount, recipient },
+            &[],
+        )
+    }
+
+    pub fn buyback(
+        &mut self,
+        sender: impl ToString,
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::Buyback {
+                belief_price,
+                max_spread,
+            },
+            &[],
+        )
+    }
+
+    pub fn commit_seed_chain(
+        &mut self,
+        sender: impl ToString,
+        head: HexBinary,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::CommitSeedChain { head }, &[])
+    }
+
+    pub fn reveal_seed(&mut self, sender: impl ToString, seed: HexBinary) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::RevealSeed { seed }, &[])
+    }
+
+    pub fn enqueue_payouts(
+        &mut self,
+        sender: impl ToString,
+        start_after: Option<String>,
+        limit: u32,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::EnqueuePayouts { start_after, limit },
+            &[],
+        )
+    }
+
+    pub fn update_config(
+        &mut self,
+        sender: impl ToString,
+        admin: Option<String>,
+        worker: Option<String>,
+        bet: Option<Range>,
+        platform_fee: Option<Decimal>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::UpdateConfig {
+                admin,
+                worker,
+                bet,
+                platform_fee,
+            },
+            &[],
+        )
+    }
+
+    pub fn update_tiers(&mut self, sender: impl ToString, tiers: Vec<Tier>) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateTiers { tiers }, &[])
+    }
+
+    pub fn set_user_tier(
+        &mut self,
+        sender: impl ToString,
+        address: impl ToString,
+        tier: Option<u8>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::SetUserTier {
+                address: address.to_string(),
+                tier,
+            },
+            &[],
+        )
+    }
+
+    pub fn update_rakeback(
+        &mut self,
+        sender: impl ToString,
+        rakeback: Option<RakebackConfig>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateRakeback { rakeback }, &[])
+    }
+
+    pub fn update_contract_callers(
+        &mut self,
+        sender: impl ToString,
+        whitelist: Option<Vec<String>>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::UpdateContractCallers { whitelist },
+            &[],
+        )
+    }
+
+    pub fn update_deferred_only(
+        &mut self,
+        sender: impl ToString,
+        enabled: bool,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateDeferredOnly { enabled }, &[])
+    }
+
+    pub fn update_randomness_fee(
+        &mut self,
+        sender: impl ToString,
+        fee: Option<Uint128>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateRandomnessFee { fee }, &[])
+    }
+
+    pub fn update_nft_perk(
+        &mut self,
+        sender: impl ToString,
+        collection: impl ToString,
+        perk: Option<NftPerk>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::UpdateNftPerk {
+                collection: collection.to_string(),
+                perk,
+            },
+            &[],
+        )
+    }
+
+    pub fn update_revenue_split(
+        &mut self,
+        sender: impl ToString,
+        recipients: Vec<RevenueRecipient>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateRevenueSplit { recipients }, &[])
+    }
+
+    pub fn update_insurance(
+        &mut self,
+        sender: impl ToString,
+        insurance: Option<InsuranceConfig>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateInsurance { insurance }, &[])
+    }
+
+    pub fn update_buyback(
+        &mut self,
+        sender: impl ToString,
+        buyback: Option<BuybackConfig>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateBuyback { buyback }, &[])
+    }
+
+    pub fn update_oracle(
+        &mut self,
+        sender: impl ToString,
+        oracle: Option<OracleConfig>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdateOracle { oracle }, &[])
+    }
+
+    pub fn credit_bonus(
+        &mut self,
+        sender: impl ToString,
+        credits: Vec<BonusCredit>,
+        wagering: u32,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::CreditBonus { credits, wagering }, &[])
+    }
+
+    pub fn update_promo_code(
+        &mut self,
+        sender: impl ToString,
+        hash: HexBinary,
+        promo: Option<PromoCode>,
+    ) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::UpdatePromoCode { hash, promo }, &[])
+    }
+
+    pub fn update_nft_floor_price(
+        &mut self,
+        sender: impl ToString,
+        collection: impl ToString,
+        price: Option<Uint128>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::UpdateNftFloorPrice {
+                collection: collection.to_string(),
+                price,
+            },
+            &[],
+        )
+    }
+
+    pub fn withdraw_nft(
+        &mut self,
+        sender: impl ToString,
+        nft: Nft,
+        recipient: Option<String>,
+    ) -> Result<T::Output> {
+        self.execute(
+            sender,
+            &ExecuteMsg::WithdrawNft {
+                collection: nft.collection,
+                token_id: nft.token_id,
+                recipient,
+            },
+            &[],
+        )
+    }
+
+    pub fn pause(&mut self, sender: impl ToString) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::Pause {}, &[])
+    }
+
+    pub fn unpause(&mut self, sender: impl ToString) -> Result<T::Output> {
+        self.execute(sender, &ExecuteMsg::Unpause {}, &[])
+    }
+}
+
+impl<T: QueryTransport> PlatformClient<T> {
+    pub fn query_config(&self) -> Result<Config> {
+        self.transport.query(&self.contract, &QueryMsg::Config {})
+    }
+
+    pub fn query_app_info(&self) -> Result<AppInfo> {
+        self.transport.query(&self.contract, &QueryMsg::AppInfo {})
+    }
+
+    pub fn query_required_to_deposit(&self) -> Result<Uint128> {
+        self.transport
+            .query(&self.contract, &QueryMsg::RequiredToDeposit {})
+    }
+
+    pub fn query_available_to_withdraw(&self) -> Result<Uint128> {
+        self.transport
+            .query(&self.contract, &QueryMsg::AvailableToWithdraw {})
+    }
+
+    pub fn query_user(&self, address: impl ToString) -> Result<UserInfo> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::User {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_user_list(
+        &self,
+        amount: u32,
+        start_after: Option<String>,
+    ) -> Result<Vec<UserListRespItem>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::UserList {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    pub fn query_tiers(&self) -> Result<Vec<Tier>> {
+        self.transport.query(&self.contract, &QueryMsg::Tiers {})
+    }
+
+    pub fn query_user_tier(&self, address: impl ToString) -> Result<UserTierResp> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::UserTier {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_user_balance(&self, address: impl ToString) -> Result<Uint128> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::UserBalance {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_user_bonus(&self, address: impl ToString) -> Result<BonusInfo> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::UserBonus {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_signed_nonce(&self, address: impl ToString) -> Result<u64> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::SignedNonce {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_ibc_sender(&self, address: impl ToString) -> Result<Option<IbcTarget>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::IbcSender {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_session_grants(
+        &self,
+        owner: impl ToString,
+        amount: u32,
+        start_after: Option<String>,
+    ) -> Result<Vec<SessionGrantsRespItem>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::SessionGrants {
+                owner: owner.to_string(),
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    pub fn query_seed_chain(&self) -> Result<Option<SeedChain>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::SeedChain {})
+    }
+
+    pub fn query_revealed_seed(&self, round: u64) -> Result<Option<HexBinary>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::RevealedSeed { round })
+    }
+
+    pub fn query_pending_flips(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> Result<Vec<PendingFlipsRespItem>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::PendingFlips {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    pub fn query_randomness_fee(&self) -> Result<Option<Uint128>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::RandomnessFee {})
+    }
+
+    pub fn query_randomness_requests(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> Result<Vec<RandomnessRequestsRespItem>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::RandomnessRequests {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    pub fn query_rakeback(&self) -> Result<Option<RakebackConfig>> {
+        self.transport.query(&self.contract, &QueryMsg::Rakeback {})
+    }
+
+    pub fn query_user_rakeback(&self, address: impl ToString) -> Result<UserRakebackResp> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::UserRakeback {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_contract_callers(&self) -> Result<Option<Vec<Addr>>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::ContractCallers {})
+    }
+
+    pub fn query_deferred_only(&self) -> Result<bool> {
+        self.transport
+            .query(&self.contract, &QueryMsg::DeferredOnly {})
+    }
+
+    pub fn query_nft_perks(&self) -> Result<Vec<NftPerksRespItem>> {
+        self.transport.query(&self.contract, &QueryMsg::NftPerks {})
+    }
+
+    pub fn query_revenue_split(&self) -> Result<Vec<RevenueSplitRespItem>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::RevenueSplit {})
+    }
+
+    pub fn query_payout_queue_position(
+        &self,
+        address: impl ToString,
+    ) -> Result<PayoutQueuePositionResp> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::PayoutQueuePosition {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    pub fn query_insurance(&self) -> Result<InsuranceResp> {
+        self.transport
+            .query(&self.contract, &QueryMsg::Insurance {})
+    }
+
+    pub fn query_buyback(&self) -> Result<Option<BuybackConfig>> {
+        self.transport.query(&self.contract, &QueryMsg::Buyback {})
+    }
+
+    pub fn query_oracle(&self) -> Result<Option<OracleConfig>> {
+        self.transport.query(&self.contract, &QueryMsg::Oracle {})
+    }
+
+    pub fn query_bet_range(&self) -> Result<BetRangeResp> {
+        self.transport.query(&self.contract, &QueryMsg::BetRange {})
+    }
+
+    pub fn query_promo_code(&self, hash: HexBinary) -> Result<Option<PromoCode>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::PromoCode { hash })
+    }
+
+    pub fn query_buyback_list(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> Result<Vec<BuybackListRespItem>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::BuybackList {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    pub fn query_nft_floor_prices(&self) -> Result<Vec<NftFloorPriceRespItem>> {
+        self.transport
+            .query(&self.contract, &QueryMsg::NftFloorPrices {})
+    }
+
+    pub fn query_house_nfts(
+        &self,
+        amount: u32,
+        start_after: Option<Nft>,
+    ) -> Result<Vec<HouseNftRespItem>> {
+        self.transport.query(
+            &self.contract,
+            &QueryMsg::HouseNfts {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    /// Pages through `UserList` like `capture-users` script does
+    pub fn query_all_users(&self, page_size: u32) -> Result<Vec<UserListRespItem>> {
+        let mut user_list: Vec<UserListRespItem> = vec![];
+        let mut start_after: Option<String> = None;
+
+        loop {
+            let page = self.query_user_list(page_size, start_after)?;
+            let is_last_page = page.len() < page_size as usize;
+            start_after = page.last().map(|x| x.address.to_string());
+            user_list.extend(page);
+
+            if is_last_page || start_after.is_none() {
+                break;
+            }
+        }
+
+        Ok(user_list)
+    }
+}
+
+/// Restores the contract error from any error in the chain
+pub fn get_contract_error(err: &anyhow::Error) -> Option<ContractError> {
+    err.chain().find_map(|x| decode_err(&x.to_string()))
+}
@@ -0,0 +1,10 @@
+pub mod client;
+pub mod outcome;
+pub mod transport;
+pub mod unsigned_tx;
+
+#[cfg(any(test, feature = "multi-test"))]
+pub mod multi_test;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use cosmwasm_std::{Addr, Coin, Empty, Querier, QuerierWrapper};
+use cw_multi_test::{AppResponse, Executor};
+use serde::de::DeserializeOwned;
+
+use cf_base::platform::msg::{ExecuteMsg, QueryMsg};
+
+use crate::transport::{ExecuteTransport, QueryTransport};
+
+/// Transport executing messages in cw-multi-test `App`
+pub struct MultiTestTransport<'a, A> {
+    pub app: &'a mut A,
+}
+
+impl<'a, A> MultiTestTransport<'a, A> {
+    pub fn new(app: &'a mut A) -> Self {
+        Self { app }
+    }
+}
+
+impl<'a, A> ExecuteTransport for MultiTestTransport<'a, A>
+where
+    A: Executor<Empty>,
+{
+    type Output = AppResponse;
+
+    fn execute(
+        &mut self,
+        sender: &str,
+        contract: &str,
+        msg: &ExecuteMsg,
+        funds: &[Coin],
+    ) -> Result<Self::Output> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            Addr::unchecked(contract),
+            msg,
+            funds,
+        )
+    }
+}
+
+impl<'a, A> QueryTransport for MultiTestTransport<'a, A>
+where
+    A: Querier,
+{
+    fn query<T: DeserializeOwned>(&self, contract: &str, msg: &QueryMsg) -> Result<T> {
+        Ok(QuerierWrapper::<Empty>::new(&*self.app).query_wasm_smart(contract, msg)?)
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::{anyhow, Context, Result};
+use cosmwasm_std::{Addr, Attribute, Decimal, Event, Uint128};
+
+use cf_base::{
+    platform::types::{Payout, Side},
+    utils::EVENT_TYPE,
+};
+
+/// Typed `try_flip` result read from `wasm-coinflip` event attributes
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlipOutcome {
+    pub user: Addr,
+    pub side: Side,
+    pub amount: Uint128,
+    pub random_weight: Decimal,
+    pub is_winner: bool,
+    pub payout: Payout,
+    pub prize: Uint128,
+    /// part of the lost bet added to user unclaimed, zero if the tier doesn't provide it
+    pub cashback: Uint128,
+    /// rakeback of finished epoch settled before the flip
+    pub rakeback: Uint128,
+}
+
+impl FlipOutcome {
+    /// Finds the flip event in tx (or `AppResponse`) events
+    pub fn from_events(events: &[Event]) -> Result<Self> {
+        let wasm_event_type = format!("wasm-{}", EVENT_TYPE);
+
+        events
+            .iter()
+            .filter(|x| x.ty == wasm_event_type || x.ty == EVENT_TYPE)
+            .find(|x| get_attr(&x.attributes, "action").ok() == Some("flip"))
+            .ok_or(anyhow!("Flip event is not found"))
+            .and_then(|x| Self::from_attributes(&x.attributes))
+    }
+
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let outcome = get_attr(attributes, "outcome")?;
+
+        Ok(Self {
+            user: Addr::unchecked(get_attr(attributes, "user")?),
+            side: parse_attr(attributes, "side")?,
+            amount: parse_attr(attributes, "amount")?,
+            random_weight: parse_attr(attributes, "random_weight")?,
+            is_winner: match outcome {
+                "win" => true,
+                "lose" => false,
+                _ => Err(anyhow!("Unknown outcome {}", outcome))?,
+            },
+            payout: parse_attr(attributes, "payout")?,
+            prize: parse_attr(attributes, "prize")?,
+            cashback: parse_opt_attr(attributes, "cashback")?.unwrap_or_default(),
+            rakeback: parse_opt_attr(attributes, "rakeback")?.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(any(test, feature = "multi-test"))]
+impl TryFrom<&cw_multi_test::AppResponse> for FlipOutcome {
+    type Error = anyhow::Error;
+
+    fn try_from(res: &cw_multi_test::AppResponse) -> Result<Self> {
+        Self::from_events(&res.events)
+    }
+}
+
+fn get_attr<'a>(attributes: &'a [Attribute], key: &str) -> Result<&'a str> {
+    attributes
+        .iter()
+        .find(|x| x.key == key)
+        .map(|x| x.value.as_str())
+        .ok_or(anyhow!("Attribute {} is not found", key))
+}
+
+fn parse_attr<T>(attributes: &[Attribute], key: &str) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let value = get_attr(attributes, key)?;
+
+    value
+        .parse()
+        .with_context(|| format!("Can't parse {} = {}", key, value))
+}
+
+fn parse_opt_attr<T>(attributes: &[Attribute], key: &str) -> Result<Option<T>>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    if get_attr(attributes, key).is_err() {
+        return Ok(None);
+    }
+
+    parse_attr(attributes, key).map(Some)
+}
@@ -0,0 +1,186 @@
+use cosmwasm_std::{coin, coins, testing::MockApi, Addr, Event, Uint128};
+use cw_multi_test::{App, AppBuilder, ContractWrapper, Executor};
+use speculoos::prelude::*;
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::{
+        msg::{ExecuteMsg, InstantiateMsg},
+        state::DENOM,
+        types::{Payout, Side},
+    },
+    utils::Attrs,
+};
+
+use crate::{
+    client::{get_contract_error, PlatformClient},
+    multi_test::MultiTestTransport,
+    outcome::FlipOutcome,
+    unsigned_tx::{UnsignedTxTransport, MSG_EXECUTE_CONTRACT_TYPE_URL},
+};
+
+const INITIAL_FUNDS: u128 = 1_000_000_000;
+
+fn instantiate() -> (App, Addr, Vec<Addr>) {
+    let account_list: Vec<Addr> = ["owner", "alice", "bob"]
+        .iter()
+        .map(|x| MockApi::default().addr_make(x))
+        .collect();
+
+    let mut app = AppBuilder::new().build(|router, _, storage| {
+        for account in &account_list {
+            router
+                .bank
+                .init_balance(storage, account, coins(INITIAL_FUNDS, DENOM))
+                .unwrap();
+        }
+    });
+
+    let code_id = app.store_code(Box::new(ContractWrapper::new(
+        platform::contract::execute,
+        platform::contract::instantiate,
+        platform::contract::query,
+    )));
+    let contract = app
+        .instantiate_contract(
+            code_id,
+            account_list[0].clone(),
+            &InstantiateMsg {
+                worker: None,
+                bet: None,
+                platform_fee: None,
+            },
+            &[],
+            "platform",
+            None,
+        )
+        .unwrap();
+
+    (app, contract, account_list)
+}
+
+#[test]
+fn multi_test_transport() {
+    let (mut app, contract, account_list) = instantiate();
+    let (owner, alice, bob) = (&account_list[0], &account_list[1], &account_list[2]);
+    let mut client = PlatformClient::new(MultiTestTransport::new(&mut app), &contract);
+
+    client.deposit(owner, 50_000_000u128).unwrap();
+
+    let res = client.flip(alice, Side::Head, 1_000_000u128).unwrap();
+    let outcome = FlipOutcome::try_from(&res).unwrap();
+    assert_that(&outcome.user).is_equal_to(alice);
+    assert_that(&outcome.side).is_equal_to(Side::Head);
+    assert_that(&outcome.amount.u128()).is_equal_to(1_000_000);
+    assert_that(&outcome.is_winner).is_equal_to(outcome.payout == Payout::AutoPaid);
+
+    let res = client.flip(bob, Side::Tail, 2_000_000u128).unwrap();
+    let outcome = FlipOutcome::from_events(&res.events).unwrap();
+    assert_that(&outcome.user).is_equal_to(bob);
+
+    let config = client.query_config().unwrap();
+    assert_that(&config.admin).is_equal_to(owner);
+    assert_that(&config.denom.as_str()).is_equal_to(DENOM);
+
+    let app_info = client.query_app_info().unwrap();
+    assert_that(&app_info.deposited.u128()).is_equal_to(50_000_000);
+    assert_that(&app_info.user_stats.bets.value.u128()).is_equal_to(3_000_000);
+    assert_that(&client.query_available_to_withdraw().is_ok()).is_true();
+    assert_that(&client.query_required_to_deposit().is_ok()).is_true();
+
+    let user = client.query_user(alice).unwrap();
+    assert_that(&user.stats.bets.count).is_equal_to(1);
+
+    let user_list = client.query_all_users(1).unwrap();
+    assert_that(&user_list).has_length(2);
+    assert_that(&client.query_user_list(10, None).unwrap()).is_equal_to(user_list);
+
+    // balances are changed by the transport
+    let bob_balance = app.wrap().query_balance(bob, DENOM).unwrap();
+    assert_that(&(bob_balance.amount.u128() < INITIAL_FUNDS)).is_equal_to(!outcome.is_winner);
+}
+
+#[test]
+fn contract_errors_are_restored() {
+    let (mut app, contract, account_list) = instantiate();
+    let alice = &account_list[1];
+    let mut client = PlatformClient::new(MultiTestTransport::new(&mut app), &contract);
+
+    let err = client.flip(alice, Side::Head, 100u128).unwrap_err();
+    assert_that(&get_contract_error(&err)).is_equal_to(Some(ContractError::BetIsOutOfRange));
+
+    let err = client.pause(alice).unwrap_err();
+    assert_that(&get_contract_error(&err)).is_equal_to(Some(ContractError::Unauthorized));
+
+    let err = anyhow::anyhow!("connection refused");
+    assert_that(&get_contract_error(&err)).is_none();
+}
+
+#[test]
+fn unsigned_tx_transport() {
+    let mut client = PlatformClient::new(
+        UnsignedTxTransport::new(300_000, vec![coin(7_500, DENOM)]).with_memo("flip"),
+        "contract",
+    );
+
+    let tx = client.flip("alice", Side::Tail, 1_000_000u128).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&tx.to_json().unwrap()).unwrap();
+
+    assert_that(&json["body"]["messages"][0]).is_equal_to(serde_json::json!({
+        "@type": MSG_EXECUTE_CONTRACT_TYPE_URL,
+        "sender": "alice",
+        "contract": "contract",
+        "msg": { "flip": { "side": "tail", "nft": null, "client_seed": null, "callback": null, "amount": null, "payout_to": null } },
+        "funds": [{ "denom": DENOM, "amount": "1000000" }]
+    }));
+    assert_that(&json["body"]["memo"]).is_equal_to(serde_json::json!("flip"));
+    assert_that(&json["auth_info"]["fee"]).is_equal_to(serde_json::json!({
+        "amount": [{ "denom": DENOM, "amount": "7500" }],
+        "gas_limit": "300000",
+        "payer": "",
+        "granter": ""
+    }));
+    assert_that(&json["signatures"]).is_equal_to(serde_json::json!([]));
+
+    let tx = client
+        .withdraw("owner", Some(Uint128::new(5)), None)
+        .unwrap();
+    assert_that(&tx.body.messages[0].msg).is_equal_to(ExecuteMsg::Withdraw {
+        amount: Some(Uint128::new(5)),
+        recipient: None,
+    });
+    assert_that(&tx.body.messages[0].funds).is_empty();
+}
+
+#[test]
+fn flip_outcome_from_attributes() {
+    let user = Addr::unchecked("alice");
+    let random_weight = str_to_dec("0.690849493452539158");
+    let event: Event = Attrs::flip(
+        &user,
+        &Side::Head,
+        Uint128::new(1_000_000),
+        random_weight,
+        &Payout::Unclaimed,
+        Uint128::new(2_000_000),
+    )
+    .into();
+
+    let expected = FlipOutcome {
+        user,
+        side: Side::Head,
+        amount: Uint128::new(1_000_000),
+        random_weight,
+        is_winner: true,
+        payout: Payout::Unclaimed,
+        prize: Uint128::new(2_000_000),
+        cashback: Uint128::zero(),
+        rakeback: Uint128::zero(),
+    };
+
+    assert_that(&FlipOutcome::from_attributes(&event.attributes).unwrap()).is_equal_to(&expected);
+    assert_that(&FlipOutcome::from_events(&[Event::new("wasm"), event]).unwrap())
+        .is_equal_to(&expected);
+    assert_that(&FlipOutcome::from_events(&[Event::new("wasm")]).is_err()).is_true();
+}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+use cosmwasm_std::Coin;
+use serde::de::DeserializeOwned;
+
+use cf_base::platform::msg::{ExecuteMsg, QueryMsg};
+
+/// Delivers execute messages to the contract. `Output` depends on the transport:
+/// it can be a tx result as well as a tx to be signed and broadcasted later
+pub trait ExecuteTransport {
+    type Output;
+
+    fn execute(
+        &mut self,
+        sender: &str,
+        contract: &str,
+        msg: &ExecuteMsg,
+        funds: &[Coin],
+    ) -> Result<Self::Output>;
+}
+
+/// Runs smart queries against the contract
+pub trait QueryTransport {
+    fn query<T: DeserializeOwned>(&self, contract: &str, msg: &QueryMsg) -> Result<T>;
+}
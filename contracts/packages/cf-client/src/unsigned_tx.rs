@@ -0,0 +1,131 @@
+use anyhow::Result;
+use cosmwasm_std::Coin;
+use serde::Serialize;
+
+use cf_base::platform::msg::ExecuteMsg;
+
+use crate::transport::ExecuteTransport;
+
+pub const MSG_EXECUTE_CONTRACT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+pub const DEFAULT_GAS_LIMIT: u64 = 500_000;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MsgExecuteContract {
+    #[serde(rename = "@type")]
+    pub type_url: String,
+    pub sender: String,
+    pub contract: String,
+    pub msg: ExecuteMsg,
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TxBody {
+    pub messages: Vec<MsgExecuteContract>,
+    pub memo: String,
+    pub timeout_height: String,
+    pub extension_options: Vec<serde_json::Value>,
+    pub non_critical_extension_options: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Fee {
+    pub amount: Vec<Coin>,
+    pub gas_limit: String,
+    pub payer: String,
+    pub granter: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AuthInfo {
+    pub signer_infos: Vec<serde_json::Value>,
+    pub fee: Fee,
+}
+
+/// Unsigned tx in the same format as `wasmd tx wasm execute --generate-only` output.
+/// It can be signed by `wasmd tx sign` or any wallet supporting JSON txs
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct UnsignedTx {
+    pub body: TxBody,
+    pub auth_info: AuthInfo,
+    pub signatures: Vec<String>,
+}
+
+impl UnsignedTx {
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Transport producing unsigned txs instead of broadcasting the messages
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsignedTxTransport {
+    pub memo: String,
+    pub gas_limit: u64,
+    pub fee: Vec<Coin>,
+}
+
+impl Default for UnsignedTxTransport {
+    fn default() -> Self {
+        Self {
+            memo: String::default(),
+            gas_limit: DEFAULT_GAS_LIMIT,
+            fee: vec![],
+        }
+    }
+}
+
+impl UnsignedTxTransport {
+    pub fn new(gas_limit: u64, fee: Vec<Coin>) -> Self {
+        Self {
+            gas_limit,
+            fee,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_memo(self, memo: impl ToString) -> Self {
+        Self {
+            memo: memo.to_string(),
+            ..self
+        }
+    }
+}
+
+impl ExecuteTransport for UnsignedTxTransport {
+    type Output = UnsignedTx;
+
+    fn execute(
+        &mut self,
+        sender: &str,
+        contract: &str,
+        msg: &ExecuteMsg,
+        funds: &[Coin],
+    ) -> Result<Self::Output> {
+        Ok(UnsignedTx {
+            body: TxBody {
+                messages: vec![MsgExecuteContract {
+                    type_url: MSG_EXECUTE_CONTRACT_TYPE_URL.to_string(),
+                    sender: sender.to_string(),
+                    contract: contract.to_string(),
+                    msg: msg.to_owned(),
+                    funds: funds.to_vec(),
+                }],
+                memo: self.memo.to_owned(),
+                timeout_height: "0".to_string(),
+                extension_options: vec![],
+                non_critical_extension_options: vec![],
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![],
+                fee: Fee {
+                    amount: self.fee.to_owned(),
+                    gas_limit: self.gas_limit.to_string(),
+                    payer: String::default(),
+                    granter: String::default(),
+                },
+            },
+            signatures: vec![],
+        })
+    }
+}
@@ -0,0 +1,866 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{anyhow, Context, Result};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Int256, Uint128};
+
+use cf_base::{
+    platform::types::{
+        Config, IbcTarget, Nft, NftPerk, Payout, PromoCode, RakebackConfig, Range, Side,
+    },
+    utils::EVENT_TYPE,
+};
+
+use crate::tx::{TxEvent, TxResponse};
+
+/// App state attached to the event by the contract, used to verify the replay
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppSnapshot {
+    pub balance: Uint128,
+    pub deposited: Uint128,
+    pub revenue_total: Int256,
+    pub revenue_current: Int256,
+    pub user_unclaimed: Uint128,
+    pub insurance: Uint128,
+    pub pending_bets: Uint128,
+    pub user_balances: Uint128,
+    pub bonus_balances: Uint128,
+}
+
+/// User state attached to the event by the contract, used to verify the replay
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSnapshot {
+    pub unclaimed: Uint128,
+    pub last_flip_date: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoinflipEvent {
+    Instantiate {
+        config: Config,
+    },
+    Flip {
+        user: Addr,
+        side: Side,
+        amount: Uint128,
+        random_weight: Decimal,
+        payout: Payout,
+        prize: Uint128,
+        /// zero if the tier doesn't provide cashback
+        cashback: Uint128,
+        /// part of the lost bet moved to the insurance
+        insurance_fill: Uint128,
+        /// settled rakeback of finished epoch
+        rakeback: Uint128,
+        /// NFT which perks were applied
+        nft: Option<Nft>,
+        /// seeded flip or flip with callback
+        flip_id: Option<u64>,
+        /// round of seeded flip settled by the revealed seed
+        round: Option<u64>,
+        /// contract notified with the flip result
+        callback: Option<Addr>,
+        /// the bet was taken from the user balance, auto paid prize was credited to it
+        from_balance: bool,
+        /// session key which placed the bet on behalf of the user
+        session: Option<Addr>,
+        /// original chain sender of ibc-hooks flip registered with the bet
+        ibc_target: Option<IbcTarget>,
+    },
+    PendingFlip {
+        user: Addr,
+        id: u64,
+        side: Side,
+        amount: Uint128,
+        client_seed: String,
+        nonce: u64,
+        round: u64,
+        nft: Option<Nft>,
+        callback: Option<Addr>,
+        from_balance: bool,
+        session: Option<Addr>,
+        ibc_target: Option<IbcTarget>,
+    },
+    FlipCallbackFailed {
+        flip_id: u64,
+        error: String,
+    },
+    RefundFlip {
+        user: Addr,
+        id: u64,
+        amount: Uint128,
+        /// the bet paid by the house for the missed reveal
+        penalty: Uint128,
+        payout: Payout,
+        /// the bet was credited back to the user balance
+        from_balance: bool,
+    },
+    GrantSession {
+        owner: Addr,
+        session: Addr,
+        spend_cap: Uint128,
+        expiration: u64,
+        side: Option<Side>,
+        bet: Option<Range>,
+    },
+    RevokeSession {
+        owner: Addr,
+        session: Addr,
+    },
+    TopUp {
+        user: Addr,
+        amount: Uint128,
+        user_balance: Uint128,
+    },
+    WithdrawBalance {
+        user: Addr,
+        amount: Uint128,
+        user_balance: Uint128,
+    },
+    RequestRandomness {
+        requester: Addr,
+        id: u64,
+        job_id: String,
+        callback: Addr,
+        fee: Uint128,
+        /// round of deferred request
+        round: Option<u64>,
+        /// delivered randomness of immediate request
+        randomness: Option<HexBinary>,
+    },
+    FulfillRandomness {
+        requester: Addr,
+        id: u64,
+        job_id: String,
+        callback: Addr,
+        fee: Uint128,
+        round: u64,
+        seed: HexBinary,
+        randomness: HexBinary,
+    },
+    RefundRandomness {
+        requester: Addr,
+        id: u64,
+        job_id: String,
+        fee: Uint128,
+    },
+    RandomnessCallbackFailed {
+        id: u64,
+        error: String,
+    },
+    NftFlip {
+        user: Addr,
+        side: Side,
+        nft: Nft,
+        random_weight: Decimal,
+        payout: Payout,
+        /// floor price on win
+        prize: Uint128,
+    },
+    BonusFlip {
+        user: Addr,
+        side: Side,
+        amount: Uint128,
+        random_weight: Decimal,
+        payout: Payout,
+        /// winnings beyond the stake, the stake returns to the bonus balance on win
+        prize: Uint128,
+        bonus_balance: Uint128,
+        wagered: Uint128,
+        /// the winnings were credited to the bonus balance
+        locked: bool,
+    },
+    RedeemPromoCode {
+        user: Addr,
+        hash: HexBinary,
+        amount: Uint128,
+        wagering: u32,
+        bonus_balance: Uint128,
+    },
+    Claim {
+        user: Addr,
+        amount: Uint128,
+    },
+    ReturnIbcPayout {
+        user: Addr,
+        channel_id: String,
+        /// the transfer was rejected without sending the packet if it's omitted
+        sequence: Option<u64>,
+        amount: Uint128,
+    },
+    ClaimRakeback {
+        user: Addr,
+        amount: Uint128,
+        /// settled rakeback of finished epoch
+        rakeback: Uint128,
+    },
+    Deposit {
+        sender: Addr,
+        amount: Uint128,
+        /// users paid from the payout queue
+        payouts: Vec<(Addr, Uint128)>,
+    },
+    Withdraw {
+        sender: Addr,
+        recipient: Addr,
+        amount: Uint128,
+    },
+    DepositInsurance {
+        sender: Addr,
+        amount: Uint128,
+    },
+    WithdrawInsurance {
+        sender: Addr,
+        recipient: Addr,
+        amount: Uint128,
+    },
+    ProcessPayouts {
+        sender: Addr,
+        amount: Uint128,
+        payouts: Vec<(Addr, Uint128)>,
+    },
+    DistributeRevenue {
+        sender: Addr,
+        amount: Uint128,
+    },
+    Buyback {
+        sender: Addr,
+        pair: Addr,
+        amount_in: Uint128,
+    },
+    Burn {
+        id: u64,
+        token: String,
+        amount: Uint128,
+    },
+    CommitSeedChain {
+        sender: Addr,
+        head: HexBinary,
+        round: u64,
+    },
+    RevealSeed {
+        sender: Addr,
+        round: u64,
+        seed: HexBinary,
+    },
+    EnqueuePayouts {
+        sender: Addr,
+        users: u32,
+        last: Option<Addr>,
+    },
+    AcceptAdminRole {
+        admin: Addr,
+    },
+    UpdateConfig {
+        sender: Addr,
+        new_admin: Option<Addr>,
+        config: Config,
+    },
+    UpdateTiers {
+        sender: Addr,
+        tiers: u32,
+    },
+    SetUserTier {
+        sender: Addr,
+        user: Addr,
+        tier: Option<u8>,
+    },
+    UpdateRakeback {
+        sender: Addr,
+        rakeback: Option<RakebackConfig>,
+    },
+    UpdateRevenueSplit {
+        sender: Addr,
+        recipients: u32,
+    },
+    UpdateBuyback {
+        sender: Addr,
+        pair: Option<Addr>,
+        share: Option<Decimal>,
+    },
+    UpdateOracle {
+        sender: Addr,
+        contract: Option<Addr>,
+        bet: Option<Range>,
+        max_age: Option<u64>,
+    },
+    CreditBonus {
+        sender: Addr,
+        amount: Uint128,
+        wagering: u32,
+        credits: Vec<(Addr, Uint128)>,
+    },
+    UpdatePromoCode {
+        sender: Addr,
+        hash: HexBinary,
+        /// `None` if the code is removed
+        promo: Option<PromoCode>,
+    },
+    UpdateInsurance {
+        sender: Addr,
+        rate: Option<Decimal>,
+        target: Option<Uint128>,
+    },
+    UpdateContractCallers {
+        sender: Addr,
+        whitelist: Option<Vec<Addr>>,
+    },
+    UpdateDeferredOnly {
+        sender: Addr,
+        enabled: bool,
+    },
+    UpdateRandomnessFee {
+        sender: Addr,
+        fee: Option<Uint128>,
+    },
+    UpdateNftPerk {
+        sender: Addr,
+        collection: Addr,
+        perk: Option<NftPerk>,
+    },
+    UpdateNftFloorPrice {
+        sender: Addr,
+        collection: Addr,
+        price: Option<Uint128>,
+    },
+    WithdrawNft {
+        sender: Addr,
+        recipient: Addr,
+        nft: Nft,
+    },
+    Pause {
+        sender: Addr,
+    },
+    Unpause {
+        sender: Addr,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedEvent {
+    pub height: u64,
+    pub txhash: String,
+    pub contract_address: Addr,
+    pub event: CoinflipEvent,
+    pub user_snapshot: Option<UserSnapshot>,
+    pub app_snapshot: Option<AppSnapshot>,
+}
+
+/// Extracts `wasm-coinflip` events from successful txs keeping the order. \
+/// Events of other contracts are skipped if `contract_address` is specified
+pub fn extract_events(
+    tx_list: &[TxResponse],
+    contract_address: Option<&str>,
+) -> Result<Vec<IndexedEvent>> {
+    let mut event_list: Vec<IndexedEvent> = vec![];
+    let event_type = format!("wasm-{}", EVENT_TYPE);
+
+    for tx in tx_list.iter().filter(|x| x.is_successful()) {
+        for tx_event in tx.get_events().into_iter().filter(|x| x.ty == event_type) {
+            let event = parse_event(tx, tx_event)
+                .with_context(|| format!("Can't parse event of tx {}", tx.txhash))?;
+
+            if contract_address.map_or(true, |x| event.contract_address.as_str() == x) {
+                event_list.push(event);
+            }
+        }
+    }
+
+    Ok(event_list)
+}
+
+fn parse_event(tx: &TxResponse, tx_event: &TxEvent) -> Result<IndexedEvent> {
+    let attrs = Attrs::new(tx_event);
+    let action = attrs.get("action")?;
+
+    let event = match action {
+        "instantiate" => CoinflipEvent::Instantiate {
+            config: attrs.config()?,
+        },
+        "flip" => CoinflipEvent::Flip {
+            user: attrs.addr("user")?,
+            side: attrs.parse("side")?,
+            amount: attrs.parse("amount")?,
+            random_weight: attrs.parse("random_weight")?,
+            payout: attrs.parse("payout")?,
+            prize: attrs.parse("prize")?,
+            cashback: attrs.parse_opt("cashback")?.unwrap_or_default(),
+            insurance_fill: attrs.parse_opt("insurance_fill")?.unwrap_or_default(),
+            rakeback: attrs.parse_opt("rakeback")?.unwrap_or_default(),
+            nft: attrs.opt_nft()?,
+            flip_id: attrs.parse_opt("flip_id")?,
+            round: attrs.parse_opt("round")?,
+            callback: attrs.opt_addr("callback"),
+            from_balance: attrs.contains("from_balance"),
+            session: attrs.opt_addr("session"),
+            ibc_target: attrs.opt_ibc_target()?,
+        },
+        "pending_flip" => CoinflipEvent::PendingFlip {
+            user: attrs.addr("user")?,
+            id: attrs.parse("id")?,
+            side: attrs.parse("side")?,
+            amount: attrs.parse("amount")?,
+            client_seed: attrs.get("client_seed")?.to_string(),
+            nonce: attrs.parse("nonce")?,
+            round: attrs.parse("round")?,
+            nft: attrs.opt_nft()?,
+            callback: attrs.opt_addr("callback"),
+            from_balance: attrs.contains("from_balance"),
+            session: attrs.opt_addr("session"),
+            ibc_target: attrs.opt_ibc_target()?,
+        },
+        "flip_callback_failed" => CoinflipEvent::FlipCallbackFailed {
+            flip_id: attrs.parse("flip_id")?,
+            error: attrs.get("error")?.to_string(),
+        },
+        "refund_flip" => CoinflipEvent::RefundFlip {
+            user: attrs.addr("user")?,
+            id: attrs.parse("id")?,
+            amount: attrs.parse("amount")?,
+            penalty: attrs.parse("penalty")?,
+            payout: attrs.parse("payout")?,
+            from_balance: attrs.contains("from_balance"),
+        },
+        "grant_session" => CoinflipEvent::GrantSession {
+            owner: attrs.addr("owner")?,
+            session: attrs.addr("session")?,
+            spend_cap: attrs.parse("spend_cap")?,
+            expiration: attrs.parse("expiration")?,
+            side: attrs.parse_opt("side")?,
+            bet: match (attrs.parse_opt("bet_min")?, attrs.parse_opt("bet_max")?) {
+                (Some(min), Some(max)) => Some(Range { min, max }),
+                _ => None,
+            },
+        },
+        "revoke_session" => CoinflipEvent::RevokeSession {
+            owner: attrs.addr("owner")?,
+            session: attrs.addr("session")?,
+        },
+        "top_up" => CoinflipEvent::TopUp {
+            user: attrs.addr("user")?,
+            amount: attrs.parse("amount")?,
+            user_balance: attrs.parse("user_balance")?,
+        },
+        "withdraw_balance" => CoinflipEvent::WithdrawBalance {
+            user: attrs.addr("user")?,
+            amount: attrs.parse("amount")?,
+            user_balance: attrs.parse("user_balance")?,
+        },
+        "request_randomness" => CoinflipEvent::RequestRandomness {
+            requester: attrs.addr("requester")?,
+            id: attrs.parse("id")?,
+            job_id: attrs.get("job_id")?.to_string(),
+            callback: attrs.addr("callback")?,
+            fee: attrs.parse("fee")?,
+            round: attrs.parse_opt("round")?,
+            randomness: attrs.opt_hex("randomness")?,
+        },
+        "fulfill_randomness" => CoinflipEvent::FulfillRandomness {
+            requester: attrs.addr("requester")?,
+            id: attrs.parse("id")?,
+            job_id: attrs.get("job_id")?.to_string(),
+            callback: attrs.addr("callback")?,
+            fee: attrs.parse("fee")?,
+            round: attrs.parse("round")?,
+            seed: attrs.hex("seed")?,
+            randomness: attrs.hex("randomness")?,
+        },
+        "refund_randomness" => CoinflipEvent::RefundRandomness {
+            requester: attrs.addr("requester")?,
+            id: attrs.parse("id")?,
+            job_id: attrs.get("job_id")?.to_string(),
+            fee: attrs.parse("fee")?,
+        },
+        "randomness_callback_failed" => CoinflipEvent::RandomnessCallbackFailed {
+            id: attrs.parse("id")?,
+            error: attrs.get("error")?.to_string(),
+        },
+        "nft_flip" => CoinflipEvent::NftFlip {
+            user: attrs.addr("user")?,
+            side: attrs.parse("side")?,
+            nft: attrs.nft()?,
+            random_weight: attrs.parse("random_weight")?,
+            payout: attrs.parse("payout")?,
+            prize: attrs.parse("prize")?,
+        },
+        "bonus_flip" => CoinflipEvent::BonusFlip {
+            user: attrs.addr("user")?,
+            side: attrs.parse("side")?,
+            amount: attrs.parse("amount")?,
+            random_weight: attrs.parse("random_weight")?,
+            payout: attrs.parse("payout")?,
+            prize: attrs.parse("prize")?,
+            bonus_balance: attrs.parse("bonus_balance")?,
+            wagered: attrs.parse("wagered")?,
+            locked: attrs.parse_opt("locked")?.unwrap_or_default(),
+        },
+        "redeem_promo_code" => CoinflipEvent::RedeemPromoCode {
+            user: attrs.addr("user")?,
+            hash: attrs.hex("hash")?,
+            amount: attrs.parse("amount")?,
+            wagering: attrs.parse("wagering")?,
+            bonus_balance: attrs.parse("bonus_balance")?,
+        },
+        "claim" => CoinflipEvent::Claim {
+            user: attrs.addr("user")?,
+            amount: attrs.parse("amount")?,
+        },
+        "return_ibc_payout" => CoinflipEvent::ReturnIbcPayout {
+            user: attrs.addr("user")?,
+            channel_id: attrs.get("channel_id")?.to_string(),
+            sequence: attrs.parse_opt("sequence")?,
+            amount: attrs.parse("amount")?,
+        },
+        "claim_rakeback" => CoinflipEvent::ClaimRakeback {
+            user: attrs.addr("user")?,
+            amount: attrs.parse("amount")?,
+            rakeback: attrs.parse_opt("rakeback")?.unwrap_or_default(),
+        },
+        "deposit" => CoinflipEvent::Deposit {
+            sender: attrs.addr("sender")?,
+            amount: attrs.parse("amount")?,
+            payouts: attrs.payouts()?,
+        },
+        "withdraw" => CoinflipEvent::Withdraw {
+            sender: attrs.addr("sender")?,
+            recipient: attrs.addr("recipient")?,
+            amount: attrs.parse("amount")?,
+        },
+        "deposit_insurance" => CoinflipEvent::DepositInsurance {
+            sender: attrs.addr("sender")?,
+            amount: attrs.parse("amount")?,
+        },
+        "withdraw_insurance" => CoinflipEvent::WithdrawInsurance {
+            sender: attrs.addr("sender")?,
+            recipient: attrs.addr("recipient")?,
+            amount: attrs.parse("amount")?,
+        },
+        "process_payouts" => CoinflipEvent::ProcessPayouts {
+            sender: attrs.addr("sender")?,
+            amount: attrs.parse("amount")?,
+            payouts: attrs.payouts()?,
+        },
+        "distribute_revenue" => CoinflipEvent::DistributeRevenue {
+            sender: attrs.addr("sender")?,
+            amount: attrs.parse("amount")?,
+        },
+        "buyback" => CoinflipEvent::Buyback {
+            sender: attrs.addr("sender")?,
+            pair: attrs.addr("pair")?,
+            amount_in: attrs.parse("amount_in")?,
+        },
+        "burn" => CoinflipEvent::Burn {
+            id: attrs.parse("id")?,
+            token: attrs.get("token")?.to_string(),
+            amount: attrs.parse("amount")?,
+        },
+        "commit_seed_chain" => CoinflipEvent::CommitSeedChain {
+            sender: attrs.addr("sender")?,
+            head: attrs.hex("head")?,
+            round: attrs.parse("round")?,
+        },
+        "reveal_seed" => CoinflipEvent::RevealSeed {
+            sender: attrs.addr("sender")?,
+            round: attrs.parse("round")?,
+            seed: attrs.hex("seed")?,
+        },
+        "enqueue_payouts" => CoinflipEvent::EnqueuePayouts {
+            sender: attrs.addr("sender")?,
+            users: attrs.parse("users")?,
+            last: attrs.opt_addr("last"),
+        },
+        "accept_admin_role" => CoinflipEvent::AcceptAdminRole {
+            admin: attrs.addr("admin")?,
+        },
+        "update_config" => CoinflipEvent::UpdateConfig {
+            sender: attrs.addr("sender")?,
+            new_admin: attrs.opt_addr("new_admin"),
+            config: attrs.config()?,
+        },
+        "update_tiers" => CoinflipEvent::UpdateTiers {
+            sender: attrs.addr("sender")?,
+            tiers: attrs.parse("tiers")?,
+        },
+        "set_user_tier" => CoinflipEvent::SetUserTier {
+            sender: attrs.addr("sender")?,
+            user: attrs.addr("user")?,
+            tier: attrs.parse_opt("tier")?,
+        },
+        "update_rakeback" => CoinflipEvent::UpdateRakeback {
+            sender: attrs.addr("sender")?,
+            rakeback: match attrs.parse_opt("epoch")? {
+                Some(epoch) => Some(RakebackConfig {
+                    rate: attrs.parse("rate")?,
+                    epoch,
+                }),
+                None => None,
+            },
+        },
+        "update_revenue_split" => CoinflipEvent::UpdateRevenueSplit {
+            sender: attrs.addr("sender")?,
+            recipients: attrs.parse("recipients")?,
+        },
+        "update_buyback" => CoinflipEvent::UpdateBuyback {
+            sender: attrs.addr("sender")?,
+            pair: attrs.opt_addr("pair"),
+            share: attrs.parse_opt("share")?,
+        },
+        "update_oracle" => CoinflipEvent::UpdateOracle {
+            sender: attrs.addr("sender")?,
+            contract: attrs.opt_addr("contract"),
+            bet: match attrs.contains("bet_min") {
+                true => Some(Range {
+                    min: attrs.parse("bet_min")?,
+                    max: attrs.parse("bet_max")?,
+                }),
+                false => None,
+            },
+            max_age: attrs.parse_opt("max_age")?,
+        },
+        "credit_bonus" => CoinflipEvent::CreditBonus {
+            sender: attrs.addr("sender")?,
+            amount: attrs.parse("amount")?,
+            wagering: attrs.parse("wagering")?,
+            credits: attrs.address_amounts("credits", "credit")?,
+        },
+        "update_promo_code" => CoinflipEvent::UpdatePromoCode {
+            sender: attrs.addr("sender")?,
+            hash: attrs.hex("hash")?,
+            promo: match attrs.contains("amount") {
+                true => Some(PromoCode {
+                    amount: attrs.parse("amount")?,
+                    wagering: attrs.parse("wagering")?,
+                    redemptions: attrs.parse("redemptions")?,
+                }),
+                false => None,
+            },
+        },
+        "update_insurance" => CoinflipEvent::UpdateInsurance {
+            sender: attrs.addr("sender")?,
+            rate: attrs.parse_opt("rate")?,
+            target: attrs.parse_opt("target")?,
+        },
+        "update_contract_callers" => CoinflipEvent::UpdateContractCallers {
+            sender: attrs.addr("sender")?,
+            whitelist: attrs.contract_callers()?,
+        },
+        "update_deferred_only" => CoinflipEvent::UpdateDeferredOnly {
+            sender: attrs.addr("sender")?,
+            enabled: attrs.parse("enabled")?,
+        },
+        "update_randomness_fee" => CoinflipEvent::UpdateRandomnessFee {
+            sender: attrs.addr("sender")?,
+            fee: attrs.parse_opt("fee")?,
+        },
+        "update_nft_perk" => CoinflipEvent::UpdateNftPerk {
+            sender: attrs.addr("sender")?,
+            collection: attrs.addr("collection")?,
+            perk: match attrs.parse_opt("fee_discount")? {
+                Some(fee_discount) => Some(NftPerk {
+                    fee_discount,
+                    bet_max: attrs.parse_opt("bet_max")?,
+                }),
+                None => None,
+            },
+        },
+        "update_nft_floor_price" => CoinflipEvent::UpdateNftFloorPrice {
+            sender: attrs.addr("sender")?,
+            collection: attrs.addr("collection")?,
+            price: attrs.parse_opt("price")?,
+        },
+        "withdraw_nft" => CoinflipEvent::WithdrawNft {
+            sender: attrs.addr("sender")?,
+            recipient: attrs.addr("recipient")?,
+            nft: attrs.nft()?,
+        },
+        "pause" => CoinflipEvent::Pause {
+            sender: attrs.addr("sender")?,
+        },
+        "unpause" => CoinflipEvent::Unpause {
+            sender: attrs.addr("sender")?,
+        },
+        _ => Err(anyhow!("Unknown action {}", action))?,
+    };
+
+    let user_snapshot = if attrs.contains("unclaimed") {
+        Some(UserSnapshot {
+            unclaimed: attrs.parse("unclaimed")?,
+            last_flip_date: attrs.parse("last_flip_date")?,
+        })
+    } else {
+        None
+    };
+
+    let app_snapshot = if attrs.contains("balance") {
+        Some(AppSnapshot {
+            balance: attrs.parse("balance")?,
+            deposited: attrs.parse("deposited")?,
+            revenue_total: attrs.parse("revenue_total")?,
+            revenue_current: attrs.parse("revenue_current")?,
+            user_unclaimed: attrs.parse("user_unclaimed")?,
+            // it isn't emitted before the insurance was added
+            insurance: attrs.parse_opt("insurance")?.unwrap_or_default(),
+            // it isn't emitted before seeded flips were added
+            pending_bets: attrs.parse_opt("pending_bets")?.unwrap_or_default(),
+            // it isn't emitted before user balances were added
+            user_balances: attrs.parse_opt("user_balances")?.unwrap_or_default(),
+            // it isn't emitted before bonus balances were added
+            bonus_balances: attrs.parse_opt("bonus_balances")?.unwrap_or_default(),
+        })
+    } else {
+        None
+    };
+
+    Ok(IndexedEvent {
+        height: tx.height,
+        txhash: tx.txhash.to_owned(),
+        contract_address: attrs.addr("_contract_address")?,
+        event,
+        user_snapshot,
+        app_snapshot,
+    })
+}
+
+struct Attrs<'a> {
+    map: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Attrs<'a> {
+    fn new(tx_event: &'a TxEvent) -> Self {
+        Self {
+            map: tx_event
+                .attributes
+                .iter()
+                .map(|x| (x.key.as_str(), x.value.as_str()))
+                .collect(),
+        }
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        self.map.contains_key(key)
+    }
+
+    fn get(&self, key: &str) -> Result<&'a str> {
+        self.map
+            .get(key)
+            .copied()
+            .ok_or(anyhow!("Attribute {} is not found", key))
+    }
+
+    fn parse<T>(&self, key: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let value = self.get(key)?;
+
+        T::from_str(value).map_err(|e| anyhow!("Can't parse {} = {}: {}", key, value, e))
+    }
+
+    fn parse_opt<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        if !self.contains(key) {
+            return Ok(None);
+        }
+
+        self.parse(key).map(Some)
+    }
+
+    /// `payout_{n}` attributes in `address:amount` format, empty if the queue wasn't processed
+    fn payouts(&self) -> Result<Vec<(Addr, Uint128)>> {
+        self.address_amounts("payouts", "payout")
+    }
+
+    /// `{prefix}_{n}` attributes in `address:amount` format counted by `count_key`, empty if
+    /// the counter isn't emitted
+    fn address_amounts(&self, count_key: &str, prefix: &str) -> Result<Vec<(Addr, Uint128)>> {
+        let amount: u32 = self.parse_opt(count_key)?.unwrap_or_default();
+
+        (0..amount)
+            .map(|i| {
+                let key = format!("{}_{}", prefix, i);
+                let (address, amount) = self
+                    .get(&key)?
+                    .rsplit_once(':')
+                    .ok_or(anyhow!("Can't parse {}", key))?;
+
+                Ok((Addr::unchecked(address), Uint128::from_str(amount)?))
+            })
+            .collect()
+    }
+
+    /// `contract_{n}` attributes, `None` if any contract can flip
+    fn contract_callers(&self) -> Result<Option<Vec<Addr>>> {
+        let Some(amount) = self.parse_opt::<u32>("contracts")? else {
+            return Ok(None);
+        };
+
+        (0..amount)
+            .map(|i| self.addr(&format!("contract_{}", i)))
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
+    fn addr(&self, key: &str) -> Result<Addr> {
+        self.get(key).map(Addr::unchecked)
+    }
+
+    fn opt_addr(&self, key: &str) -> Option<Addr> {
+        self.get(key).ok().map(Addr::unchecked)
+    }
+
+    fn nft(&self) -> Result<Nft> {
+        Ok(Nft {
+            collection: self.get("nft_collection")?.to_string(),
+            token_id: self.get("nft_token_id")?.to_string(),
+        })
+    }
+
+    /// Original chain sender registered by ibc-hooks flip, `None` if it wasn't passed
+    fn opt_ibc_target(&self) -> Result<Option<IbcTarget>> {
+        if !self.contains("ibc_channel") {
+            return Ok(None);
+        }
+
+        Ok(Some(IbcTarget {
+            channel_id: self.get("ibc_channel")?.to_string(),
+            receiver: self.get("ibc_receiver")?.to_string(),
+        }))
+    }
+
+    /// NFT which perks were applied, `None` if it wasn't passed
+    fn opt_nft(&self) -> Result<Option<Nft>> {
+        if !self.contains("nft_collection") {
+            return Ok(None);
+        }
+
+        self.nft().map(Some)
+    }
+
+    fn hex(&self, key: &str) -> Result<HexBinary> {
+        HexBinary::from_hex(self.get(key)?).map_err(|e| anyhow!("Can't parse {}: {}", key, e))
+    }
+
+    fn opt_hex(&self, key: &str) -> Result<Option<HexBinary>> {
+        if !self.contains(key) {
+            return Ok(None);
+        }
+
+        self.hex(key).map(Some)
+    }
+
+    fn config(&self) -> Result<Config> {
+        Ok(Config {
+            admin: self.addr("admin")?,
+            worker: self.opt_addr("worker"),
+            bet: Range {
+                min: self.parse("bet_min")?,
+                max: self.parse("bet_max")?,
+            },
+            denom: self.get("denom")?.to_string(),
+            platform_fee: self.parse("platform_fee")?,
+        })
+    }
+}
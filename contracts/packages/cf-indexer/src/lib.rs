@@ -0,0 +1,7 @@
+pub mod events;
+pub mod model;
+pub mod snapshot;
+pub mod tx;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,65 @@
+//! Usage: `cargo run -p cf-indexer -- [--contract <address>] <snapshot.json> <txs.json>...`
+
+use std::env::args;
+
+use anyhow::{bail, Result};
+
+use cf_indexer::{
+    events::extract_events,
+    model::State,
+    snapshot::{diff_users, Snapshot, UserDiff},
+    tx::load_txs,
+};
+
+fn main() -> Result<()> {
+    let mut contract_address: Option<String> = None;
+    let mut paths: Vec<String> = vec![];
+    let mut arg_list = args().skip(1);
+
+    while let Some(arg) = arg_list.next() {
+        if arg == "--contract" {
+            contract_address = arg_list.next();
+        } else {
+            paths.push(arg);
+        }
+    }
+
+    if paths.len() < 2 {
+        bail!("Usage: cf-indexer [--contract <address>] <snapshot.json> <txs.json>...");
+    }
+
+    let snapshot = Snapshot::load(&paths[0])?;
+    let tx_list = load_txs(&paths[1..])?;
+    let event_list = extract_events(&tx_list, contract_address.as_deref())?;
+    let state = State::replay(&event_list)?;
+    let diff_list = diff_users(&snapshot, &state.user_list());
+
+    println!("txs: {}, events: {}", tx_list.len(), event_list.len());
+    println!("users: {}", state.users.len());
+    println!("app info: {:#?}", state.app_info);
+
+    if diff_list.is_empty() {
+        println!("the snapshot matches the events");
+        return Ok(());
+    }
+
+    for diff in &diff_list {
+        match diff {
+            UserDiff::MissingInEvents { address } => println!("{}: missing in events", address),
+            UserDiff::MissingInSnapshot { address } => {
+                println!("{}: missing in snapshot", address)
+            }
+            UserDiff::Field {
+                address,
+                field,
+                snapshot,
+                replayed,
+            } => println!(
+                "{}: {} is {} in snapshot, {} in events",
+                address, field, snapshot, replayed
+            ),
+        }
+    }
+
+    bail!("found {} differences", diff_list.len())
+}
@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, ensure, Result};
+use cosmwasm_std::{Addr, Uint128};
+
+use cf_base::platform::{
+    msg::UserListRespItem,
+    types::{AppInfo, Config, Payout, UserInfo},
+};
+
+use crate::events::{AppSnapshot, CoinflipEvent, IndexedEvent, UserSnapshot};
+
+/// In-memory model of the contract state rebuilt from the events
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct State {
+    pub config: Option<Config>,
+    pub is_paused: bool,
+    pub app_info: AppInfo,
+    pub users: BTreeMap<Addr, UserInfo>,
+}
+
+impl State {
+    /// Replays the events from the beginning of contract history
+    pub fn replay(event_list: &[IndexedEvent]) -> Result<Self> {
+        let mut state = Self::default();
+
+        for event in event_list {
+            state.apply(event)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Applies the event using the contract accounting rules and verifies the result
+    /// with the state attached to the event. An error means the events are missing or
+    /// the contract accounting was changed
+    pub fn apply(&mut self, indexed_event: &IndexedEvent) -> Result<()> {
+        let IndexedEvent {
+            height,
+            txhash,
+            event,
+            user_snapshot,
+            app_snapshot,
+            ..
+        } = indexed_event;
+
+        let user_address = match event {
+            CoinflipEvent::Instantiate { config } => {
+                self.config = Some(config.to_owned());
+                None
+            }
+            CoinflipEvent::Flip {
+                user,
+                amount,
+                payout,
+                prize,
+                cashback,
+                insurance_fill,
+                rakeback,
+                round,
+                from_balance,
+                ..
+            } => {
+                // the bet of seeded flip was taken from the user balance when it was placed
+                match round {
+                    Some(_) => self.app_info.remove_pending_bet(*amount),
+                    None if *from_balance => self.app_info.take_balance_bet(*amount),
+                    None => {}
+                }
+
+                self.app_info.add_rakeback(*rakeback);
+                let app_payout = self.app_info.settle_flip(*amount, *prize);
+                ensure!(
+                    &app_payout == payout,
+                    "Payout mismatch at height {}, tx {}: replayed {}, emitted {}",
+                    height,
+                    txhash,
+                    app_payout,
+                    payout
+                );
+
+                let block_time = user_snapshot
+                    .as_ref()
+                    .map(|x| x.last_flip_date)
+                    .unwrap_or_default();
+                let user_info = self.users.entry(user.to_owned()).or_default();
+                user_info.settle_flip(*amount, *prize, payout, block_time);
+
+                if !cashback.is_zero() {
+                    self.app_info.add_cashback(*cashback);
+                    user_info.add_cashback(*cashback);
+                }
+
+                if !insurance_fill.is_zero() {
+                    self.app_info.fill_insurance(*insurance_fill);
+                }
+
+                if *from_balance && payout == &Payout::AutoPaid {
+                    self.app_info.top_up(*prize);
+                }
+
+                Some(user)
+            }
+            CoinflipEvent::PendingFlip {
+                amount,
+                from_balance,
+                ..
+            } => {
+                if *from_balance {
+                    self.app_info.take_balance_bet(*amount);
+                }
+
+                self.app_info.add_pending_bet(*amount);
+                None
+            }
+            CoinflipEvent::RefundFlip {
+                user,
+                amount,
+                penalty,
+                payout,
+                from_balance,
+                ..
+            } => {
+                self.app_info.remove_pending_bet(*amount);
+                let app_payout = self.app_info.pay_reveal_penalty(*penalty);
+                ensure!(
+                    &app_payout == payout,
+                    "Payout mismatch at height {}, tx {}: replayed {}, emitted {}",
+                    height,
+                    txhash,
+                    app_payout,
+                    payout
+                );
+
+                self.users
+                    .entry(user.to_owned())
+                    .or_default()
+                    .add_reveal_penalty(*penalty, payout);
+
+                if *from_balance {
+                    match payout {
+                        Payout::AutoPaid => self.app_info.top_up(*amount + *penalty),
+                        _ => self.app_info.top_up(*amount),
+                    }
+                }
+
+                Some(user)
+            }
+            CoinflipEvent::TopUp { amount, .. } => {
+                self.app_info.top_up(*amount);
+                None
+            }
+            CoinflipEvent::WithdrawBalance { amount, .. } => {
+                self.app_info.withdraw_user_balance(*amount);
+                None
+            }
+            // the fee of deferred request is pending until it's fulfilled or refunded
+            CoinflipEvent::RequestRandomness { fee, round, .. } => {
+                match round {
+                    Some(_) => self.app_info.add_pending_bet(*fee),
+                    None => self.app_info.add_randomness_fee(*fee),
+                }
+
+                None
+            }
+            CoinflipEvent::FulfillRandomness { fee, .. } => {
+                self.app_info.remove_pending_bet(*fee);
+                self.app_info.add_randomness_fee(*fee);
+                None
+            }
+            CoinflipEvent::RefundRandomness { fee, .. } => {
+                self.app_info.remove_pending_bet(*fee);
+                None
+            }
+            CoinflipEvent::NftFlip {
+                user,
+                payout,
+                prize,
+                ..
+            } => {
+                let app_payout = self.app_info.settle_nft_flip(*prize);
+                ensure!(
+                    &app_payout == payout,
+                    "Payout mismatch at height {}, tx {}: replayed {}, emitted {}",
+                    height,
+                    txhash,
+                    app_payout,
+                    payout
+                );
+
+                let block_time = user_snapshot
+                    .as_ref()
+                    .map(|x| x.last_flip_date)
+                    .unwrap_or_default();
+                self.users
+                    .entry(user.to_owned())
+                    .or_default()
+                    .settle_nft_flip(*prize, payout, block_time);
+
+                Some(user)
+            }
+            CoinflipEvent::BonusFlip {
+                user,
+                amount,
+                payout,
+                prize,
+                locked,
+                ..
+            } => {
+                // the stake returns to the bonus balance on win, the event prize is the winnings
+                let full_prize = if prize.is_zero() {
+                    Uint128::zero()
+                } else {
+                    amount + prize
+                };
+                let app_payout = self
+                    .app_info
+                    .settle_bonus_flip(*amount, full_prize, *locked);
+                ensure!(
+                    &app_payout == payout,
+                    "Payout mismatch at height {}, tx {}: replayed {}, emitted {}",
+                    height,
+                    txhash,
+                    app_payout,
+                    payout
+                );
+
+                let block_time = user_snapshot
+                    .as_ref()
+                    .map(|x| x.last_flip_date)
+                    .unwrap_or_default();
+                self.users
+                    .entry(user.to_owned())
+                    .or_default()
+                    .settle_bonus_flip(*prize, payout, block_time);
+
+                Some(user)
+            }
+            CoinflipEvent::RedeemPromoCode { amount, .. }
+            | CoinflipEvent::CreditBonus { amount, .. } => {
+                self.app_info.credit_bonus(*amount);
+                None
+            }
+            CoinflipEvent::Claim { user, amount } => {
+                let user_info = self.users.entry(user.to_owned()).or_default();
+                ensure!(
+                    user_info.unclaimed == *amount,
+                    "Claim mismatch at height {}, tx {}: replayed {}, emitted {}",
+                    height,
+                    txhash,
+                    user_info.unclaimed,
+                    amount
+                );
+
+                self.app_info.claim(*amount);
+                user_info.unclaimed = Default::default();
+
+                Some(user)
+            }
+            CoinflipEvent::ReturnIbcPayout { user, amount, .. } => {
+                self.app_info.return_payout(*amount);
+                self.users.entry(user.to_owned()).or_default().unclaimed += amount;
+
+                Some(user)
+            }
+            CoinflipEvent::ClaimRakeback {
+                amount, rakeback, ..
+            } => {
+                self.app_info.add_rakeback(*rakeback);
+                self.app_info.claim_rakeback(*amount);
+                None
+            }
+            CoinflipEvent::Deposit {
+                amount, payouts, ..
+            } => {
+                self.app_info.deposit(*amount);
+                self.pay_queue(payouts, *height, txhash)?;
+                None
+            }
+            CoinflipEvent::ProcessPayouts { payouts, .. } => {
+                self.pay_queue(payouts, *height, txhash)?;
+                None
+            }
+            CoinflipEvent::Withdraw { amount, .. } => {
+                self.app_info.withdraw(*amount);
+                None
+            }
+            CoinflipEvent::DepositInsurance { amount, .. } => {
+                self.app_info.deposit_insurance(*amount);
+                None
+            }
+            CoinflipEvent::WithdrawInsurance { amount, .. } => {
+                self.app_info.withdraw_insurance(*amount);
+                None
+            }
+            CoinflipEvent::DistributeRevenue { amount, .. } => {
+                self.app_info.distribute_revenue(*amount);
+                None
+            }
+            CoinflipEvent::Buyback { amount_in, .. } => {
+                self.app_info.distribute_revenue(*amount_in);
+                None
+            }
+            CoinflipEvent::AcceptAdminRole { admin } => {
+                match self.config.as_mut() {
+                    Some(config) => config.admin = admin.to_owned(),
+                    None => bail!("Config isn't initialized at height {}", height),
+                }
+
+                None
+            }
+            CoinflipEvent::UpdateConfig { config, .. } => {
+                self.config = Some(config.to_owned());
+                None
+            }
+            CoinflipEvent::UpdateTiers { .. }
+            | CoinflipEvent::SetUserTier { .. }
+            | CoinflipEvent::UpdateRakeback { .. }
+            | CoinflipEvent::UpdateRevenueSplit { .. }
+            | CoinflipEvent::UpdateBuyback { .. }
+            | CoinflipEvent::UpdateOracle { .. }
+            | CoinflipEvent::UpdatePromoCode { .. }
+            | CoinflipEvent::UpdateInsurance { .. }
+            | CoinflipEvent::Burn { .. }
+            | CoinflipEvent::FlipCallbackFailed { .. }
+            | CoinflipEvent::RandomnessCallbackFailed { .. }
+            | CoinflipEvent::UpdateRandomnessFee { .. }
+            | CoinflipEvent::GrantSession { .. }
+            | CoinflipEvent::RevokeSession { .. }
+            | CoinflipEvent::CommitSeedChain { .. }
+            | CoinflipEvent::RevealSeed { .. }
+            | CoinflipEvent::EnqueuePayouts { .. }
+            | CoinflipEvent::UpdateContractCallers { .. }
+            | CoinflipEvent::UpdateDeferredOnly { .. }
+            | CoinflipEvent::UpdateNftPerk { .. }
+            | CoinflipEvent::UpdateNftFloorPrice { .. }
+            | CoinflipEvent::WithdrawNft { .. } => None,
+            CoinflipEvent::Pause { .. } => {
+                self.is_paused = true;
+                None
+            }
+            CoinflipEvent::Unpause { .. } => {
+                self.is_paused = false;
+                None
+            }
+        };
+
+        if let (Some(user), Some(user_snapshot)) = (user_address, user_snapshot) {
+            self.verify_user(user, user_snapshot, *height, txhash)?;
+        }
+
+        if let Some(app_snapshot) = app_snapshot {
+            self.verify_app(app_snapshot, *height, txhash)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns users in `QueryMsg::UserList` format
+    pub fn user_list(&self) -> Vec<UserListRespItem> {
+        self.users
+            .iter()
+            .map(|(address, info)| UserListRespItem {
+                address: address.to_owned(),
+                info: info.to_owned(),
+            })
+            .collect()
+    }
+
+    /// Payouts from the queue are replayed as claims
+    fn pay_queue(&mut self, payouts: &[(Addr, Uint128)], height: u64, txhash: &str) -> Result<()> {
+        for (user, amount) in payouts {
+            let user_info = self.users.entry(user.to_owned()).or_default();
+            ensure!(
+                user_info.unclaimed == *amount,
+                "Payout mismatch at height {}, tx {}: replayed {}, emitted {}",
+                height,
+                txhash,
+                user_info.unclaimed,
+                amount
+            );
+
+            self.app_info.claim(*amount);
+            user_info.unclaimed = Default::default();
+        }
+
+        Ok(())
+    }
+
+    fn verify_user(
+        &self,
+        user: &Addr,
+        user_snapshot: &UserSnapshot,
+        height: u64,
+        txhash: &str,
+    ) -> Result<()> {
+        let user_info = self.users.get(user).cloned().unwrap_or_default();
+        let replayed = UserSnapshot {
+            unclaimed: user_info.unclaimed,
+            last_flip_date: user_info.last_flip_date,
+        };
+
+        ensure!(
+            &replayed == user_snapshot,
+            "User {} state mismatch at height {}, tx {}: replayed {:?}, emitted {:?}",
+            user,
+            height,
+            txhash,
+            replayed,
+            user_snapshot
+        );
+
+        Ok(())
+    }
+
+    fn verify_app(&self, app_snapshot: &AppSnapshot, height: u64, txhash: &str) -> Result<()> {
+        let replayed = AppSnapshot {
+            balance: self.app_info.balance,
+            deposited: self.app_info.deposited,
+            revenue_total: self.app_info.revenue.total,
+            revenue_current: self.app_info.revenue.current,
+            user_unclaimed: self.app_info.user_unclaimed,
+            insurance: self.app_info.insurance,
+            pending_bets: self.app_info.pending_bets,
+            user_balances: self.app_info.user_balances,
+            bonus_balances: self.app_info.bonus_balances,
+        };
+
+        ensure!(
+            &replayed == app_snapshot,
+            "App state mismatch at height {}, tx {}: replayed {:?}, emitted {:?}",
+            height,
+            txhash,
+            replayed,
+            app_snapshot
+        );
+
+        Ok(())
+    }
+}
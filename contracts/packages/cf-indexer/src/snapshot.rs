@@ -0,0 +1,268 @@
+use std::{collections::BTreeMap, fs::read_to_string, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use cf_base::platform::msg::UserListRespItem;
+
+const MICRO_UNITS: f64 = 1e6;
+const EPS: f64 = 1e-9;
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CapturedStatsItem {
+    pub count: u32,
+    pub value: f64,
+}
+
+/// `capture-users` script output item. Amounts are in whole units floored to 1 decimal,
+/// ROI is floored to 3 decimals, date format is `DD.MM.YYYY HH:MM:SS` (UTC)
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CapturedUser {
+    pub address: String,
+    pub bets: CapturedStatsItem,
+    pub wins: CapturedStatsItem,
+    pub roi: f64,
+    pub unclaimed: f64,
+    pub last_flip_date: String,
+}
+
+impl From<&UserListRespItem> for CapturedUser {
+    fn from(item: &UserListRespItem) -> Self {
+        let UserListRespItem { address, info } = item;
+
+        Self {
+            address: address.to_string(),
+            bets: CapturedStatsItem {
+                count: info.stats.bets.count,
+                value: to_units(info.stats.bets.value.u128()),
+            },
+            wins: CapturedStatsItem {
+                count: info.stats.wins.count,
+                value: to_units(info.stats.wins.value.u128()),
+            },
+            roi: floor(info.roi.to_string().parse().unwrap_or_default(), 3),
+            unclaimed: to_units(info.unclaimed.u128()),
+            last_flip_date: epoch_to_date_string_utc(info.last_flip_date),
+        }
+    }
+}
+
+/// Supported snapshot formats: raw `QueryMsg::UserList` response and `capture-users` script output
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Snapshot {
+    UserList(Vec<UserListRespItem>),
+    Captured(Vec<CapturedUser>),
+}
+
+impl Snapshot {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("Unknown snapshot format")
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = read_to_string(path).with_context(|| format!("Can't read {:?}", path))?;
+
+        Self::parse(&json).with_context(|| format!("Can't parse {:?}", path))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserDiff {
+    /// the user is in the snapshot but wasn't found in the events
+    MissingInEvents { address: String },
+    /// the user was found in the events but isn't in the snapshot
+    MissingInSnapshot { address: String },
+    Field {
+        address: String,
+        field: String,
+        snapshot: String,
+        replayed: String,
+    },
+}
+
+/// Compares replayed users with the snapshot. The replayed users are rounded
+/// the same way as the snapshot if it was captured by the script
+pub fn diff_users(snapshot: &Snapshot, replayed: &[UserListRespItem]) -> Vec<UserDiff> {
+    match snapshot {
+        Snapshot::UserList(user_list) => {
+            let snapshot_users: BTreeMap<String, CapturedUser> = user_list
+                .iter()
+                .map(|x| (x.address.to_string(), CapturedUser::from(x)))
+                .collect();
+
+            // compare raw values first, rounded ones can hide the difference
+            let mut diff_list = diff_captured(
+                &snapshot_users,
+                &replayed.iter().map(CapturedUser::from).collect::<Vec<_>>(),
+            );
+
+            for item in user_list {
+                if let Some(x) = replayed.iter().find(|x| x.address == item.address) {
+                    if x.info != item.info
+                        && !diff_list.iter().any(|y| is_diff_of(y, x.address.as_str()))
+                    {
+                        diff_list.push(UserDiff::Field {
+                            address: x.address.to_string(),
+                            field: "info".to_string(),
+                            snapshot: format!("{:?}", item.info),
+                            replayed: format!("{:?}", x.info),
+                        });
+                    }
+                }
+            }
+
+            diff_list
+        }
+        Snapshot::Captured(user_list) => diff_captured(
+            &user_list
+                .iter()
+                .map(|x| (x.address.to_owned(), x.to_owned()))
+                .collect(),
+            &replayed.iter().map(CapturedUser::from).collect::<Vec<_>>(),
+        ),
+    }
+}
+
+fn diff_captured(
+    snapshot_users: &BTreeMap<String, CapturedUser>,
+    replayed: &[CapturedUser],
+) -> Vec<UserDiff> {
+    let mut diff_list: Vec<UserDiff> = vec![];
+    let replayed_users: BTreeMap<&str, &CapturedUser> =
+        replayed.iter().map(|x| (x.address.as_str(), x)).collect();
+
+    for (address, snapshot_user) in snapshot_users {
+        let Some(replayed_user) = replayed_users.get(address.as_str()) else {
+            diff_list.push(UserDiff::MissingInEvents {
+                address: address.to_owned(),
+            });
+            continue;
+        };
+
+        let fields: [(&str, String, String, bool); 7] = [
+            (
+                "bets.count",
+                snapshot_user.bets.count.to_string(),
+                replayed_user.bets.count.to_string(),
+                snapshot_user.bets.count == replayed_user.bets.count,
+            ),
+            (
+                "bets.value",
+                snapshot_user.bets.value.to_string(),
+                replayed_user.bets.value.to_string(),
+                is_equal(snapshot_user.bets.value, replayed_user.bets.value),
+            ),
+            (
+                "wins.count",
+                snapshot_user.wins.count.to_string(),
+                replayed_user.wins.count.to_string(),
+                snapshot_user.wins.count == replayed_user.wins.count,
+            ),
+            (
+                "wins.value",
+                snapshot_user.wins.value.to_string(),
+                replayed_user.wins.value.to_string(),
+                is_equal(snapshot_user.wins.value, replayed_user.wins.value),
+            ),
+            (
+                "roi",
+                snapshot_user.roi.to_string(),
+                replayed_user.roi.to_string(),
+                is_equal(snapshot_user.roi, replayed_user.roi),
+            ),
+            (
+                "unclaimed",
+                snapshot_user.unclaimed.to_string(),
+                replayed_user.unclaimed.to_string(),
+                is_equal(snapshot_user.unclaimed, replayed_user.unclaimed),
+            ),
+            (
+                "lastFlipDate",
+                snapshot_user.last_flip_date.to_owned(),
+                replayed_user.last_flip_date.to_owned(),
+                snapshot_user.last_flip_date == replayed_user.last_flip_date,
+            ),
+        ];
+
+        for (field, snapshot, replayed, is_matched) in fields {
+            if !is_matched {
+                diff_list.push(UserDiff::Field {
+                    address: address.to_owned(),
+                    field: field.to_string(),
+                    snapshot,
+                    replayed,
+                });
+            }
+        }
+    }
+
+    for address in replayed_users.keys() {
+        if !snapshot_users.contains_key(*address) {
+            diff_list.push(UserDiff::MissingInSnapshot {
+                address: address.to_string(),
+            });
+        }
+    }
+
+    diff_list
+}
+
+fn is_diff_of(diff: &UserDiff, address: &str) -> bool {
+    match diff {
+        UserDiff::MissingInEvents { address: x } => x == address,
+        UserDiff::MissingInSnapshot { address: x } => x == address,
+        UserDiff::Field { address: x, .. } => x == address,
+    }
+}
+
+fn is_equal(a: f64, b: f64) -> bool {
+    (a - b).abs() < EPS
+}
+
+/// Same as `floor` in scripts/src/common/utils
+fn floor(num: f64, digits: i32) -> f64 {
+    let k = 10_f64.powi(digits);
+    (k * num).floor() / k
+}
+
+fn to_units(amount: u128) -> f64 {
+    floor(amount as f64 / MICRO_UNITS, 1)
+}
+
+/// Same as `epochToDateStringUTC` in scripts/src/backend/services/utils
+pub fn epoch_to_date_string_utc(timestamp: u64) -> String {
+    const SECONDS_PER_DAY: u64 = 86_400;
+
+    let days = (timestamp / SECONDS_PER_DAY) as i64;
+    let seconds = timestamp % SECONDS_PER_DAY;
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{:02}.{:02}.{} {:02}:{:02}:{:02}",
+        day,
+        month,
+        year,
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60
+    )
+}
+
+/// Converts days since 1970-01-01 to (year, month, day), see
+/// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
@@ -0,0 +1,209 @@
+use cosmwasm_std::{Addr, Int256, Uint128};
+use speculoos::prelude::*;
+
+use cf_base::{converters::str_to_dec, platform::types::Payout};
+
+use crate::{
+    events::{extract_events, CoinflipEvent, IndexedEvent},
+    model::State,
+    snapshot::{diff_users, epoch_to_date_string_utc, Snapshot, UserDiff},
+    tx::{load_txs, parse_txs},
+};
+
+const CONTRACT: &str = "mantra1v0ah2wvd6ywqzukpnqe3l9hzsx3wwdfprg2xmfdaxhjl4sqjkvtqjx6z6h";
+const ALICE: &str = "mantra1j5ft99lyd36e5fyp8kh8ze7qcj00relmvt5prd";
+const BOB: &str = "mantra1hvp3q00ypzrurd46h7c7c3hu86tx9uf80lfxn6";
+
+const TXS_1: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/txs_1.json");
+const TXS_2: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/txs_2.json");
+const USERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/users.json");
+
+fn load_events() -> Vec<IndexedEvent> {
+    let tx_list = load_txs(&[TXS_2, TXS_1]).unwrap();
+    extract_events(&tx_list, Some(CONTRACT)).unwrap()
+}
+
+#[test]
+fn tx_dump_formats() {
+    let search = parse_txs(&std::fs::read_to_string(TXS_1).unwrap()).unwrap();
+    let list = parse_txs(&std::fs::read_to_string(TXS_2).unwrap()).unwrap();
+
+    assert_that(&search.len()).is_equal_to(8);
+    assert_that(&list.len()).is_equal_to(10);
+    assert_that(&search[6]).is_equal_to(&list[0]);
+
+    let single = format!(
+        r#"{{"tx_response": {}}}"#,
+        serde_json::to_string(&serde_json::json!({ "height": 5, "txhash": "AB" })).unwrap()
+    );
+    assert_that(&parse_txs(&single).unwrap()[0].height).is_equal_to(5);
+    assert_that(&parse_txs("{}").is_err()).is_true();
+}
+
+#[test]
+fn overlapping_dumps_are_merged() {
+    let tx_list = load_txs(&[TXS_2, TXS_1]).unwrap();
+    let heights: Vec<u64> = tx_list.iter().map(|x| x.height).collect();
+
+    assert_that(&heights).is_equal_to((100..=115).collect::<Vec<u64>>());
+}
+
+#[test]
+fn failed_and_foreign_txs_are_skipped() {
+    let tx_list = load_txs(&[TXS_1, TXS_2]).unwrap();
+
+    // failed tx at height 105
+    let all_events = extract_events(&tx_list, None).unwrap();
+    assert_that(&all_events.len()).is_equal_to(15);
+    assert_that(&all_events.iter().any(|x| x.height == 105)).is_false();
+
+    // other contract at height 107
+    let events = load_events();
+    assert_that(&events.len()).is_equal_to(14);
+    assert_that(&events.iter().any(|x| x.height == 107)).is_false();
+
+    assert_that(&events[4].event).is_equal_to(CoinflipEvent::Flip {
+        user: Addr::unchecked(BOB),
+        side: "tail".parse().unwrap(),
+        amount: Uint128::new(2_500_000),
+        random_weight: str_to_dec("0.912847712390177631"),
+        payout: Payout::Unclaimed,
+        prize: Uint128::new(5_000_000),
+        cashback: Uint128::zero(),
+        insurance_fill: Uint128::zero(),
+        rakeback: Uint128::zero(),
+        nft: None,
+        flip_id: None,
+        round: None,
+        callback: None,
+        from_balance: false,
+        session: None,
+        ibc_target: None,
+    });
+}
+
+#[test]
+fn replay_fixtures() {
+    let state = State::replay(&load_events()).unwrap();
+
+    let config = state.config.clone().unwrap();
+    assert_that(&config.platform_fee).is_equal_to(str_to_dec("0.08"));
+    assert_that(&state.is_paused).is_false();
+
+    assert_that(&state.app_info.balance.u128()).is_equal_to(3_000_000);
+    assert_that(&state.app_info.deposited.u128()).is_equal_to(2_500_000);
+    assert_that(&state.app_info.revenue.total).is_equal_to(Int256::from(500_000));
+    assert_that(&state.app_info.revenue.current).is_equal_to(Int256::from(500_000));
+    assert_that(&state.app_info.user_unclaimed.u128()).is_equal_to(0);
+    assert_that(&state.app_info.user_stats.bets.count).is_equal_to(6);
+    assert_that(&state.app_info.user_stats.wins.count).is_equal_to(3);
+
+    let alice = &state.users[&Addr::unchecked(ALICE)];
+    assert_that(&alice.stats.bets.value.u128()).is_equal_to(11_000_000);
+    assert_that(&alice.stats.wins.value.u128()).is_equal_to(10_000_000);
+    assert_that(&alice.roi.to_string().as_str()).is_equal_to("-0.09090909090909091");
+
+    let bob = &state.users[&Addr::unchecked(BOB)];
+    assert_that(&bob.unclaimed.u128()).is_equal_to(0);
+    assert_that(&bob.stats.wins.count).is_equal_to(1);
+}
+
+#[test]
+fn diff_with_captured_snapshot() {
+    let state = State::replay(&load_events()).unwrap();
+    let snapshot = Snapshot::load(USERS).unwrap();
+
+    assert_that(&matches!(snapshot, Snapshot::Captured(_))).is_true();
+    assert_that(&diff_users(&snapshot, &state.user_list())).is_equal_to(vec![]);
+
+    // the snapshot was captured earlier
+    let state = State::replay(&load_events()[..12]).unwrap();
+    assert_that(&diff_users(&snapshot, &state.user_list())).is_equal_to(vec![
+        UserDiff::Field {
+            address: ALICE.to_string(),
+            field: "bets.count".to_string(),
+            snapshot: "4".to_string(),
+            replayed: "3".to_string(),
+        },
+        UserDiff::Field {
+            address: ALICE.to_string(),
+            field: "bets.value".to_string(),
+            snapshot: "11".to_string(),
+            replayed: "7".to_string(),
+        },
+        UserDiff::Field {
+            address: ALICE.to_string(),
+            field: "wins.count".to_string(),
+            snapshot: "2".to_string(),
+            replayed: "1".to_string(),
+        },
+        UserDiff::Field {
+            address: ALICE.to_string(),
+            field: "wins.value".to_string(),
+            snapshot: "10".to_string(),
+            replayed: "2".to_string(),
+        },
+        UserDiff::Field {
+            address: ALICE.to_string(),
+            field: "roi".to_string(),
+            snapshot: "-0.091".to_string(),
+            replayed: "-0.715".to_string(),
+        },
+        UserDiff::Field {
+            address: ALICE.to_string(),
+            field: "lastFlipDate".to_string(),
+            snapshot: "27.01.2025 17:41:24".to_string(),
+            replayed: "27.01.2025 17:40:36".to_string(),
+        },
+    ]);
+
+    let state = State::replay(&load_events()[..3]).unwrap();
+    assert_that(&diff_users(&snapshot, &state.user_list())).contains(UserDiff::MissingInEvents {
+        address: BOB.to_string(),
+    });
+}
+
+#[test]
+fn diff_with_user_list_snapshot() {
+    let state = State::replay(&load_events()).unwrap();
+    let mut user_list = state.user_list();
+    let snapshot = Snapshot::parse(&serde_json::to_string(&user_list).unwrap()).unwrap();
+
+    assert_that(&matches!(snapshot, Snapshot::UserList(_))).is_true();
+    assert_that(&diff_users(&snapshot, &user_list)).is_equal_to(vec![]);
+
+    // the difference is hidden by rounding
+    user_list[0].info.stats.bets.value += Uint128::new(1);
+    assert_that(&diff_users(&snapshot, &user_list)).has_length(1);
+
+    user_list.pop();
+    assert_that(&diff_users(&snapshot, &user_list)).contains(UserDiff::MissingInEvents {
+        address: ALICE.to_string(),
+    });
+}
+
+#[test]
+fn missing_events_are_detected() {
+    let mut events = load_events();
+
+    // deposit
+    events.remove(1);
+    let err = State::replay(&events).unwrap_err();
+    assert_that(&err.to_string()).contains("App state mismatch at height 102");
+
+    // claim
+    let mut events = load_events();
+    if let CoinflipEvent::Claim { amount, .. } = &mut events[8].event {
+        *amount -= Uint128::new(1);
+    }
+    let err = State::replay(&events).unwrap_err();
+    assert_that(&err.to_string()).contains("Claim mismatch at height 110");
+}
+
+#[test]
+fn dates() {
+    assert_that(&epoch_to_date_string_utc(0).as_str()).is_equal_to("01.01.1970 00:00:00");
+    assert_that(&epoch_to_date_string_utc(1_737_999_612).as_str())
+        .is_equal_to("27.01.2025 17:40:12");
+    assert_that(&epoch_to_date_string_utc(951_825_599).as_str()).is_equal_to("29.02.2000 11:59:59");
+}
@@ -0,0 +1,122 @@
+use std::{collections::HashSet, fs::read_to_string, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer};
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TxAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TxEvent {
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(default)]
+    pub attributes: Vec<TxAttribute>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TxLog {
+    #[serde(default)]
+    pub events: Vec<TxEvent>,
+}
+
+/// Subset of cosmos-sdk TxResponse required to replay the events. \
+/// Event attributes are expected as plain strings (cosmos-sdk v0.50+)
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TxResponse {
+    #[serde(deserialize_with = "deserialize_height")]
+    pub height: u64,
+    #[serde(default)]
+    pub txhash: String,
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub timestamp: String,
+    #[serde(default)]
+    pub events: Vec<TxEvent>,
+    /// legacy location of the events
+    #[serde(default)]
+    pub logs: Vec<TxLog>,
+}
+
+impl TxResponse {
+    pub fn is_successful(&self) -> bool {
+        self.code == 0
+    }
+
+    /// Returns tx events falling back to legacy logs if needed
+    pub fn get_events(&self) -> Vec<&TxEvent> {
+        if !self.events.is_empty() {
+            return self.events.iter().collect();
+        }
+
+        self.logs.iter().flat_map(|x| x.events.iter()).collect()
+    }
+}
+
+/// Supported tx dump formats: `GetTxsEvent` response, `GetTx` response,
+/// list of tx responses and single tx response
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TxDump {
+    Search { tx_responses: Vec<TxResponse> },
+    Single { tx_response: TxResponse },
+    List(Vec<TxResponse>),
+    Raw(TxResponse),
+}
+
+impl From<TxDump> for Vec<TxResponse> {
+    fn from(tx_dump: TxDump) -> Self {
+        match tx_dump {
+            TxDump::Search { tx_responses } => tx_responses,
+            TxDump::Single { tx_response } => vec![tx_response],
+            TxDump::List(x) => x,
+            TxDump::Raw(x) => vec![x],
+        }
+    }
+}
+
+pub fn parse_txs(json: &str) -> Result<Vec<TxResponse>> {
+    let tx_dump: TxDump = serde_json::from_str(json).context("Unknown tx dump format")?;
+
+    Ok(tx_dump.into())
+}
+
+/// Reads all dumps and returns txs ordered by height. Txs of the same height keep the file order
+pub fn load_txs(paths: &[impl AsRef<Path>]) -> Result<Vec<TxResponse>> {
+    let mut tx_list: Vec<TxResponse> = vec![];
+
+    for path in paths {
+        let path = path.as_ref();
+        let json = read_to_string(path).with_context(|| format!("Can't read {:?}", path))?;
+        tx_list.extend(parse_txs(&json).with_context(|| format!("Can't parse {:?}", path))?);
+    }
+
+    // dumps can overlap
+    let mut hashes: HashSet<String> = HashSet::new();
+    tx_list.retain(|x| x.txhash.is_empty() || hashes.insert(x.txhash.to_owned()));
+    tx_list.sort_by_key(|x| x.height);
+
+    Ok(tx_list)
+}
+
+/// LCD returns height as string while RPC based tools return it as number
+fn deserialize_height<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Height {
+        Str(String),
+        Num(u64),
+    }
+
+    match Height::deserialize(deserializer)? {
+        Height::Str(x) => x.parse().map_err(serde::de::Error::custom),
+        Height::Num(x) => Ok(x),
+    }
+}
@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error, Result};
+use cosmwasm_std::{Decimal, Uint128};
+use rand::Rng;
+
+use cf_base::{
+    converters::str_to_dec,
+    platform::{
+        state::{BET_MAX, BET_MIN, PLATFORM_FEE},
+        types::Range,
+    },
+};
+
+/// How users choose the bet amount. Sampled bets are clamped to the bet range
+#[derive(Debug, Clone, PartialEq)]
+pub enum BetDistribution {
+    /// the same bet for each flip
+    Fixed(Uint128),
+    /// uniform on the bet range
+    Uniform,
+    /// exponential with the specified mean, small bets are more frequent
+    Exponential { mean: Uint128 },
+}
+
+impl BetDistribution {
+    pub fn sample(&self, rng: &mut impl Rng, bet: &Range) -> Uint128 {
+        let amount = match self {
+            Self::Fixed(amount) => *amount,
+            Self::Uniform => Uint128::new(rng.gen_range(bet.min.u128()..=bet.max.u128())),
+            Self::Exponential { mean } => {
+                let u: f64 = rng.gen_range(f64::EPSILON..1.0);
+                Uint128::new((-u.ln() * mean.u128() as f64) as u128)
+            }
+        };
+
+        amount.clamp(bet.min, bet.max)
+    }
+}
+
+/// Format: `fixed:<amount>`, `uniform`, `exp:<mean>`
+impl FromStr for BetDistribution {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, value) = s.split_once(':').unwrap_or((s, ""));
+        let parse_amount = || -> Result<Uint128> {
+            value
+                .parse::<u128>()
+                .map(Uint128::new)
+                .map_err(|_| anyhow!("Wrong bet distribution amount {}", value))
+        };
+
+        match name {
+            "fixed" => Ok(Self::Fixed(parse_amount()?)),
+            "uniform" => Ok(Self::Uniform),
+            "exp" => Ok(Self::Exponential {
+                mean: parse_amount()?,
+            }),
+            _ => bail!("Unknown bet distribution {}", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationConfig {
+    /// number of independent bankroll paths
+    pub runs: u32,
+    /// max flips per run, the run is stopped earlier on ruin
+    pub flips: u64,
+    /// unique users, each flip is made by a random one
+    pub users: u32,
+    /// initial deposit
+    pub bankroll: Uint128,
+    pub platform_fee: Decimal,
+    pub bet: Range,
+    pub bet_distribution: BetDistribution,
+    pub seed: u64,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            runs: 100,
+            flips: 10_000,
+            users: 100,
+            bankroll: Uint128::new(100_000_000),
+            platform_fee: str_to_dec(PLATFORM_FEE),
+            bet: Range::new(BET_MIN, BET_MAX),
+            bet_distribution: BetDistribution::Uniform,
+            seed: 0,
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Parses `--key value` pairs, missing keys are taken from default config
+    pub fn parse_args(arg_list: &[String]) -> Result<Self> {
+        let mut config = Self::default();
+        let mut iter = arg_list.iter();
+
+        while let Some(key) = iter.next() {
+            let value = iter
+                .next()
+                .ok_or(anyhow!("Value of {} is not specified", key))?;
+            let wrong_value = || anyhow!("Wrong value of {}: {}", key, value);
+
+            match key.as_str() {
+                "--runs" => config.runs = value.parse().map_err(|_| wrong_value())?,
+                "--flips" => config.flips = value.parse().map_err(|_| wrong_value())?,
+                "--users" => config.users = value.parse().map_err(|_| wrong_value())?,
+                "--bankroll" => config.bankroll = value.parse().map_err(|_| wrong_value())?,
+                "--fee" => config.platform_fee = value.parse().map_err(|_| wrong_value())?,
+                "--bet-min" => config.bet.min = value.parse().map_err(|_| wrong_value())?,
+                "--bet-max" => config.bet.max = value.parse().map_err(|_| wrong_value())?,
+                "--bets" => config.bet_distribution = value.parse()?,
+                "--seed" => config.seed = value.parse().map_err(|_| wrong_value())?,
+                _ => bail!("Unknown argument {}", key),
+            }
+        }
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.runs == 0 || self.flips == 0 || self.users == 0 {
+            bail!("Runs, flips and users must be positive");
+        }
+
+        if self.bet.min.is_zero() || self.bet.min > self.bet.max {
+            bail!("Wrong bet range {}..{}", self.bet.min, self.bet.max);
+        }
+
+        if self.platform_fee >= Decimal::one() {
+            bail!("Platform fee must be less than 1");
+        }
+
+        Ok(())
+    }
+}
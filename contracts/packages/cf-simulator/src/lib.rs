@@ -0,0 +1,6 @@
+pub mod config;
+pub mod report;
+pub mod simulation;
+
+#[cfg(test)]
+pub mod tests;
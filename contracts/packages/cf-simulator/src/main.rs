@@ -0,0 +1,18 @@
+//! Usage: `cargo run --release -p cf-simulator -- [--runs 100] [--flips 10000] [--users 100]
+//! [--bankroll 100000000] [--fee 0.1] [--bet-min 1000000] [--bet-max 20000000]
+//! [--bets uniform|fixed:<amount>|exp:<mean>] [--seed 0]`
+
+use std::env::args;
+
+use anyhow::Result;
+
+use cf_simulator::{config::SimulationConfig, simulation::Simulation};
+
+fn main() -> Result<()> {
+    let config = SimulationConfig::parse_args(&args().skip(1).collect::<Vec<_>>())?;
+
+    println!("{:#?}", config);
+    println!("{}", Simulation::new(config).run_all());
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use cosmwasm_std::{Int128, Int256};
+
+use crate::{config::SimulationConfig, simulation::RunResult};
+
+pub const DRAWDOWN_PERCENTILES: [u8; 5] = [50, 90, 95, 99, 100];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub runs: u32,
+    pub flips: u64,
+    pub wins: u64,
+    /// revenue / bets, the house edge expected by the contract is `platform_fee`
+    pub house_edge: f64,
+    pub expected_house_edge: f64,
+    /// share of runs where the house equity wasn't positive
+    pub ruin_probability: f64,
+    /// share of wins added to unclaimed because of liquidity deficiency
+    pub unclaimed_rate: f64,
+    /// share of runs with at least 1 unclaimed win
+    pub runs_with_unclaimed: f64,
+    /// (percentile, max drawdown in bankroll fractions)
+    pub drawdowns: Vec<(u8, f64)>,
+}
+
+impl Report {
+    pub fn new(config: &SimulationConfig, run_list: &[RunResult]) -> Self {
+        let runs = run_list.len() as f64;
+        let flips: u64 = run_list.iter().map(|x| x.flips).sum();
+        let wins: u64 = run_list
+            .iter()
+            .map(|x| x.app_info.user_stats.wins.count as u64)
+            .sum();
+        let unclaimed_wins: u64 = run_list.iter().map(|x| x.unclaimed_wins).sum();
+        let bets_value: f64 = run_list
+            .iter()
+            .map(|x| x.app_info.user_stats.bets.value.u128() as f64)
+            .sum();
+        let revenue: f64 = run_list
+            .iter()
+            .map(|x| to_f64(x.app_info.revenue.total))
+            .sum();
+
+        let mut drawdown_list: Vec<f64> = run_list.iter().map(|x| x.max_drawdown).collect();
+        drawdown_list.sort_by(|a, b| a.total_cmp(b));
+
+        Self {
+            runs: run_list.len() as u32,
+            flips,
+            wins,
+            house_edge: ratio(revenue, bets_value),
+            expected_house_edge: config.platform_fee.to_string().parse().unwrap_or_default(),
+            ruin_probability: ratio(run_list.iter().filter(|x| x.is_ruined).count() as f64, runs),
+            unclaimed_rate: ratio(unclaimed_wins as f64, wins as f64),
+            runs_with_unclaimed: ratio(
+                run_list.iter().filter(|x| x.unclaimed_wins != 0).count() as f64,
+                runs,
+            ),
+            drawdowns: DRAWDOWN_PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&drawdown_list, p)))
+                .collect(),
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "runs: {}, flips: {}, wins: {}",
+            self.runs, self.flips, self.wins
+        )?;
+        writeln!(
+            f,
+            "house edge: {:.4}% (expected {:.4}%)",
+            100.0 * self.house_edge,
+            100.0 * self.expected_house_edge
+        )?;
+        writeln!(f, "ruin probability: {:.2}%", 100.0 * self.ruin_probability)?;
+        writeln!(
+            f,
+            "unclaimed wins: {:.4}%, runs with unclaimed: {:.2}%",
+            100.0 * self.unclaimed_rate,
+            100.0 * self.runs_with_unclaimed
+        )?;
+        write!(f, "max drawdown (bankroll fraction):")?;
+
+        for (p, drawdown) in &self.drawdowns {
+            write!(f, " p{}={:.4}", p, drawdown)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted_list: &[f64], p: u8) -> f64 {
+    if sorted_list.is_empty() {
+        return 0.0;
+    }
+
+    let rank = (p as f64 / 100.0 * sorted_list.len() as f64).ceil() as usize;
+    sorted_list[rank.clamp(1, sorted_list.len()) - 1]
+}
+
+fn ratio(a: f64, b: f64) -> f64 {
+    if b == 0.0 {
+        0.0
+    } else {
+        a / b
+    }
+}
+
+fn to_f64(value: Int256) -> f64 {
+    Int128::try_from(value)
+        .map(|x| x.i128() as f64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,129 @@
+use std::collections::VecDeque;
+
+use cosmwasm_std::{Decimal, Int128, Int256, Uint128};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use cf_base::platform::types::{AppInfo, Payout, Side, UserInfo};
+
+use crate::{config::SimulationConfig, report::Report};
+
+/// Contract uses normalized argon2 hash as random weight, it's modeled as uniform
+/// decimal on [0, 1) with 18 digits precision
+const WEIGHT_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunResult {
+    pub flips: u64,
+    pub app_info: AppInfo,
+    pub unclaimed_wins: u64,
+    pub is_ruined: bool,
+    /// max equity drop from its peak in bankroll fractions
+    pub max_drawdown: f64,
+}
+
+pub struct Simulation {
+    config: SimulationConfig,
+    rng: StdRng,
+}
+
+impl Simulation {
+    pub fn new(config: SimulationConfig) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(config.seed),
+            config,
+        }
+    }
+
+    pub fn run_all(&mut self) -> Report {
+        let run_list: Vec<RunResult> = (0..self.config.runs).map(|_| self.run()).collect();
+
+        Report::new(&self.config, &run_list)
+    }
+
+    /// Simulates a single bankroll path. Users claim their unclaimed prizes in FIFO order
+    /// as soon as the balance allows it. The run is stopped on ruin - when the house
+    /// equity (deposited + current revenue) isn't positive
+    pub fn run(&mut self) -> RunResult {
+        let SimulationConfig {
+            flips,
+            users,
+            bankroll,
+            platform_fee,
+            bet,
+            bet_distribution,
+            ..
+        } = &self.config;
+
+        let mut res = RunResult::default();
+        let mut user_list: Vec<UserInfo> = vec![UserInfo::default(); *users as usize];
+        let mut claim_queue: VecDeque<usize> = VecDeque::new();
+
+        res.app_info.deposit(*bankroll);
+        let initial_equity = bankroll.u128() as f64;
+        let mut peak_equity = initial_equity;
+
+        for flip in 0..*flips {
+            let user_id = self.rng.gen_range(0..user_list.len());
+            let user = &mut user_list[user_id];
+            let side = if self.rng.gen_bool(0.5) {
+                Side::Head
+            } else {
+                Side::Tail
+            };
+            let amount = bet_distribution.sample(&mut self.rng, bet);
+            let random_weight = Decimal::raw(self.rng.gen_range(0..WEIGHT_PRECISION));
+
+            // same as try_flip
+            let prize = if side.is_winner(random_weight, *platform_fee) {
+                Uint128::new(2) * amount
+            } else {
+                Uint128::zero()
+            };
+            let payout = res.app_info.settle_flip(amount, prize);
+            user.settle_flip(amount, prize, &payout, flip);
+
+            if payout == Payout::Unclaimed {
+                res.unclaimed_wins += 1;
+
+                if !claim_queue.contains(&user_id) {
+                    claim_queue.push_back(user_id);
+                }
+            }
+
+            // same as process_payout_queue
+            while let Some(&user_id) = claim_queue.front() {
+                let user = &mut user_list[user_id];
+
+                if res.app_info.payable() < user.unclaimed {
+                    break;
+                }
+
+                res.app_info.claim(user.unclaimed);
+                user.unclaimed = Uint128::zero();
+                claim_queue.pop_front();
+            }
+
+            res.flips += 1;
+
+            let equity = get_equity(&res.app_info);
+            peak_equity = peak_equity.max(equity);
+            res.max_drawdown = res
+                .max_drawdown
+                .max((peak_equity - equity) / initial_equity);
+
+            if equity <= 0.0 {
+                res.is_ruined = true;
+                break;
+            }
+        }
+
+        res
+    }
+}
+
+fn get_equity(app_info: &AppInfo) -> f64 {
+    (Int256::from(app_info.deposited) + app_info.revenue.current)
+        .try_into()
+        .map(|x: Int128| x.i128() as f64)
+        .unwrap_or(f64::MIN)
+}
@@ -0,0 +1,120 @@
+use cosmwasm_std::{Decimal, Uint128};
+use rand::{rngs::StdRng, SeedableRng};
+use speculoos::prelude::*;
+
+use cf_base::{converters::str_to_dec, platform::types::Range};
+
+use crate::{
+    config::{BetDistribution, SimulationConfig},
+    simulation::Simulation,
+};
+
+fn to_args(s: &str) -> Vec<String> {
+    s.split_whitespace().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn parse_args() {
+    assert_that(&SimulationConfig::parse_args(&[]).unwrap())
+        .is_equal_to(SimulationConfig::default());
+
+    let config = SimulationConfig::parse_args(&to_args(
+        "--runs 5 --flips 100 --bankroll 7000000 --fee 0.05 --bets exp:2000000 --seed 9",
+    ))
+    .unwrap();
+    assert_that(&config).is_equal_to(SimulationConfig {
+        runs: 5,
+        flips: 100,
+        bankroll: Uint128::new(7_000_000),
+        platform_fee: str_to_dec("0.05"),
+        bet_distribution: BetDistribution::Exponential {
+            mean: Uint128::new(2_000_000),
+        },
+        seed: 9,
+        ..SimulationConfig::default()
+    });
+
+    assert_that(&SimulationConfig::parse_args(&to_args("--runs")).is_err()).is_true();
+    assert_that(&SimulationConfig::parse_args(&to_args("--speed 1")).is_err()).is_true();
+    assert_that(&SimulationConfig::parse_args(&to_args("--bets normal")).is_err()).is_true();
+    assert_that(&SimulationConfig::parse_args(&to_args("--fee 1")).is_err()).is_true();
+    assert_that(&SimulationConfig::parse_args(&to_args("--bet-min 0")).is_err()).is_true();
+}
+
+#[test]
+fn bets_are_in_range() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let bet = Range::new(1_000_000u128, 20_000_000u128);
+
+    for distribution in [
+        BetDistribution::Fixed(Uint128::new(50_000_000)),
+        BetDistribution::Uniform,
+        BetDistribution::Exponential {
+            mean: Uint128::new(3_000_000),
+        },
+    ] {
+        for _ in 0..1_000 {
+            let amount = distribution.sample(&mut rng, &bet);
+            assert_that(&bet.validate(amount).is_ok()).is_true();
+        }
+    }
+}
+
+#[test]
+fn house_edge_matches_platform_fee() {
+    let config = SimulationConfig {
+        runs: 10,
+        flips: 20_000,
+        bankroll: Uint128::new(100_000_000_000),
+        ..SimulationConfig::default()
+    };
+    let report = Simulation::new(config.clone()).run_all();
+
+    assert_that(&report.flips).is_equal_to(200_000);
+    assert_that(&report.expected_house_edge).is_equal_to(0.1);
+    assert_that(&(report.house_edge - 0.1).abs()).is_less_than(0.01);
+    assert_that(&report.ruin_probability).is_equal_to(0.0);
+    assert_that(&report.unclaimed_rate).is_equal_to(0.0);
+
+    let config = SimulationConfig {
+        platform_fee: Decimal::zero(),
+        ..config
+    };
+    let report = Simulation::new(config).run_all();
+    assert_that(&report.house_edge.abs()).is_less_than(0.01);
+}
+
+#[test]
+fn small_bankroll_is_ruined() {
+    let config = SimulationConfig {
+        runs: 50,
+        flips: 1_000,
+        bankroll: Uint128::new(1_000_000),
+        platform_fee: Decimal::zero(),
+        ..SimulationConfig::default()
+    };
+    let report = Simulation::new(config).run_all();
+
+    assert_that(&report.ruin_probability).is_greater_than(0.5);
+    assert_that(&report.unclaimed_rate).is_greater_than(0.0);
+    assert_that(&report.runs_with_unclaimed).is_greater_than(0.0);
+
+    let drawdown_list: Vec<f64> = report.drawdowns.iter().map(|x| x.1).collect();
+    assert_that(&drawdown_list.windows(2).all(|x| x[0] <= x[1])).is_true();
+    assert_that(&drawdown_list[drawdown_list.len() - 1]).is_greater_than_or_equal_to(1.0);
+}
+
+#[test]
+fn runs_are_reproducible() {
+    let config = SimulationConfig {
+        runs: 3,
+        flips: 1_000,
+        seed: 42,
+        ..SimulationConfig::default()
+    };
+
+    assert_that(&Simulation::new(config.clone()).run_all())
+        .is_equal_to(Simulation::new(config.clone()).run_all());
+    assert_that(&Simulation::new(config.clone()).run_all())
+        .is_not_equal_to(Simulation::new(SimulationConfig { seed: 7, ..config }).run_all());
+}
@@ -1,6 +1,8 @@
 use cosmwasm_std::{StdError, StdResult};
 
 use argon2::{Algorithm, Argon2, ParamsBuilder, Version};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
 
 pub const ENC_KEY_LEN: usize = 32;
 
@@ -28,3 +30,37 @@ pub fn calc_hash_bytes(password: &str, salt: &str) -> StdResult<[u8; ENC_KEY_LEN
 
     Ok(out)
 }
+
+/// Hash of the seed chain, it's fast unlike `calc_hash_bytes`
+pub fn calc_sha256(data: &[u8]) -> [u8; ENC_KEY_LEN] {
+    Sha256::digest(data).into()
+}
+
+/// Account address bytes of secp256k1 public key: `ripemd160(sha256(pubkey))`
+pub fn calc_pubkey_hash(pubkey: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(calc_sha256(pubkey)).into()
+}
+
+/// Intermediate sender address bytes of ibc-hooks:
+/// `sha256(sha256("ibc-wasm-hook-intermediary") + "{channel_id}/{original_sender}")`
+pub fn calc_ibc_hooks_sender_hash(channel_id: &str, original_sender: &str) -> [u8; ENC_KEY_LEN] {
+    let data = [
+        calc_sha256(b"ibc-wasm-hook-intermediary").as_slice(),
+        format!("{}/{}", channel_id, original_sender).as_bytes(),
+    ]
+    .concat();
+
+    calc_sha256(&data)
+}
+
+/// Builds the seed chain hashing the last seed `length` times. Seeds are returned in reveal
+/// order: the first one is the head to commit, each next one hashes to the previous one
+pub fn calc_seed_chain(last_seed: &[u8; ENC_KEY_LEN], length: usize) -> Vec<[u8; ENC_KEY_LEN]> {
+    let mut seed_list: Vec<[u8; ENC_KEY_LEN]> =
+        std::iter::successors(Some(last_seed.to_owned()), |x| Some(calc_sha256(x)))
+            .take(length + 1)
+            .collect();
+
+    seed_list.reverse();
+    seed_list
+}
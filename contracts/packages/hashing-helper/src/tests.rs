@@ -1,4 +1,4 @@
-use crate::base::calc_hash_bytes;
+use crate::base::{calc_hash_bytes, calc_seed_chain, calc_sha256};
 
 use speculoos::assert_that;
 
@@ -16,3 +16,27 @@ fn default_hashing() {
 
     assert_that(&hash).is_equal_to(HASH_BYTES);
 }
+
+#[test]
+fn sha256_hashing() {
+    const HASH_BYTES: &[u8; 32] = &[
+        227, 176, 196, 66, 152, 252, 28, 20, 154, 251, 244, 200, 153, 111, 185, 36, 39, 174, 65,
+        228, 100, 155, 147, 76, 164, 149, 153, 27, 120, 82, 184, 85,
+    ];
+
+    assert_that(&calc_sha256(&[])).is_equal_to(HASH_BYTES);
+}
+
+#[test]
+fn seed_chain() {
+    const LENGTH: usize = 5;
+
+    let seed_list = calc_seed_chain(&[7; 32], LENGTH);
+
+    assert_that(&seed_list.len()).is_equal_to(LENGTH + 1);
+    assert_that(&seed_list[LENGTH]).is_equal_to([7; 32]);
+
+    for i in 1..=LENGTH {
+        assert_that(&calc_sha256(&seed_list[i])).is_equal_to(seed_list[i - 1]);
+    }
+}
@@ -0,0 +1,253 @@
+use cosmwasm_std::{HexBinary, StdResult};
+
+use cf_base::{
+    error::ContractError,
+    platform::{
+        state::{FLIP_COOLDOWN, MAX_BONUS_CREDITS},
+        types::{Payout, Range, Side},
+    },
+};
+use hashing_helper::base::calc_sha256;
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const BONUS: u128 = 5 * AMOUNT;
+const WAGERING: u32 = 3;
+const CODE: &str = "OMIES-WELCOME";
+
+fn hash(code: &str) -> HexBinary {
+    HexBinary::from(calc_sha256(code.as_bytes()))
+}
+
+#[test]
+fn credit_bonus() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+    let available_to_withdraw = p.platform_query_available_to_withdraw()?;
+
+    let res = p
+        .platform_try_credit_bonus(
+            ProjectAccount::Alice,
+            &[(ProjectAccount::Alice, BONUS)],
+            WAGERING,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_credit_bonus(
+            ProjectAccount::Admin,
+            &[(ProjectAccount::Alice, BONUS); MAX_BONUS_CREDITS + 1],
+            WAGERING,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::BonusCreditLimit);
+
+    let res = p
+        .platform_try_credit_bonus(
+            ProjectAccount::Admin,
+            &[(ProjectAccount::Alice, BONUS), (ProjectAccount::Bob, 0)],
+            WAGERING,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    let res = p.platform_try_credit_bonus(
+        ProjectAccount::Admin,
+        &[
+            (ProjectAccount::Alice, BONUS),
+            (ProjectAccount::Bob, AMOUNT),
+        ],
+        WAGERING,
+    )?;
+    assert_that(&get_attr(&res, "credits")).is_equal_to(Some("2".to_string()));
+
+    let bonus = p.platform_query_user_bonus(ProjectAccount::Alice)?;
+    assert_that(&bonus.balance.u128()).is_equal_to(BONUS);
+    assert_that(&bonus.wagered.u128()).is_equal_to(0);
+    assert_that(&bonus.wagering_requirement.u128()).is_equal_to(WAGERING as u128 * BONUS);
+
+    // bonus liabilities aren't backed by tokens
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.bonus_balances.u128()).is_equal_to(BONUS + AMOUNT);
+    assert_that(&app_info.balance.u128()).is_equal_to(100 * AMOUNT);
+    assert_that(&p.platform_query_available_to_withdraw()?).is_equal_to(available_to_withdraw);
+
+    Ok(())
+}
+
+#[test]
+fn bonus_flip_pays_winnings_only() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+    // the winnings aren't locked without the wagering requirement
+    p.platform_try_credit_bonus(ProjectAccount::Admin, &[(ProjectAccount::Alice, BONUS)], 0)?;
+
+    let res = p
+        .platform_try_bonus_flip(ProjectAccount::Alice, SIDE, 3 * AMOUNT)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+
+    let res = p
+        .platform_try_bonus_flip(ProjectAccount::Bob, SIDE, AMOUNT)
+        .unwrap_err();
+    assert_error(&res, ContractError::InsufficientBonusBalance);
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    let (mut wins, mut losses, mut flips) = (0u128, 0u128, 0u128);
+
+    while wins == 0 || losses == 0 {
+        let res = p.platform_try_bonus_flip(ProjectAccount::Alice, SIDE, AMOUNT)?;
+        p.wait(FLIP_COOLDOWN);
+        flips += 1;
+
+        match get_attr(&res, "payout").unwrap().parse::<Payout>()? {
+            Payout::None => {
+                assert_that(&get_attr(&res, "prize")).is_equal_to(Some("0".to_string()));
+                losses += 1;
+            }
+            _ => {
+                assert_that(&get_attr(&res, "prize")).is_equal_to(Some(AMOUNT.to_string()));
+                wins += 1;
+            }
+        }
+    }
+
+    // the stake returns to the bonus on win, only the winnings are paid
+    let bonus = p.platform_query_user_bonus(ProjectAccount::Alice)?;
+    assert_that(&bonus.balance.u128()).is_equal_to(BONUS - losses * AMOUNT);
+    assert_that(&bonus.wagered.u128()).is_equal_to(flips * AMOUNT);
+    assert_that(
+        &(p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)? - alice_balance_before),
+    )
+    .is_equal_to(wins * AMOUNT);
+
+    // bonus flips aren't included in the stats
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.bonus_balances.u128()).is_equal_to(BONUS - losses * AMOUNT);
+    assert_that(&app_info.user_stats.bets.count).is_equal_to(0);
+    assert_that(&app_info.balance.u128()).is_equal_to(100 * AMOUNT - wins * AMOUNT);
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?)
+        .is_equal_to(app_info.balance.u128());
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .bets
+            .count,
+    )
+    .is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn bonus_winnings_are_locked_until_wagered() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+    p.platform_try_credit_bonus(ProjectAccount::Admin, &[(ProjectAccount::Alice, BONUS)], 1)?;
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    let (mut bonus_balance, mut locked_wins) = (BONUS, 0);
+
+    // the requirement is met after the last stake is wagered
+    loop {
+        let res = p.platform_try_bonus_flip(ProjectAccount::Alice, SIDE, AMOUNT)?;
+        p.wait(FLIP_COOLDOWN);
+        let bonus = p.platform_query_user_bonus(ProjectAccount::Alice)?;
+        let prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+
+        if prize == 0 {
+            bonus_balance -= AMOUNT;
+        } else if bonus.wagered < bonus.wagering_requirement {
+            assert_that(&get_attr(&res, "locked")).is_equal_to(Some("true".to_string()));
+            assert_that(&get_attr(&res, "payout")).is_equal_to(Some("none".to_string()));
+            assert_that(&get_attr(&res, "outcome")).is_equal_to(Some("win".to_string()));
+            bonus_balance += AMOUNT;
+            locked_wins += 1;
+        } else {
+            assert_that(&get_attr(&res, "locked")).is_none();
+            assert_that(&get_attr(&res, "payout")).is_equal_to(Some("auto_paid".to_string()));
+            assert_that(&bonus.balance.u128()).is_equal_to(bonus_balance);
+            break;
+        }
+
+        // locked winnings aren't paid
+        assert_that(&bonus.balance.u128()).is_equal_to(bonus_balance);
+        assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+            .is_equal_to(alice_balance_before);
+    }
+
+    assert_that(&locked_wins).is_greater_than(0);
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before + AMOUNT);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.bonus_balances.u128()).is_equal_to(bonus_balance);
+    assert_that(&app_info.balance.u128()).is_equal_to(100 * AMOUNT - AMOUNT);
+
+    Ok(())
+}
+
+#[test]
+fn redeem_promo_code() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+
+    let res = p
+        .platform_try_update_promo_code(ProjectAccount::Alice, &hash(CODE), Some((BONUS, 1, 2)))
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_promo_code(
+            ProjectAccount::Admin,
+            &HexBinary::from(CODE.as_bytes()),
+            Some((BONUS, 1, 2)),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperPromoCodeHash);
+
+    let res = p
+        .platform_try_update_promo_code(ProjectAccount::Admin, &hash(CODE), Some((BONUS, 1, 0)))
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    p.platform_try_update_promo_code(ProjectAccount::Admin, &hash(CODE), Some((BONUS, 1, 2)))?;
+    assert_that(
+        &p.platform_query_promo_code(&hash(CODE))?
+            .unwrap()
+            .redemptions,
+    )
+    .is_equal_to(2);
+
+    let res = p
+        .platform_try_redeem_promo_code(ProjectAccount::Alice, "OMIES-WELCOME-2")
+        .unwrap_err();
+    assert_error(&res, ContractError::PromoCodeIsNotFound);
+
+    p.platform_try_redeem_promo_code(ProjectAccount::Alice, CODE)?;
+    let bonus = p.platform_query_user_bonus(ProjectAccount::Alice)?;
+    assert_that(&bonus.balance.u128()).is_equal_to(BONUS);
+    assert_that(&bonus.wagering_requirement.u128()).is_equal_to(BONUS);
+
+    let res = p
+        .platform_try_redeem_promo_code(ProjectAccount::Alice, CODE)
+        .unwrap_err();
+    assert_error(&res, ContractError::PromoCodeIsRedeemed);
+
+    // the code is removed after the last redemption
+    p.platform_try_redeem_promo_code(ProjectAccount::Bob, CODE)?;
+    assert_that(&p.platform_query_promo_code(&hash(CODE))?).is_none();
+    assert_that(&p.platform_query_app_info()?.bonus_balances.u128()).is_equal_to(2 * BONUS);
+
+    let res = p
+        .platform_try_redeem_promo_code(ProjectAccount::John, CODE)
+        .unwrap_err();
+    assert_error(&res, ContractError::PromoCodeIsNotFound);
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use cosmwasm_std::{Addr, Int256, StdResult, Uint128};
+use cw_multi_test::Executor;
+
+use cf_base::error::ContractError;
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        codes::WithCodes,
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin, ProjectToken},
+    },
+};
+
+const AMOUNT: u128 = 1_000;
+const ROUNDS: u32 = 10;
+const REVENUE: u128 = ROUNDS as u128 * AMOUNT;
+const DEX_LIQUIDITY: u128 = 1_000_000;
+
+/// Creates the pair selling 2 ask tokens per 1 Om
+fn create_cw20_pair(p: &mut Project, token: ProjectToken) -> Addr {
+    let pair = p.instantiate_dex(p.get_dex_code_id(), "2");
+
+    p.app
+        .execute_contract(
+            ProjectAccount::Admin.into(),
+            token.into(),
+            &cw20::Cw20ExecuteMsg::Transfer {
+                recipient: pair.to_string(),
+                amount: Uint128::new(DEX_LIQUIDITY),
+            },
+            &[],
+        )
+        .unwrap();
+
+    pair
+}
+
+fn create_native_pair(p: &mut Project, coin: ProjectCoin) -> Addr {
+    let pair = p.instantiate_dex(p.get_dex_code_id(), "2");
+    p.mint_native(&pair, DEX_LIQUIDITY, coin);
+
+    pair
+}
+
+fn query_cw20_supply(p: &Project, token: ProjectToken) -> StdResult<u128> {
+    let cw20::TokenInfoResponse { total_supply, .. } = p
+        .app
+        .wrap()
+        .query_wasm_smart(token.to_string(), &cw20::Cw20QueryMsg::TokenInfo {})?;
+
+    Ok(total_supply.u128())
+}
+
+#[test]
+fn buyback_guards() -> StdResult<()> {
+    let mut p = Project::with_losing_bets(Some(ProjectAccount::Owner), AMOUNT)?;
+    let pair = create_cw20_pair(&mut p, ProjectToken::Atom);
+
+    let res = p
+        .platform_try_buyback(ProjectAccount::Owner, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::BuybackIsDisabled);
+
+    let res = p
+        .platform_try_update_buyback(
+            ProjectAccount::Owner,
+            Some((&pair, ProjectToken::Atom.into(), "0.5")),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_buyback(
+            ProjectAccount::Admin,
+            Some((&pair, ProjectCoin::Om.into(), "0.5")),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperBuybackToken);
+
+    for share in ["0", "1.1"] {
+        let res = p
+            .platform_try_update_buyback(
+                ProjectAccount::Admin,
+                Some((&pair, ProjectToken::Atom.into(), share)),
+            )
+            .unwrap_err();
+        assert_error(&res, ContractError::BuybackShareIsOutOfRange);
+    }
+
+    p.platform_try_update_buyback(
+        ProjectAccount::Admin,
+        Some((&pair, ProjectToken::Atom.into(), "0.5")),
+    )?;
+    assert_that(&p.platform_query_buyback()?.map(|x| x.pair)).is_equal_to(Some(pair.to_string()));
+
+    let res = p
+        .platform_try_buyback(ProjectAccount::Alice, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    // deposited funds aren't used for buyback
+    p.platform_try_deposit(ProjectAccount::Admin, 10 * AMOUNT, ProjectCoin::Om)?;
+    let res = p
+        .platform_try_buyback(ProjectAccount::Owner, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    p.platform_try_update_buyback(ProjectAccount::Admin, None)?;
+    assert_that(&p.platform_query_buyback()?).is_none();
+
+    p.flip_rounds(AMOUNT, 1)?;
+    let res = p
+        .platform_try_buyback(ProjectAccount::Owner, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::BuybackIsDisabled);
+
+    Ok(())
+}
+
+#[test]
+fn buyback_cw20_burn() -> StdResult<()> {
+    let mut p = Project::with_losing_bets(Some(ProjectAccount::Owner), AMOUNT)?;
+    let pair = create_cw20_pair(&mut p, ProjectToken::Atom);
+
+    p.platform_try_update_buyback(
+        ProjectAccount::Admin,
+        Some((&pair, ProjectToken::Atom.into(), "0.5")),
+    )?;
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+
+    let supply_before = query_cw20_supply(&p, ProjectToken::Atom)?;
+    p.platform_try_buyback(ProjectAccount::Owner, None, None)?;
+    let supply_after = query_cw20_supply(&p, ProjectToken::Atom)?;
+
+    let amount_in = REVENUE / 2;
+    let amount_out = 2 * amount_in;
+
+    assert_that(&(supply_before - supply_after)).is_equal_to(amount_out);
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectToken::Atom)?).is_equal_to(0);
+    assert_that(&p.query_balance(&pair, &ProjectCoin::Om)?).is_equal_to(amount_in);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.revenue.current).is_equal_to(Int256::from(REVENUE - amount_in));
+    assert_that(&app_info.revenue.total).is_equal_to(Int256::from(REVENUE));
+    assert_that(&app_info.balance.u128()).is_equal_to(REVENUE - amount_in);
+
+    // the second buyback takes a half of the rest
+    p.platform_try_buyback(ProjectAccount::Admin, None, None)?;
+
+    let buybacks = p.platform_query_buyback_list(10, None)?;
+    assert_that(&buybacks.len()).is_equal_to(2);
+    assert_that(&buybacks[0].id).is_equal_to(0);
+    assert_that(&buybacks[0].buyback.amount_in.u128()).is_equal_to(amount_in);
+    assert_that(&buybacks[0].buyback.amount_out.u128()).is_equal_to(amount_out);
+    assert_that(&buybacks[1].buyback.amount_in.u128()).is_equal_to(amount_in / 2);
+    assert_that(&buybacks[1].buyback.amount_out.u128()).is_equal_to(amount_out / 2);
+
+    let buybacks = p.platform_query_buyback_list(10, Some(0))?;
+    assert_that(&buybacks.len()).is_equal_to(1);
+    assert_that(&buybacks[0].id).is_equal_to(1);
+
+    Ok(())
+}
+
+#[test]
+fn buyback_native_burn_with_slippage() -> StdResult<()> {
+    let mut p = Project::with_losing_bets(Some(ProjectAccount::Owner), AMOUNT)?;
+    let pair = create_native_pair(&mut p, ProjectCoin::Kuji);
+
+    p.platform_try_update_buyback(
+        ProjectAccount::Admin,
+        Some((&pair, ProjectCoin::Kuji.into(), "1")),
+    )?;
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+
+    // expected 2.5 Kuji per Om, 10 % spread isn't enough
+    p.platform_try_buyback(ProjectAccount::Owner, Some("0.4"), Some("0.1"))
+        .unwrap_err();
+    assert_that(&p.platform_query_app_info()?.revenue.current).is_equal_to(Int256::from(REVENUE));
+    assert_that(&p.platform_query_buyback_list(10, None)?).is_empty();
+
+    p.platform_try_buyback(ProjectAccount::Owner, Some("0.4"), Some("0.2"))?;
+
+    // the bought coins are burned by the platform
+    assert_that(&p.query_balance(&pair, &ProjectCoin::Kuji)?)
+        .is_equal_to(DEX_LIQUIDITY - 2 * REVENUE);
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Kuji)?).is_equal_to(0);
+    assert_that(
+        &p.platform_query_buyback_list(10, None)?[0]
+            .buyback
+            .amount_out
+            .u128(),
+    )
+    .is_equal_to(2 * REVENUE);
+    assert_that(&p.platform_query_app_info()?.revenue.current).is_equal_to(Int256::zero());
+
+    let res = p
+        .platform_try_buyback(ProjectAccount::Owner, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    Ok(())
+}
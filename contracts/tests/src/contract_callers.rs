@@ -0,0 +1,217 @@
+use cosmwasm_std::{coins, wasm_execute, Addr, CosmosMsg, HexBinary, StdResult, Uint128};
+use cw_multi_test::{AppResponse, Executor};
+
+use cf_base::{
+    error::{parse_err, ContractError},
+    platform::{
+        msg::ExecuteMsg,
+        state::{DENOM, FLIP_COOLDOWN},
+        types::{Range, Side},
+    },
+};
+use hashing_helper::base::{calc_seed_chain, ENC_KEY_LEN};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    mocks::attacker,
+    platform::PlatformExtension,
+    suite::{
+        codes::WithCodes,
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const ROUNDS: u32 = 20;
+
+fn get_project() -> StdResult<(Project, Addr)> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        None,
+    )?;
+    p.platform_try_deposit(ProjectAccount::Admin, 100 * AMOUNT, ProjectCoin::Om)?;
+    let attacker_address = p.instantiate_attacker(p.get_attacker_code_id());
+
+    Ok((p, attacker_address))
+}
+
+fn attacker_try_flip(
+    p: &mut Project,
+    sender: ProjectAccount,
+    attacker_address: &Addr,
+) -> StdResult<AppResponse> {
+    p.app
+        .execute_contract(
+            sender.into(),
+            attacker_address.to_owned(),
+            &attacker::ExecuteMsg::Flip { side: SIDE },
+            &coins(AMOUNT, DENOM),
+        )
+        .map_err(parse_err)
+}
+
+#[test]
+fn contract_caller_guards() -> StdResult<()> {
+    let (mut p, attacker_address) = get_project()?;
+
+    // contracts are rejected by default
+    assert_that(&p.platform_query_contract_callers()?).is_equal_to(Some(vec![]));
+
+    let res = attacker_try_flip(&mut p, ProjectAccount::Alice, &attacker_address).unwrap_err();
+    assert_error(&res, ContractError::ContractCallerIsNotAllowed);
+
+    // non-contract senders aren't affected
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+
+    let res = p
+        .platform_try_update_contract_callers(ProjectAccount::Alice, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    // the whitelisted contract can flip
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&attacker_address]))?;
+    assert_that(&p.platform_query_contract_callers()?)
+        .is_equal_to(Some(vec![attacker_address.clone()]));
+    // the flip is settled or reverted by the attacker on a loss
+    if let Err(err) = attacker_try_flip(&mut p, ProjectAccount::Alice, &attacker_address) {
+        assert_that(&err.to_string()).contains("Revert on loss");
+    }
+    p.wait(FLIP_COOLDOWN);
+
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[]))?;
+    let res = attacker_try_flip(&mut p, ProjectAccount::Alice, &attacker_address).unwrap_err();
+    assert_error(&res, ContractError::ContractCallerIsNotAllowed);
+
+    Ok(())
+}
+
+#[test]
+fn revert_on_loss_attack() -> StdResult<()> {
+    let (mut p, attacker_address) = get_project()?;
+    let mut wins: u32 = 0;
+
+    // any contract can flip without the defense
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, None)?;
+    assert_that(&p.platform_query_contract_callers()?).is_none();
+
+    for _ in 0..ROUNDS {
+        let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+
+        if attacker_try_flip(&mut p, ProjectAccount::Alice, &attacker_address).is_ok() {
+            wins += 1;
+        }
+
+        // lost bets are reverted, the attacker pays only for wins which are doubled
+        let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+        assert_that(&(alice_balance_before - alice_balance_after)).is_less_than_or_equal_to(AMOUNT);
+        p.wait(FLIP_COOLDOWN);
+    }
+
+    let attacker_balance = p.query_balance(&attacker_address, &ProjectCoin::Om)?;
+    let user = p.platform_query_user(&attacker_address)?;
+    assert_that(&wins).is_greater_than(0);
+    assert_that(&wins).is_less_than(ROUNDS);
+    assert_that(&user.stats.wins.count).is_equal_to(wins);
+    assert_that(&user.stats.bets.count).is_equal_to(wins);
+    assert_that(&attacker_balance).is_equal_to(2 * AMOUNT * wins as u128);
+
+    // the platform only loses as every settled flip is won
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.average_fee.to_string().as_str()).is_equal_to("-1");
+
+    // the defense is enabled again
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[]))?;
+    let res = attacker_try_flip(&mut p, ProjectAccount::Alice, &attacker_address).unwrap_err();
+    assert_error(&res, ContractError::ContractCallerIsNotAllowed);
+
+    Ok(())
+}
+
+#[test]
+fn two_message_revert_on_loss_is_rejected() -> StdResult<()> {
+    let (mut p, attacker_address) = get_project()?;
+    let head = calc_seed_chain(&[1; ENC_KEY_LEN], 3)[0];
+    p.platform_try_commit_seed_chain(ProjectAccount::Admin, &HexBinary::from(head))?;
+
+    let res = p
+        .platform_try_update_deferred_only(ProjectAccount::Alice, true)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+    p.platform_try_update_deferred_only(ProjectAccount::Admin, true)?;
+    assert_that(&p.platform_query_deferred_only()?).is_true();
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    let get_msgs = |p: &Project, client_seed: Option<String>| -> StdResult<Vec<CosmosMsg>> {
+        Ok(vec![
+            wasm_execute(
+                p.get_platform_address(),
+                &ExecuteMsg::Flip {
+                    side: SIDE,
+                    nft: None,
+                    client_seed,
+                    callback: None,
+                    amount: None,
+                    payout_to: None,
+                },
+                coins(AMOUNT, DENOM),
+            )?
+            .into(),
+            wasm_execute(
+                &attacker_address,
+                &attacker::ExecuteMsg::RevertOnLoss {
+                    user: Addr::from(ProjectAccount::Alice).to_string(),
+                    balance: Uint128::new(alice_balance_before),
+                },
+                vec![],
+            )?
+            .into(),
+        ])
+    };
+
+    // the non-contract sender can't flip and check the outcome in the next message of the tx
+    let msgs = get_msgs(&p, None)?;
+    let err = p
+        .app
+        .execute_multi(ProjectAccount::Alice.into(), msgs)
+        .unwrap_err();
+    assert_error(
+        &parse_err(err),
+        ContractError::ImmediateSettlementIsDisabled,
+    );
+
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::ImmediateSettlementIsDisabled);
+    let res = p
+        .platform_try_bonus_flip(ProjectAccount::Alice, SIDE, AMOUNT)
+        .unwrap_err();
+    assert_error(&res, ContractError::ImmediateSettlementIsDisabled);
+
+    // the seeded flip outcome is unknown in the tx, the bet is always taken
+    let msgs = get_msgs(&p, Some("seed".to_string()))?;
+    let err = p
+        .app
+        .execute_multi(ProjectAccount::Alice.into(), msgs)
+        .unwrap_err();
+    assert_that(&format!("{:?}", err)).contains("Revert on loss");
+    p.platform_try_flip_seeded(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om, "seed")?;
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before - AMOUNT);
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .bets
+            .count,
+    )
+    .is_equal_to(0);
+
+    Ok(())
+}
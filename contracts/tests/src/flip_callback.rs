@@ -0,0 +1,205 @@
+use cosmwasm_std::{Addr, HexBinary, StdResult, Uint128};
+
+use cf_base::{
+    error::ContractError,
+    platform::{
+        msg::CallbackMsg,
+        types::{Outcome, Range, Side},
+    },
+};
+use hashing_helper::base::{calc_seed_chain, ENC_KEY_LEN};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    mocks::receiver::FAILURE_MSG,
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        codes::WithCodes,
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const MSG: &[u8] = b"raffle 42";
+
+fn prepare_project() -> StdResult<(Project, Addr)> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, AMOUNT))?;
+    let receiver_address = p.instantiate_receiver(p.get_receiver_code_id());
+
+    Ok((p, receiver_address))
+}
+
+#[test]
+fn callback_contract_must_be_whitelisted() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+
+    let res = p
+        .platform_try_flip_with_callback(
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+            None,
+            &receiver_address,
+            MSG,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::CallbackIsNotAllowed);
+
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver_address]))?;
+    p.platform_try_flip_with_callback(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        None,
+        &receiver_address,
+        MSG,
+    )?;
+    assert_that(&p.query_callbacks(&receiver_address)?.len()).is_equal_to(1);
+
+    Ok(())
+}
+
+#[test]
+fn callback_receives_flip_result() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver_address]))?;
+
+    let res = p.platform_try_flip_with_callback(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        None,
+        &receiver_address,
+        MSG,
+    )?;
+    assert_that(&get_attr(&res, "flip_id")).is_equal_to(Some("0".to_string()));
+    assert_that(&get_attr(&res, "callback")).is_equal_to(Some(receiver_address.to_string()));
+
+    let prize = Uint128::new(get_attr(&res, "prize").unwrap().parse().unwrap());
+    let outcome = if get_attr(&res, "outcome").unwrap() == "win" {
+        Outcome::Win
+    } else {
+        Outcome::Lose
+    };
+    assert_that(&p.query_callbacks(&receiver_address)?).is_equal_to(vec![
+        CallbackMsg::FlipCallback {
+            flip_id: 0,
+            user: Addr::unchecked(ProjectAccount::Alice.to_string()),
+            outcome,
+            prize,
+            msg: MSG.into(),
+        },
+    ]);
+
+    // flips without callback don't take ids
+    p.wait(BLOCK);
+    let res = p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    assert_that(&get_attr(&res, "flip_id")).is_none();
+
+    p.wait(BLOCK);
+    p.platform_try_flip_with_callback(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        None,
+        &receiver_address,
+        MSG,
+    )?;
+    let callbacks = p.query_callbacks(&receiver_address)?;
+    assert_that(&matches!(
+        callbacks[1],
+        CallbackMsg::FlipCallback { flip_id: 1, .. }
+    ))
+    .is_true();
+
+    Ok(())
+}
+
+#[test]
+fn failed_callback_keeps_flip() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver_address]))?;
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+
+    let res = p.platform_try_flip_with_callback(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        None,
+        &receiver_address,
+        FAILURE_MSG,
+    )?;
+    assert_that(&res.events.iter().any(|x| {
+        x.attributes
+            .iter()
+            .any(|y| y.key == "action" && y.value == "flip_callback_failed")
+    }))
+    .is_true();
+    assert_that(&p.query_callbacks(&receiver_address)?).is_empty();
+
+    // the bet and the prize aren't reverted
+    let prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after + AMOUNT)).is_equal_to(alice_balance_before + prize);
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .bets
+            .count,
+    )
+    .is_equal_to(1);
+
+    Ok(())
+}
+
+#[test]
+fn seeded_flip_callback_is_sent_on_settlement() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+    let seed_list: Vec<HexBinary> = calc_seed_chain(&[1; ENC_KEY_LEN], 3)
+        .into_iter()
+        .map(HexBinary::from)
+        .collect();
+
+    // seeded flips can't be reverted, the callback contract isn't whitelisted
+    p.platform_try_commit_seed_chain(ProjectAccount::Admin, &seed_list[0])?;
+    p.wait(BLOCK);
+    p.platform_try_flip_with_callback(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        Some("seed"),
+        &receiver_address,
+        MSG,
+    )?;
+    assert_that(&p.query_callbacks(&receiver_address)?).is_empty();
+
+    for seed in &seed_list[1..=2] {
+        p.platform_try_reveal_seed(ProjectAccount::Admin, seed)?;
+        p.wait(BLOCK);
+    }
+    let res = p.platform_try_settle_flip(ProjectAccount::Bob, 0)?;
+    assert_that(&get_attr(&res, "callback")).is_equal_to(Some(receiver_address.to_string()));
+
+    let callbacks = p.query_callbacks(&receiver_address)?;
+    assert_that(&callbacks.len()).is_equal_to(1);
+    let CallbackMsg::FlipCallback {
+        flip_id, user, msg, ..
+    } = &callbacks[0]
+    else {
+        panic!("flip callback is expected");
+    };
+    assert_that(flip_id).is_equal_to(0);
+    assert_that(&user.to_string()).is_equal_to(ProjectAccount::Alice.to_string());
+    assert_that(&msg.as_slice()).is_equal_to(MSG);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use cosmwasm_std::{coins, to_json_vec, Addr, Api, CanonicalAddr, StdResult, Uint128};
+use cw_multi_test::Executor;
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+use cf_base::{
+    error::{parse_err, ContractError},
+    platform::{
+        msg::{ExecuteMsg, SignedFlipPayload},
+        types::{Range, Side},
+    },
+};
+use hashing_helper::base::{calc_pubkey_hash, calc_sha256};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const TOP_UP: u128 = 3 * AMOUNT;
+/// seconds
+const PAYLOAD_LIFETIME: u64 = 60;
+const RELAYER: ProjectAccount = ProjectAccount::Owner;
+
+/// User holding no gas tokens, the balance is topped up on its behalf
+struct Signer {
+    key: SigningKey,
+    pubkey: Vec<u8>,
+    address: Addr,
+}
+
+impl Signer {
+    fn new(p: &Project, seed: u8) -> Self {
+        let key = SigningKey::from_slice(&[seed; 32]).unwrap();
+        let pubkey = key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        let address = p
+            .app
+            .api()
+            .addr_humanize(&CanonicalAddr::from(calc_pubkey_hash(&pubkey)))
+            .unwrap();
+
+        Self {
+            key,
+            pubkey,
+            address,
+        }
+    }
+
+    fn payload(&self, p: &Project, nonce: u64) -> SignedFlipPayload {
+        SignedFlipPayload {
+            contract: p.get_platform_address().to_string(),
+            chain_id: p.app.block_info().chain_id,
+            nonce,
+            expires_at: p.app.block_info().time.seconds() + PAYLOAD_LIFETIME,
+            side: SIDE,
+            amount: Uint128::new(AMOUNT),
+            client_seed: None,
+        }
+    }
+
+    fn sign(&self, payload: &SignedFlipPayload) -> Vec<u8> {
+        let payload = to_json_vec(payload).unwrap();
+        let signature: Signature = self.key.sign_prehash(&calc_sha256(&payload)).unwrap();
+
+        signature.to_bytes().to_vec()
+    }
+}
+
+fn prepare_project() -> StdResult<(Project, Signer)> {
+    let mut p = Project::with_bet_range(Some(RELAYER), Range::new(AMOUNT, AMOUNT))?;
+
+    let signer = Signer::new(&p, 1);
+    let funds = coins(TOP_UP, ProjectCoin::Om.to_string());
+    p.app
+        .send_tokens(ProjectAccount::Admin.into(), signer.address.clone(), &funds)
+        .unwrap();
+    p.app
+        .execute_contract(
+            signer.address.clone(),
+            p.get_platform_address(),
+            &ExecuteMsg::TopUp {},
+            &funds,
+        )
+        .map_err(parse_err)?;
+
+    Ok((p, signer))
+}
+
+#[test]
+fn signed_flip_is_relayed() -> StdResult<()> {
+    let (mut p, signer) = prepare_project()?;
+    let payload = signer.payload(&p, 0);
+    let signature = signer.sign(&payload);
+
+    // only the worker can relay
+    let res = p
+        .platform_try_flip_signed(ProjectAccount::Alice, &payload, &signer.pubkey, &signature)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p.platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signature)?;
+    assert_that(&get_attr(&res, "user")).is_equal_to(Some(signer.address.to_string()));
+    assert_that(&p.platform_query_signed_nonce(&signer.address)?).is_equal_to(1);
+
+    // the bet is debited from the user balance, the prize is credited to it
+    let prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    assert_that(&p.platform_query_user_balance(&signer.address)?.u128())
+        .is_equal_to(TOP_UP - AMOUNT + prize);
+    assert_that(&p.query_balance(signer.address.clone(), &ProjectCoin::Om)?).is_equal_to(0);
+
+    // the payload can't be replayed
+    p.wait(BLOCK);
+    let res = p
+        .platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signature)
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperSignedNonce);
+
+    let payload = signer.payload(&p, 1);
+    p.platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signer.sign(&payload))?;
+    assert_that(&p.platform_query_signed_nonce(&signer.address)?).is_equal_to(2);
+
+    Ok(())
+}
+
+#[test]
+fn signed_flip_verification() -> StdResult<()> {
+    let (mut p, signer) = prepare_project()?;
+    let payload = signer.payload(&p, 0);
+
+    // signed by another key
+    let signature = Signer::new(&p, 2).sign(&payload);
+    let res = p
+        .platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signature)
+        .unwrap_err();
+    assert_error(&res, ContractError::InvalidSignature);
+
+    // the payload is changed after signing
+    let signature = signer.sign(&payload);
+    let changed_payload = SignedFlipPayload {
+        amount: Uint128::new(2 * AMOUNT),
+        ..payload.clone()
+    };
+    let res = p
+        .platform_try_flip_signed(RELAYER, &changed_payload, &signer.pubkey, &signature)
+        .unwrap_err();
+    assert_error(&res, ContractError::InvalidSignature);
+
+    // the payload is made for another chain
+    let payload = SignedFlipPayload {
+        chain_id: "another-chain".to_string(),
+        ..payload
+    };
+    let res = p
+        .platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signer.sign(&payload))
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperSignedPayload);
+
+    // the payload is expired, the nonce isn't used
+    let payload = signer.payload(&p, 0);
+    let signature = signer.sign(&payload);
+    p.wait(PAYLOAD_LIFETIME);
+    let res = p
+        .platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signature)
+        .unwrap_err();
+    assert_error(&res, ContractError::SignedPayloadIsExpired);
+    assert_that(&p.platform_query_signed_nonce(&signer.address)?).is_equal_to(0);
+
+    // the expiration can't be extended after signing
+    let changed_payload = SignedFlipPayload {
+        expires_at: payload.expires_at + PAYLOAD_LIFETIME,
+        ..payload
+    };
+    let res = p
+        .platform_try_flip_signed(RELAYER, &changed_payload, &signer.pubkey, &signature)
+        .unwrap_err();
+    assert_error(&res, ContractError::InvalidSignature);
+
+    // the balance isn't enough
+    let signer = Signer::new(&p, 3);
+    let payload = signer.payload(&p, 0);
+    let res = p
+        .platform_try_flip_signed(RELAYER, &payload, &signer.pubkey, &signer.sign(&payload))
+        .unwrap_err();
+    assert_error(&res, ContractError::InsufficientUserBalance);
+    assert_that(&p.platform_query_signed_nonce(&signer.address)?).is_equal_to(0);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Contract flipping the coin in a submessage and reverting the whole tx in the reply if the
+//! bet is lost, the prize is kept by the contract. It also guards flips of non-contract senders
+//! executed in the same tx before it
+
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use cf_base::{
+    platform::{state::DENOM, types::Side},
+    utils::EVENT_TYPE,
+};
+
+const PLATFORM: Item<Addr> = Item::new("platform");
+const FLIP_REPLY_ID: u64 = 1;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {
+    pub platform: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// sent funds are used as the bet
+    Flip { side: Side },
+    /// reverts the tx if the user balance dropped below the one before the flip
+    RevertOnLoss { user: String, balance: Uint128 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Platform {},
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    PLATFORM.save(deps.storage, &deps.api.addr_validate(&msg.platform)?)?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    let side = match msg {
+        ExecuteMsg::Flip { side } => side,
+        ExecuteMsg::RevertOnLoss { user, balance } => {
+            if deps.querier.query_balance(user, DENOM)?.amount < balance {
+                Err(StdError::generic_err("Revert on loss"))?;
+            }
+
+            return Ok(Response::new());
+        }
+    };
+
+    let msg = WasmMsg::Execute {
+        contract_addr: PLATFORM.load(deps.storage)?.to_string(),
+        msg: to_json_binary(&cf_base::platform::msg::ExecuteMsg::Flip {
+            side,
+            nft: None,
+            client_seed: None,
+            callback: None,
+            amount: None,
+            payout_to: None,
+        })?,
+        funds: info.funds,
+    };
+
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(msg, FLIP_REPLY_ID)))
+}
+
+pub fn reply(_deps: DepsMut, _env: Env, reply: Reply) -> StdResult<Response> {
+    let SubMsgResult::Ok(res) = reply.result else {
+        Err(StdError::generic_err("Flip is failed"))?
+    };
+
+    let is_lost = res.events.iter().any(|event| {
+        event.ty == format!("wasm-{}", EVENT_TYPE)
+            && event
+                .attributes
+                .iter()
+                .any(|x| x.key == "outcome" && x.value == "lose")
+    });
+
+    if is_lost {
+        Err(StdError::generic_err("Revert on loss"))?;
+    }
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Platform {} => to_json_binary(&PLATFORM.load(deps.storage)?),
+    }
+}
+
+pub fn contract() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query).with_reply(reply))
+}
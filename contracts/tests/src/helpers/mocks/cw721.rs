@@ -0,0 +1,179 @@
+//! Minimal cw721 contract compatible with cw721-base messages. cw721-base depends on
+//! cosmwasm-std 1.x and can't be stored in cw-multi-test App
+
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
+    WasmMsg,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::{Item, Map};
+use serde::{Deserialize, Serialize};
+
+const MINTER: Item<String> = Item::new("minter");
+const OWNERS: Map<&str, String> = Map::new("owners");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    pub minter: String,
+}
+
+/// Unknown fields (token_uri, extension, expires) are ignored
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+    },
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    ApproveAll {
+        operator: String,
+    },
+}
+
+/// Hook message executed on the receiver contract by `SendNft`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiverExecuteMsg {
+    ReceiveNft(Cw721ReceiveMsg),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    OwnerOf {
+        token_id: String,
+    },
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OwnerOfResponse {
+    pub owner: String,
+    pub approvals: Vec<Empty>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Empty {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    MINTER.save(deps.storage, &msg.minter)?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::Mint { token_id, owner } => {
+            if info.sender.as_str() != MINTER.load(deps.storage)? {
+                Err(StdError::generic_err("Unauthorized"))?;
+            }
+
+            if OWNERS.has(deps.storage, &token_id) {
+                Err(StdError::generic_err("Token is already minted"))?;
+            }
+
+            OWNERS.save(deps.storage, &token_id, &owner)?;
+        }
+        ExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => {
+            if info.sender.as_str() != OWNERS.load(deps.storage, &token_id)? {
+                Err(StdError::generic_err("Unauthorized"))?;
+            }
+
+            OWNERS.save(deps.storage, &token_id, &recipient)?;
+        }
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => {
+            if info.sender.as_str() != OWNERS.load(deps.storage, &token_id)? {
+                Err(StdError::generic_err("Unauthorized"))?;
+            }
+
+            OWNERS.save(deps.storage, &token_id, &contract)?;
+
+            return Ok(Response::new().add_message(WasmMsg::Execute {
+                contract_addr: contract,
+                msg: to_json_binary(&ReceiverExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+                    sender: info.sender.to_string(),
+                    token_id,
+                    msg,
+                }))?,
+                funds: vec![],
+            }));
+        }
+        ExecuteMsg::ApproveAll { .. } => {}
+    }
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::OwnerOf { token_id } => to_json_binary(&OwnerOfResponse {
+            owner: OWNERS.load(deps.storage, &token_id)?,
+            approvals: vec![],
+        }),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => {
+            let tokens = OWNERS
+                .range(deps.storage, None, None, Order::Ascending)
+                .filter_map(|x| x.ok())
+                .filter(|(token_id, token_owner)| {
+                    token_owner == &owner && start_after.as_ref().map_or(true, |x| token_id > x)
+                })
+                .map(|(token_id, _)| token_id)
+                .take(limit.unwrap_or(u32::MAX) as usize)
+                .collect();
+
+            to_json_binary(&TokensResponse { tokens })
+        }
+    }
+}
+
+pub fn contract() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
@@ -0,0 +1,103 @@
+//! Minimal DEX pair accepting astroport-like `Swap` messages. The pair sells the ask asset
+//! from its own balance at the fixed rate (ask per offer) set on instantiation
+
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult, WasmMsg,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use cf_base::dex::{AssetInfo, PairExecuteMsg};
+
+const RATE: Item<Decimal> = Item::new("rate");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {
+    pub rate: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Rate {},
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    RATE.save(deps.storage, &msg.rate)?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: PairExecuteMsg,
+) -> StdResult<Response> {
+    let PairExecuteMsg::Swap {
+        offer_asset,
+        ask_asset_info,
+        belief_price,
+        max_spread,
+        to,
+    } = msg;
+
+    let AssetInfo::NativeToken { denom } = offer_asset.info else {
+        Err(StdError::generic_err(
+            "Only native offer asset is supported",
+        ))?
+    };
+
+    if info.funds != coins(offer_asset.amount.u128(), denom) {
+        Err(StdError::generic_err("Improper funds"))?;
+    }
+
+    let return_amount = offer_asset.amount.mul_floor(RATE.load(deps.storage)?);
+
+    if let Some(belief_price) = belief_price {
+        let expected_amount = offer_asset.amount.mul_floor(Decimal::one() / belief_price);
+        let spread = max_spread.unwrap_or_default().min(Decimal::one());
+
+        if return_amount < expected_amount.mul_floor(Decimal::one() - spread) {
+            Err(StdError::generic_err("Max spread assertion"))?;
+        }
+    }
+
+    let recipient = to.unwrap_or(info.sender.to_string());
+    let msg: CosmosMsg =
+        match ask_asset_info.ok_or(StdError::generic_err("Ask asset is not specified"))? {
+            AssetInfo::NativeToken { denom } => BankMsg::Send {
+                to_address: recipient,
+                amount: coins(return_amount.u128(), denom),
+            }
+            .into(),
+            AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+                contract_addr,
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                    recipient,
+                    amount: return_amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+
+    Ok(Response::new().add_message(msg))
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Rate {} => to_json_binary(&RATE.load(deps.storage)?),
+    }
+}
+
+pub fn contract() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
@@ -0,0 +1,130 @@
+//! ICS-20 transfer module stub. Transferred funds are locked on the channel escrow address
+//! and `ibc_transfer` event is emitted, the packet isn't relayed. The response data is
+//! `MsgTransferResponse` with the packet sequence. Transfers over `CLOSED_CHANNEL_ID` are rejected
+
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{
+    Addr, Api, BankMsg, Binary, BlockInfo, CanonicalAddr, CosmosMsg, CustomMsg, CustomQuery, Empty,
+    Event, IbcMsg, IbcQuery, Querier, StdResult, Storage,
+};
+use cw_multi_test::{AppResponse, CosmosRouter, Ibc, Module};
+use serde::de::DeserializeOwned;
+
+use hashing_helper::base::calc_sha256;
+
+const SEQUENCE_KEY: &[u8] = b"ibc_transfer_sequence";
+
+pub const CLOSED_CHANNEL_ID: &str = "channel-closed";
+
+pub struct IbcTransferModule;
+
+impl Ibc for IbcTransferModule {}
+
+impl Module for IbcTransferModule {
+    type ExecT = IbcMsg;
+    type QueryT = IbcQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let IbcMsg::Transfer {
+            channel_id,
+            to_address,
+            amount,
+            ..
+        } = msg
+        else {
+            bail!("Unsupported IBC message: {:?}", msg);
+        };
+
+        if channel_id == CLOSED_CHANNEL_ID {
+            bail!("Channel {} is closed", channel_id);
+        }
+
+        router.execute(
+            api,
+            storage,
+            block,
+            sender.clone(),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: get_escrow_address(api, &channel_id)?.to_string(),
+                amount: vec![amount.clone()],
+            }),
+        )?;
+
+        // sequences start from 1 as in ibc-go
+        let sequence = storage
+            .get(SEQUENCE_KEY)
+            .map(|x| u64::from_be_bytes(x.try_into().unwrap_or_default()))
+            .unwrap_or_default()
+            + 1;
+        storage.set(SEQUENCE_KEY, &sequence.to_be_bytes());
+
+        Ok(AppResponse {
+            events: vec![Event::new("ibc_transfer")
+                .add_attribute("sender", sender)
+                .add_attribute("channel_id", channel_id)
+                .add_attribute("receiver", to_address)
+                .add_attribute("amount", amount.to_string())
+                .add_attribute("sequence", sequence.to_string())],
+            data: Some(encode_transfer_response(sequence)),
+        })
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        bail!("Unsupported IBC query: {:?}", request)
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("Unsupported IBC sudo: {:?}", msg)
+    }
+}
+
+/// Protobuf encoded `MsgTransferResponse`
+fn encode_transfer_response(mut sequence: u64) -> Binary {
+    // field 1, varint
+    let mut data = vec![0x08];
+    while sequence >= 0x80 {
+        data.push((sequence as u8 & 0x7f) | 0x80);
+        sequence >>= 7;
+    }
+    data.push(sequence as u8);
+
+    data.into()
+}
+
+/// Escrow address of the transfer channel derived as ibc-go does
+pub fn get_escrow_address(api: &dyn Api, channel_id: &str) -> StdResult<Addr> {
+    let hash = calc_sha256(format!("ics20-1\0transfer/{}", channel_id).as_bytes());
+
+    api.addr_humanize(&CanonicalAddr::from(&hash[..20]))
+}
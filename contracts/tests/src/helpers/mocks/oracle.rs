@@ -0,0 +1,70 @@
+//! Price oracle answering `OracleQueryMsg::Price` with the price set by anyone. The price update
+//! time is the block time, the query fails if the price isn't set
+
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use cf_base::oracle::{OracleQueryMsg, PriceResp};
+
+const PRICE: Item<Option<PriceResp>> = Item::new("price");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {
+    pub price: Option<Decimal>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    SetPrice { price: Option<Decimal> },
+}
+
+fn save_price(deps: DepsMut, env: &Env, price: Option<Decimal>) -> StdResult<()> {
+    let price = price.map(|x| PriceResp {
+        price: x,
+        updated_at: env.block.time.seconds(),
+    });
+
+    PRICE.save(deps.storage, &price)
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    save_price(deps, &env, msg.price)?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    let ExecuteMsg::SetPrice { price } = msg;
+    save_price(deps, &env, price)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: OracleQueryMsg) -> StdResult<Binary> {
+    match msg {
+        OracleQueryMsg::Price { .. } => to_json_binary(
+            &PRICE
+                .load(deps.storage)?
+                .ok_or(StdError::generic_err("Price is not set"))?,
+        ),
+    }
+}
+
+pub fn contract() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
@@ -0,0 +1,66 @@
+//! Contract receiving flip and randomness callbacks. It stores the callbacks and fails if the passed message
+//! is `FAILURE_MSG`
+
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use cf_base::platform::msg::CallbackMsg;
+
+pub const FAILURE_MSG: &[u8] = b"fail";
+
+const CALLBACKS: Item<Vec<CallbackMsg>> = Item::new("callbacks");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Callbacks {},
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    CALLBACKS.save(deps.storage, &vec![])?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: CallbackMsg,
+) -> StdResult<Response> {
+    let (CallbackMsg::FlipCallback { msg: data, .. }
+    | CallbackMsg::RandomnessCallback { msg: data, .. }) = &msg;
+
+    if data.as_slice() == FAILURE_MSG {
+        Err(StdError::generic_err("Callback is failed"))?;
+    }
+
+    CALLBACKS.update(deps.storage, |mut x| -> StdResult<_> {
+        x.push(msg);
+        Ok(x)
+    })?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Callbacks {} => to_json_binary(&CALLBACKS.load(deps.storage)?),
+    }
+}
+
+pub fn contract() -> Box<dyn Contract<cosmwasm_std::Empty>> {
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
@@ -1,21 +1,108 @@
-use cosmwasm_std::{StdResult, Uint128};
+use cosmwasm_std::{to_json_binary, Addr, HexBinary, StdResult, Uint128};
 use cw_multi_test::{AppResponse, Executor};
 
 use cf_base::{
+    assets::Token,
     converters::str_to_dec,
     error::parse_err,
     platform::{
-        msg::{ExecuteMsg, QueryMsg, UserListRespItem},
-        types::{AppInfo, Config, Range, Side, UserInfo},
+        msg::{
+            BetRangeResp, BuybackListRespItem, CallbackMsg, ExecuteMsg, HouseNftRespItem,
+            InsuranceResp, NftFloorPriceRespItem, NftPerksRespItem, PayoutQueuePositionResp,
+            PendingFlipsRespItem, QueryMsg, RandomnessRequestsRespItem, ReceiveNftMsg,
+            RevenueSplitRespItem, SessionGrantsRespItem, SignedFlipPayload, UserListRespItem,
+            UserRakebackResp, UserTierResp,
+        },
+        state::FLIP_COOLDOWN,
+        types::{
+            AppInfo, BonusCredit, BonusInfo, BuybackConfig, Callback, Config, IbcTarget,
+            InsuranceConfig, Nft, NftPerk, OracleConfig, PromoCode, RakebackConfig, Range,
+            RevenueRecipient, SeedChain, Side, Tier, UserInfo,
+        },
     },
+    utils::EVENT_TYPE,
 };
 
-use crate::helpers::suite::{
-    core::{add_funds_to_exec_msg, Project},
-    types::ProjectAccount,
+use crate::helpers::{
+    mocks::{cw721, receiver},
+    suite::{
+        core::{add_funds_to_exec_msg, Project},
+        types::{ProjectAccount, ProjectNft},
+    },
 };
 
-use super::suite::types::ProjectAsset;
+use super::suite::types::{ProjectAsset, ProjectCoin};
+
+/// Returns `wasm-coinflip` event attributes excluding `_contract_address`
+pub fn get_event_attrs(res: &AppResponse) -> Vec<(String, String)> {
+    res.events
+        .iter()
+        .find(|x| x.ty == format!("wasm-{}", EVENT_TYPE))
+        .map(|x| {
+            x.attributes
+                .iter()
+                .skip(1)
+                .map(|y| (y.key.to_string(), y.value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn to_attrs(attrs: &[(&str, &str)]) -> Vec<(String, String)> {
+    attrs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// Returns the first attribute value with the key among all events
+pub fn get_attr(res: &AppResponse, key: &str) -> Option<String> {
+    res.events
+        .iter()
+        .flat_map(|x| x.attributes.iter())
+        .find(|x| x.key == key)
+        .map(|x| x.value.to_string())
+}
+
+impl Project {
+    /// Project with the bet range and the house deposit of 100 min bets
+    pub fn with_bet_range(worker: Option<ProjectAccount>, bet: Range) -> StdResult<Self> {
+        let mut p = Project::new();
+        let deposit = 100 * bet.min.u128();
+
+        p.platform_try_update_config(ProjectAccount::Admin, None, worker, Some(bet), None)?;
+        p.platform_try_deposit(ProjectAccount::Admin, deposit, ProjectCoin::Om)?;
+
+        Ok(p)
+    }
+
+    /// Project with the fixed bet where the user always loses with 100 % platform fee
+    pub fn with_losing_bets(worker: Option<ProjectAccount>, amount: u128) -> StdResult<Self> {
+        let mut p = Project::new();
+        let bet = Range::new(amount, amount);
+
+        p.platform_try_update_config(ProjectAccount::Admin, None, worker, Some(bet), Some("1"))?;
+
+        Ok(p)
+    }
+
+    /// Alice flips the amount in each round, the rounds are separated by the flip cooldown
+    pub fn flip_rounds(&mut self, amount: u128, rounds: u32) -> StdResult<()> {
+        for _ in 0..rounds {
+            self.platform_try_flip(ProjectAccount::Alice, Side::Head, amount, ProjectCoin::Om)?;
+            self.wait(FLIP_COOLDOWN);
+        }
+
+        Ok(())
+    }
+
+    /// Returns callbacks received by the receiver mock
+    pub fn query_callbacks(&self, receiver_address: &Addr) -> StdResult<Vec<CallbackMsg>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(receiver_address, &receiver::QueryMsg::Callbacks {})
+    }
+}
 
 pub trait PlatformExtension {
     fn platform_try_flip(
@@ -26,8 +113,208 @@ pub trait PlatformExtension {
         asset: impl Into<ProjectAsset>,
     ) -> StdResult<AppResponse>;
 
+    fn platform_try_flip_with_nft(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+        collection: ProjectNft,
+        token_id: u128,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_flip_seeded(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+        client_seed: &str,
+    ) -> StdResult<AppResponse>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn platform_try_flip_with_callback(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+        client_seed: Option<&str>,
+        contract: &Addr,
+        msg: &[u8],
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_settle_flip(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_refund_flip(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn platform_try_request_randomness(
+        &mut self,
+        sender: ProjectAccount,
+        job_id: &str,
+        contract: &Addr,
+        msg: &[u8],
+        deferred: bool,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_fulfill_randomness(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_refund_randomness(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_flip_nft(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        collection: ProjectNft,
+        token_id: u128,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_flip_from_balance(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_session_flip(
+        &mut self,
+        sender: ProjectAccount,
+        owner: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_session_flip_from_balance(
+        &mut self,
+        sender: ProjectAccount,
+        owner: ProjectAccount,
+        side: Side,
+        amount: u128,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_flip_signed(
+        &mut self,
+        sender: ProjectAccount,
+        payload: &SignedFlipPayload,
+        pubkey: &[u8],
+        signature: &[u8],
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_grant_session(
+        &mut self,
+        sender: ProjectAccount,
+        session: ProjectAccount,
+        spend_cap: u128,
+        duration: u64,
+        side: Option<Side>,
+        bet: Option<Range>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_revoke_session(
+        &mut self,
+        sender: ProjectAccount,
+        session: ProjectAccount,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_top_up(
+        &mut self,
+        sender: ProjectAccount,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_withdraw_balance(
+        &mut self,
+        sender: ProjectAccount,
+        amount: Option<u128>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_bonus_flip(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_redeem_promo_code(
+        &mut self,
+        sender: ProjectAccount,
+        code: &str,
+    ) -> StdResult<AppResponse>;
+
     fn platform_try_claim(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
 
+    fn platform_try_claim_rakeback(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
+
+    fn platform_try_process_payouts(
+        &mut self,
+        sender: ProjectAccount,
+        limit: u32,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_distribute_revenue(&mut self, sender: ProjectAccount)
+        -> StdResult<AppResponse>;
+
+    fn platform_try_deposit_insurance(
+        &mut self,
+        sender: ProjectAccount,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_withdraw_insurance(
+        &mut self,
+        sender: ProjectAccount,
+        amount: Option<u128>,
+        recipient: Option<ProjectAccount>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_buyback(
+        &mut self,
+        sender: ProjectAccount,
+        belief_price: Option<&str>,
+        max_spread: Option<&str>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_commit_seed_chain(
+        &mut self,
+        sender: ProjectAccount,
+        head: &HexBinary,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_reveal_seed(
+        &mut self,
+        sender: ProjectAccount,
+        seed: &HexBinary,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_enqueue_payouts(
+        &mut self,
+        sender: ProjectAccount,
+        start_after: Option<&Addr>,
+        limit: u32,
+    ) -> StdResult<AppResponse>;
+
     fn platform_try_accept_admin_role(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
 
     fn platform_try_deposit(
@@ -53,6 +340,103 @@ pub trait PlatformExtension {
         platform_fee: Option<&str>,
     ) -> StdResult<AppResponse>;
 
+    fn platform_try_update_tiers(
+        &mut self,
+        sender: ProjectAccount,
+        tiers: Vec<Tier>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_set_user_tier(
+        &mut self,
+        sender: ProjectAccount,
+        address: ProjectAccount,
+        tier: Option<u8>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_rakeback(
+        &mut self,
+        sender: ProjectAccount,
+        rakeback: Option<RakebackConfig>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_insurance(
+        &mut self,
+        sender: ProjectAccount,
+        insurance: Option<InsuranceConfig>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_contract_callers(
+        &mut self,
+        sender: ProjectAccount,
+        whitelist: Option<&[&Addr]>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_deferred_only(
+        &mut self,
+        sender: ProjectAccount,
+        enabled: bool,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_randomness_fee(
+        &mut self,
+        sender: ProjectAccount,
+        fee: Option<u128>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_nft_perk(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        perk: Option<NftPerk>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_revenue_split(
+        &mut self,
+        sender: ProjectAccount,
+        recipients: &[(ProjectAccount, &str)],
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_buyback(
+        &mut self,
+        sender: ProjectAccount,
+        buyback: Option<(&Addr, ProjectAsset, &str)>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_oracle(
+        &mut self,
+        sender: ProjectAccount,
+        oracle: Option<(&Addr, Range, u64)>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_credit_bonus(
+        &mut self,
+        sender: ProjectAccount,
+        credits: &[(ProjectAccount, u128)],
+        wagering: u32,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_promo_code(
+        &mut self,
+        sender: ProjectAccount,
+        hash: &HexBinary,
+        promo: Option<(u128, u32, u32)>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_update_nft_floor_price(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        price: Option<u128>,
+    ) -> StdResult<AppResponse>;
+
+    fn platform_try_withdraw_nft(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        token_id: u128,
+        recipient: Option<ProjectAccount>,
+    ) -> StdResult<AppResponse>;
+
     fn platform_try_pause(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
 
     fn platform_try_unpause(&mut self, sender: ProjectAccount) -> StdResult<AppResponse>;
@@ -72,82 +456,1036 @@ pub trait PlatformExtension {
         amount: u32,
         start_after: Option<&str>,
     ) -> StdResult<Vec<UserListRespItem>>;
-}
 
-impl PlatformExtension for Project {
+    fn platform_query_tiers(&self) -> StdResult<Vec<Tier>>;
+
+    fn platform_query_user_tier(&self, address: impl ToString) -> StdResult<UserTierResp>;
+
+    fn platform_query_user_balance(&self, address: impl ToString) -> StdResult<Uint128>;
+
+    fn platform_query_user_bonus(&self, address: impl ToString) -> StdResult<BonusInfo>;
+
+    fn platform_query_signed_nonce(&self, address: impl ToString) -> StdResult<u64>;
+
+    fn platform_query_ibc_sender(&self, address: impl ToString) -> StdResult<Option<IbcTarget>>;
+
+    fn platform_query_session_grants(
+        &self,
+        owner: impl ToString,
+        amount: u32,
+        start_after: Option<&str>,
+    ) -> StdResult<Vec<SessionGrantsRespItem>>;
+
+    fn platform_query_payout_queue_position(
+        &self,
+        address: impl ToString,
+    ) -> StdResult<PayoutQueuePositionResp>;
+
+    fn platform_query_seed_chain(&self) -> StdResult<Option<SeedChain>>;
+
+    fn platform_query_revealed_seed(&self, round: u64) -> StdResult<Option<HexBinary>>;
+
+    fn platform_query_pending_flips(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<Vec<PendingFlipsRespItem>>;
+
+    fn platform_query_randomness_fee(&self) -> StdResult<Option<Uint128>>;
+
+    fn platform_query_randomness_requests(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<Vec<RandomnessRequestsRespItem>>;
+
+    fn platform_query_rakeback(&self) -> StdResult<Option<RakebackConfig>>;
+
+    fn platform_query_user_rakeback(&self, address: impl ToString) -> StdResult<UserRakebackResp>;
+
+    fn platform_query_insurance(&self) -> StdResult<InsuranceResp>;
+
+    fn platform_query_contract_callers(&self) -> StdResult<Option<Vec<Addr>>>;
+
+    fn platform_query_deferred_only(&self) -> StdResult<bool>;
+
+    fn platform_query_nft_perks(&self) -> StdResult<Vec<NftPerksRespItem>>;
+
+    fn platform_query_revenue_split(&self) -> StdResult<Vec<RevenueSplitRespItem>>;
+
+    fn platform_query_buyback(&self) -> StdResult<Option<BuybackConfig>>;
+
+    fn platform_query_oracle(&self) -> StdResult<Option<OracleConfig>>;
+
+    fn platform_query_bet_range(&self) -> StdResult<BetRangeResp>;
+
+    fn platform_query_promo_code(&self, hash: &HexBinary) -> StdResult<Option<PromoCode>>;
+
+    fn platform_query_buyback_list(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<Vec<BuybackListRespItem>>;
+
+    fn platform_query_nft_floor_prices(&self) -> StdResult<Vec<NftFloorPriceRespItem>>;
+
+    fn platform_query_house_nfts(
+        &self,
+        amount: u32,
+        start_after: Option<(ProjectNft, u128)>,
+    ) -> StdResult<Vec<HouseNftRespItem>>;
+}
+
+impl PlatformExtension for Project {
+    #[track_caller]
+    fn platform_try_flip(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed: None,
+                callback: None,
+                amount: None,
+                payout_to: None,
+            },
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_flip_with_nft(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+        collection: ProjectNft,
+        token_id: u128,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::Flip {
+                side,
+                nft: Some(Nft {
+                    collection: collection.to_string(),
+                    token_id: token_id.to_string(),
+                }),
+                client_seed: None,
+                callback: None,
+                amount: None,
+                payout_to: None,
+            },
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_flip_seeded(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+        client_seed: &str,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed: Some(client_seed.to_string()),
+                callback: None,
+                amount: None,
+                payout_to: None,
+            },
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_flip_with_callback(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+        client_seed: Option<&str>,
+        contract: &Addr,
+        msg: &[u8],
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::Flip {
+                side,
+                nft: None,
+                client_seed: client_seed.map(|x| x.to_string()),
+                callback: Some(Callback {
+                    contract: contract.to_string(),
+                    msg: msg.into(),
+                }),
+                amount: None,
+                payout_to: None,
+            },
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_settle_flip(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::SettleFlip { id },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_refund_flip(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::RefundFlip { id },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_request_randomness(
+        &mut self,
+        sender: ProjectAccount,
+        job_id: &str,
+        contract: &Addr,
+        msg: &[u8],
+        deferred: bool,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::RequestRandomness {
+                job_id: job_id.to_string(),
+                callback: Callback {
+                    contract: contract.to_string(),
+                    msg: msg.into(),
+                },
+                deferred,
+            },
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_fulfill_randomness(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::FulfillRandomness { id },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_refund_randomness(
+        &mut self,
+        sender: ProjectAccount,
+        id: u64,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::RefundRandomness { id },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_flip_nft(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        collection: ProjectNft,
+        token_id: u128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                collection.into(),
+                &cw721::ExecuteMsg::SendNft {
+                    contract: self.get_platform_address().to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_json_binary(&ReceiveNftMsg::Flip { side })?,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_flip_from_balance(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::Flip {
+                    side,
+                    nft: None,
+                    client_seed: None,
+                    callback: None,
+                    amount: Some(Uint128::new(amount)),
+                    payout_to: None,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_session_flip(
+        &mut self,
+        sender: ProjectAccount,
+        owner: ProjectAccount,
+        side: Side,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::SessionFlip {
+                owner: owner.to_string(),
+                side,
+                client_seed: None,
+                amount: None,
+            },
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_session_flip_from_balance(
+        &mut self,
+        sender: ProjectAccount,
+        owner: ProjectAccount,
+        side: Side,
+        amount: u128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::SessionFlip {
+                    owner: owner.to_string(),
+                    side,
+                    client_seed: None,
+                    amount: Some(Uint128::new(amount)),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_flip_signed(
+        &mut self,
+        sender: ProjectAccount,
+        payload: &SignedFlipPayload,
+        pubkey: &[u8],
+        signature: &[u8],
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::FlipSigned {
+                    payload: to_json_binary(payload)?,
+                    pubkey: pubkey.into(),
+                    signature: signature.into(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_grant_session(
+        &mut self,
+        sender: ProjectAccount,
+        session: ProjectAccount,
+        spend_cap: u128,
+        duration: u64,
+        side: Option<Side>,
+        bet: Option<Range>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::GrantSession {
+                    session: session.to_string(),
+                    spend_cap: Uint128::new(spend_cap),
+                    duration,
+                    side,
+                    bet,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_revoke_session(
+        &mut self,
+        sender: ProjectAccount,
+        session: ProjectAccount,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::RevokeSession {
+                    session: session.to_string(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_top_up(
+        &mut self,
+        sender: ProjectAccount,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::TopUp {},
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_withdraw_balance(
+        &mut self,
+        sender: ProjectAccount,
+        amount: Option<u128>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::WithdrawBalance {
+                    amount: amount.map(Uint128::new),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_bonus_flip(
+        &mut self,
+        sender: ProjectAccount,
+        side: Side,
+        amount: u128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::BonusFlip {
+                    side,
+                    amount: Uint128::new(amount),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_redeem_promo_code(
+        &mut self,
+        sender: ProjectAccount,
+        code: &str,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::RedeemPromoCode {
+                    code: code.to_string(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_claim(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::Claim {},
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_claim_rakeback(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::ClaimRakeback {},
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_process_payouts(
+        &mut self,
+        sender: ProjectAccount,
+        limit: u32,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::ProcessPayouts { limit },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_distribute_revenue(
+        &mut self,
+        sender: ProjectAccount,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::DistributeRevenue {},
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_deposit_insurance(
+        &mut self,
+        sender: ProjectAccount,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::DepositInsurance {},
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_withdraw_insurance(
+        &mut self,
+        sender: ProjectAccount,
+        amount: Option<u128>,
+        recipient: Option<ProjectAccount>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::WithdrawInsurance {
+                    amount: amount.map(Uint128::new),
+                    recipient: recipient.map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_buyback(
+        &mut self,
+        sender: ProjectAccount,
+        belief_price: Option<&str>,
+        max_spread: Option<&str>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::Buyback {
+                    belief_price: belief_price.map(str_to_dec),
+                    max_spread: max_spread.map(str_to_dec),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_commit_seed_chain(
+        &mut self,
+        sender: ProjectAccount,
+        head: &HexBinary,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::CommitSeedChain {
+                    head: head.to_owned(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_reveal_seed(
+        &mut self,
+        sender: ProjectAccount,
+        seed: &HexBinary,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::RevealSeed {
+                    seed: seed.to_owned(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_enqueue_payouts(
+        &mut self,
+        sender: ProjectAccount,
+        start_after: Option<&Addr>,
+        limit: u32,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::EnqueuePayouts {
+                    start_after: start_after.map(|x| x.to_string()),
+                    limit,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_accept_admin_role(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::AcceptAdminRole {},
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_deposit(
+        &mut self,
+        sender: ProjectAccount,
+        amount: u128,
+        asset: impl Into<ProjectAsset>,
+    ) -> StdResult<AppResponse> {
+        add_funds_to_exec_msg(
+            self,
+            sender,
+            &self.get_platform_address(),
+            &ExecuteMsg::Deposit {},
+            amount,
+            asset,
+        )
+    }
+
+    #[track_caller]
+    fn platform_try_withdraw(
+        &mut self,
+        sender: ProjectAccount,
+        amount: Option<u128>,
+        recipient: Option<ProjectAccount>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::Withdraw {
+                    amount: amount.map(Uint128::new),
+                    recipient: recipient.map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_config(
+        &mut self,
+        sender: ProjectAccount,
+        admin: Option<ProjectAccount>,
+        worker: Option<ProjectAccount>,
+        bet: Option<Range>,
+        platform_fee: Option<&str>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateConfig {
+                    admin: admin.map(|x| x.to_string()),
+                    worker: worker.map(|x| x.to_string()),
+                    bet,
+                    platform_fee: platform_fee.map(str_to_dec),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_tiers(
+        &mut self,
+        sender: ProjectAccount,
+        tiers: Vec<Tier>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateTiers { tiers },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_set_user_tier(
+        &mut self,
+        sender: ProjectAccount,
+        address: ProjectAccount,
+        tier: Option<u8>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::SetUserTier {
+                    address: address.to_string(),
+                    tier,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_rakeback(
+        &mut self,
+        sender: ProjectAccount,
+        rakeback: Option<RakebackConfig>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateRakeback { rakeback },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_insurance(
+        &mut self,
+        sender: ProjectAccount,
+        insurance: Option<InsuranceConfig>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateInsurance { insurance },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_contract_callers(
+        &mut self,
+        sender: ProjectAccount,
+        whitelist: Option<&[&Addr]>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateContractCallers {
+                    whitelist: whitelist.map(|x| x.iter().map(|y| y.to_string()).collect()),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_deferred_only(
+        &mut self,
+        sender: ProjectAccount,
+        enabled: bool,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateDeferredOnly { enabled },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_randomness_fee(
+        &mut self,
+        sender: ProjectAccount,
+        fee: Option<u128>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateRandomnessFee {
+                    fee: fee.map(Uint128::new),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_nft_perk(
+        &mut self,
+        sender: ProjectAccount,
+        collection: ProjectNft,
+        perk: Option<NftPerk>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateNftPerk {
+                    collection: collection.to_string(),
+                    perk,
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
+    #[track_caller]
+    fn platform_try_update_revenue_split(
+        &mut self,
+        sender: ProjectAccount,
+        recipients: &[(ProjectAccount, &str)],
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateRevenueSplit {
+                    recipients: recipients
+                        .iter()
+                        .map(|(address, weight)| RevenueRecipient {
+                            address: address.to_string(),
+                            weight: str_to_dec(weight),
+                        })
+                        .collect(),
+                },
+                &[],
+            )
+            .map_err(parse_err)
+    }
+
     #[track_caller]
-    fn platform_try_flip(
+    fn platform_try_update_buyback(
         &mut self,
         sender: ProjectAccount,
-        side: Side,
-        amount: u128,
-        asset: impl Into<ProjectAsset>,
+        buyback: Option<(&Addr, ProjectAsset, &str)>,
     ) -> StdResult<AppResponse> {
-        add_funds_to_exec_msg(
-            self,
-            sender,
-            &self.get_platform_address(),
-            &ExecuteMsg::Flip { side },
-            amount,
-            asset,
-        )
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdateBuyback {
+                    buyback: buyback.map(|(pair, token, share)| BuybackConfig {
+                        pair: pair.to_string(),
+                        token: Token::from(token).into(),
+                        share: str_to_dec(share),
+                    }),
+                },
+                &[],
+            )
+            .map_err(parse_err)
     }
 
     #[track_caller]
-    fn platform_try_claim(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+    fn platform_try_update_oracle(
+        &mut self,
+        sender: ProjectAccount,
+        oracle: Option<(&Addr, Range, u64)>,
+    ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_platform_address(),
-                &ExecuteMsg::Claim {},
+                &ExecuteMsg::UpdateOracle {
+                    oracle: oracle.map(|(contract, bet, max_age)| OracleConfig {
+                        contract: contract.to_string(),
+                        bet,
+                        max_age,
+                    }),
+                },
                 &[],
             )
             .map_err(parse_err)
     }
 
     #[track_caller]
-    fn platform_try_accept_admin_role(&mut self, sender: ProjectAccount) -> StdResult<AppResponse> {
+    fn platform_try_credit_bonus(
+        &mut self,
+        sender: ProjectAccount,
+        credits: &[(ProjectAccount, u128)],
+        wagering: u32,
+    ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_platform_address(),
-                &ExecuteMsg::AcceptAdminRole {},
+                &ExecuteMsg::CreditBonus {
+                    credits: credits
+                        .iter()
+                        .map(|(address, amount)| BonusCredit {
+                            address: address.to_string(),
+                            amount: Uint128::new(*amount),
+                        })
+                        .collect(),
+                    wagering,
+                },
                 &[],
             )
             .map_err(parse_err)
     }
 
     #[track_caller]
-    fn platform_try_deposit(
+    fn platform_try_update_promo_code(
         &mut self,
         sender: ProjectAccount,
-        amount: u128,
-        asset: impl Into<ProjectAsset>,
+        hash: &HexBinary,
+        promo: Option<(u128, u32, u32)>,
     ) -> StdResult<AppResponse> {
-        add_funds_to_exec_msg(
-            self,
-            sender,
-            &self.get_platform_address(),
-            &ExecuteMsg::Deposit {},
-            amount,
-            asset,
-        )
+        self.app
+            .execute_contract(
+                sender.into(),
+                self.get_platform_address(),
+                &ExecuteMsg::UpdatePromoCode {
+                    hash: hash.to_owned(),
+                    promo: promo.map(|(amount, wagering, redemptions)| PromoCode {
+                        amount: Uint128::new(amount),
+                        wagering,
+                        redemptions,
+                    }),
+                },
+                &[],
+            )
+            .map_err(parse_err)
     }
 
     #[track_caller]
-    fn platform_try_withdraw(
+    fn platform_try_update_nft_floor_price(
         &mut self,
         sender: ProjectAccount,
-        amount: Option<u128>,
-        recipient: Option<ProjectAccount>,
+        collection: ProjectNft,
+        price: Option<u128>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_platform_address(),
-                &ExecuteMsg::Withdraw {
-                    amount: amount.map(Uint128::new),
-                    recipient: recipient.map(|x| x.to_string()),
+                &ExecuteMsg::UpdateNftFloorPrice {
+                    collection: collection.to_string(),
+                    price: price.map(Uint128::new),
                 },
                 &[],
             )
@@ -155,23 +1493,21 @@ impl PlatformExtension for Project {
     }
 
     #[track_caller]
-    fn platform_try_update_config(
+    fn platform_try_withdraw_nft(
         &mut self,
         sender: ProjectAccount,
-        admin: Option<ProjectAccount>,
-        worker: Option<ProjectAccount>,
-        bet: Option<Range>,
-        platform_fee: Option<&str>,
+        collection: ProjectNft,
+        token_id: u128,
+        recipient: Option<ProjectAccount>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 sender.into(),
                 self.get_platform_address(),
-                &ExecuteMsg::UpdateConfig {
-                    admin: admin.map(|x| x.to_string()),
-                    worker: worker.map(|x| x.to_string()),
-                    bet,
-                    platform_fee: platform_fee.map(str_to_dec),
+                &ExecuteMsg::WithdrawNft {
+                    collection: collection.to_string(),
+                    token_id: token_id.to_string(),
+                    recipient: recipient.map(|x| x.to_string()),
                 },
                 &[],
             )
@@ -255,4 +1591,266 @@ impl PlatformExtension for Project {
             },
         )
     }
+
+    #[track_caller]
+    fn platform_query_tiers(&self) -> StdResult<Vec<Tier>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::Tiers {})
+    }
+
+    #[track_caller]
+    fn platform_query_user_tier(&self, address: impl ToString) -> StdResult<UserTierResp> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::UserTier {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_signed_nonce(&self, address: impl ToString) -> StdResult<u64> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::SignedNonce {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_ibc_sender(&self, address: impl ToString) -> StdResult<Option<IbcTarget>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::IbcSender {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_session_grants(
+        &self,
+        owner: impl ToString,
+        amount: u32,
+        start_after: Option<&str>,
+    ) -> StdResult<Vec<SessionGrantsRespItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::SessionGrants {
+                owner: owner.to_string(),
+                amount,
+                start_after: start_after.map(|x| x.to_string()),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_user_balance(&self, address: impl ToString) -> StdResult<Uint128> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::UserBalance {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_user_bonus(&self, address: impl ToString) -> StdResult<BonusInfo> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::UserBonus {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_payout_queue_position(
+        &self,
+        address: impl ToString,
+    ) -> StdResult<PayoutQueuePositionResp> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::PayoutQueuePosition {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_seed_chain(&self) -> StdResult<Option<SeedChain>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::SeedChain {})
+    }
+
+    #[track_caller]
+    fn platform_query_revealed_seed(&self, round: u64) -> StdResult<Option<HexBinary>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::RevealedSeed { round },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_pending_flips(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<Vec<PendingFlipsRespItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::PendingFlips {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_randomness_fee(&self) -> StdResult<Option<Uint128>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::RandomnessFee {})
+    }
+
+    #[track_caller]
+    fn platform_query_randomness_requests(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<Vec<RandomnessRequestsRespItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::RandomnessRequests {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_rakeback(&self) -> StdResult<Option<RakebackConfig>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::Rakeback {})
+    }
+
+    #[track_caller]
+    fn platform_query_user_rakeback(&self, address: impl ToString) -> StdResult<UserRakebackResp> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::UserRakeback {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_insurance(&self) -> StdResult<InsuranceResp> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::Insurance {})
+    }
+
+    #[track_caller]
+    fn platform_query_contract_callers(&self) -> StdResult<Option<Vec<Addr>>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::ContractCallers {})
+    }
+
+    #[track_caller]
+    fn platform_query_deferred_only(&self) -> StdResult<bool> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::DeferredOnly {})
+    }
+
+    #[track_caller]
+    fn platform_query_nft_perks(&self) -> StdResult<Vec<NftPerksRespItem>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::NftPerks {})
+    }
+
+    #[track_caller]
+    fn platform_query_revenue_split(&self) -> StdResult<Vec<RevenueSplitRespItem>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::RevenueSplit {})
+    }
+
+    #[track_caller]
+    fn platform_query_buyback(&self) -> StdResult<Option<BuybackConfig>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::Buyback {})
+    }
+
+    #[track_caller]
+    fn platform_query_oracle(&self) -> StdResult<Option<OracleConfig>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::Oracle {})
+    }
+
+    #[track_caller]
+    fn platform_query_bet_range(&self) -> StdResult<BetRangeResp> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::BetRange {})
+    }
+
+    #[track_caller]
+    fn platform_query_promo_code(&self, hash: &HexBinary) -> StdResult<Option<PromoCode>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::PromoCode {
+                hash: hash.to_owned(),
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_buyback_list(
+        &self,
+        amount: u32,
+        start_after: Option<u64>,
+    ) -> StdResult<Vec<BuybackListRespItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::BuybackList {
+                amount,
+                start_after,
+            },
+        )
+    }
+
+    #[track_caller]
+    fn platform_query_nft_floor_prices(&self) -> StdResult<Vec<NftFloorPriceRespItem>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.get_platform_address(), &QueryMsg::NftFloorPrices {})
+    }
+
+    #[track_caller]
+    fn platform_query_house_nfts(
+        &self,
+        amount: u32,
+        start_after: Option<(ProjectNft, u128)>,
+    ) -> StdResult<Vec<HouseNftRespItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.get_platform_address(),
+            &QueryMsg::HouseNfts {
+                amount,
+                start_after: start_after.map(|(collection, token_id)| Nft {
+                    collection: collection.to_string(),
+                    token_id: token_id.to_string(),
+                }),
+            },
+        )
+    }
 }
@@ -0,0 +1,157 @@
+//! Goodness-of-fit tests for samples expected to be i.i.d. uniform on [0, 1).
+//! Each test returns p-value of the null hypothesis
+
+use std::f64::consts::SQRT_2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub name: &'static str,
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    pub fn is_passed(&self, significance_level: f64) -> bool {
+        self.p_value >= significance_level
+    }
+}
+
+/// Pearson's chi-square test on equal-width bins
+pub fn chi_square(sample: &[f64], bins: usize) -> TestResult {
+    let mut counts = vec![0_u64; bins];
+
+    for &x in sample {
+        counts[((x * bins as f64) as usize).min(bins - 1)] += 1;
+    }
+
+    let expected = sample.len() as f64 / bins as f64;
+    let statistic: f64 = counts
+        .iter()
+        .map(|&x| (x as f64 - expected).powi(2) / expected)
+        .sum();
+
+    TestResult {
+        name: "chi-square",
+        statistic,
+        p_value: chi_square_sf(statistic, (bins - 1) as f64),
+    }
+}
+
+/// One-sample Kolmogorov–Smirnov test against uniform CDF
+pub fn kolmogorov_smirnov(sample: &[f64]) -> TestResult {
+    let mut sorted = sample.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let n = sorted.len() as f64;
+    let statistic = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (x - i as f64 / n).max((i + 1) as f64 / n - x))
+        .fold(0.0, f64::max);
+
+    // Stephens' approximation
+    let lambda = (n.sqrt() + 0.12 + 0.11 / n.sqrt()) * statistic;
+
+    TestResult {
+        name: "kolmogorov-smirnov",
+        statistic,
+        p_value: kolmogorov_sf(lambda),
+    }
+}
+
+/// Wald–Wolfowitz runs test above/below 0.5
+pub fn runs(sample: &[f64]) -> TestResult {
+    let is_above: Vec<bool> = sample.iter().map(|&x| x >= 0.5).collect();
+    let n1 = is_above.iter().filter(|&&x| x).count() as f64;
+    let n2 = is_above.len() as f64 - n1;
+    let n = n1 + n2;
+    let runs = 1 + is_above.windows(2).filter(|x| x[0] != x[1]).count();
+
+    let mean = 2.0 * n1 * n2 / n + 1.0;
+    let variance = 2.0 * n1 * n2 * (2.0 * n1 * n2 - n) / (n * n * (n - 1.0));
+    let statistic = (runs as f64 - mean) / variance.sqrt();
+
+    TestResult {
+        name: "runs",
+        statistic,
+        p_value: 2.0 * normal_sf(statistic.abs()),
+    }
+}
+
+/// Lag-1 serial correlation, `r * sqrt(n)` is asymptotically standard normal
+pub fn serial_correlation(sample: &[f64]) -> TestResult {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<f64>() / n;
+    let variance: f64 = sample.iter().map(|x| (x - mean).powi(2)).sum();
+    let covariance: f64 = sample
+        .windows(2)
+        .map(|x| (x[0] - mean) * (x[1] - mean))
+        .sum();
+    let statistic = covariance / variance;
+
+    TestResult {
+        name: "serial correlation",
+        statistic,
+        p_value: 2.0 * normal_sf(statistic.abs() * n.sqrt()),
+    }
+}
+
+pub fn run_all(sample: &[f64], bins: usize) -> Vec<TestResult> {
+    vec![
+        chi_square(sample, bins),
+        kolmogorov_smirnov(sample),
+        runs(sample),
+        serial_correlation(sample),
+    ]
+}
+
+/// P(Z > z) for standard normal Z
+pub fn normal_sf(z: f64) -> f64 {
+    0.5 * erfc(z / SQRT_2)
+}
+
+/// P(X > x) for chi-square X using Wilson–Hilferty transformation
+pub fn chi_square_sf(x: f64, df: f64) -> f64 {
+    let k = 2.0 / (9.0 * df);
+    let z = ((x / df).cbrt() - (1.0 - k)) / k.sqrt();
+
+    normal_sf(z)
+}
+
+/// P(K > lambda) for Kolmogorov distribution
+pub fn kolmogorov_sf(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.0;
+    }
+
+    let p: f64 = (1..=100)
+        .map(|k| {
+            let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+            sign * (-2.0 * (k * k) as f64 * lambda * lambda).exp()
+        })
+        .sum();
+
+    (2.0 * p).clamp(0.0, 1.0)
+}
+
+/// Complementary error function with fractional error < 1.2e-7, see Numerical Recipes
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let y = t
+        * (-z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+            .exp();
+
+    if x >= 0.0 {
+        y
+    } else {
+        2.0 - y
+    }
+}
@@ -14,7 +14,11 @@ use crate::helpers::suite::{
 pub trait WithCodes {
     // store packages
     fn store_cw20_base_code(&mut self) -> u64;
-    // fn store_cw721_base_code(&mut self) -> u64;
+    fn store_cw721_base_code(&mut self) -> u64;
+    fn store_dex_code(&mut self) -> u64;
+    fn store_attacker_code(&mut self) -> u64;
+    fn store_receiver_code(&mut self) -> u64;
+    fn store_oracle_code(&mut self) -> u64;
 
     // store contracts
     fn store_platform_code(&mut self) -> u64;
@@ -22,6 +26,10 @@ pub trait WithCodes {
     // instantiate packages
     fn instantiate_cw20_base_token(&mut self, code_id: u64, project_token: ProjectToken) -> Addr;
     fn instantiate_cw721_base_token(&mut self, code_id: u64) -> Addr;
+    fn instantiate_dex(&mut self, code_id: u64, rate: &str) -> Addr;
+    fn instantiate_attacker(&mut self, code_id: u64) -> Addr;
+    fn instantiate_receiver(&mut self, code_id: u64) -> Addr;
+    fn instantiate_oracle(&mut self, code_id: u64, price: Option<&str>) -> Addr;
 
     // instantiate contracts
     fn instantiate_platform(
@@ -51,13 +59,30 @@ impl WithCodes for Project {
         )))
     }
 
-    // fn store_cw721_base_code(&mut self) -> u64 {
-    //     self.app.store_code(Box::new(ContractWrapper::new(
-    //         cw721_base::entry::execute,
-    //         cw721_base::entry::instantiate,
-    //         cw721_base::entry::query,
-    //     )))
-    // }
+    // cw721-base requires cosmwasm-std 1.x, the mock accepts the same messages
+    fn store_cw721_base_code(&mut self) -> u64 {
+        self.app
+            .store_code(crate::helpers::mocks::cw721::contract())
+    }
+
+    fn store_dex_code(&mut self) -> u64 {
+        self.app.store_code(crate::helpers::mocks::dex::contract())
+    }
+
+    fn store_attacker_code(&mut self) -> u64 {
+        self.app
+            .store_code(crate::helpers::mocks::attacker::contract())
+    }
+
+    fn store_receiver_code(&mut self) -> u64 {
+        self.app
+            .store_code(crate::helpers::mocks::receiver::contract())
+    }
+
+    fn store_oracle_code(&mut self) -> u64 {
+        self.app
+            .store_code(crate::helpers::mocks::oracle::contract())
+    }
 
     // store contracts
     fn store_platform_code(&mut self) -> u64 {
@@ -67,6 +92,7 @@ impl WithCodes for Project {
                 platform::contract::instantiate,
                 platform::contract::query,
             )
+            .with_sudo(platform::contract::sudo)
             .with_reply(platform::contract::reply)
             .with_migrate(platform::contract::migrate),
         ))
@@ -116,6 +142,44 @@ impl WithCodes for Project {
         )
     }
 
+    fn instantiate_dex(&mut self, code_id: u64, rate: &str) -> Addr {
+        self.instantiate_contract(
+            code_id,
+            "dex",
+            &crate::helpers::mocks::dex::InstantiateMsg {
+                rate: str_to_dec(rate),
+            },
+        )
+    }
+
+    fn instantiate_attacker(&mut self, code_id: u64) -> Addr {
+        let platform = self.get_platform_address().to_string();
+
+        self.instantiate_contract(
+            code_id,
+            "attacker",
+            &crate::helpers::mocks::attacker::InstantiateMsg { platform },
+        )
+    }
+
+    fn instantiate_receiver(&mut self, code_id: u64) -> Addr {
+        self.instantiate_contract(
+            code_id,
+            "receiver",
+            &crate::helpers::mocks::receiver::InstantiateMsg {},
+        )
+    }
+
+    fn instantiate_oracle(&mut self, code_id: u64, price: Option<&str>) -> Addr {
+        self.instantiate_contract(
+            code_id,
+            "oracle",
+            &crate::helpers::mocks::oracle::InstantiateMsg {
+                price: price.map(str_to_dec),
+            },
+        )
+    }
+
     // instantiate contracts
     fn instantiate_platform(
         &mut self,
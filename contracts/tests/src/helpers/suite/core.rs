@@ -15,11 +15,14 @@ use cf_base::{
     error::parse_err,
 };
 
-use crate::helpers::suite::{
-    codes::WithCodes,
-    types::{
-        CustomApp, GetDecimals, ProjectAccount, ProjectAsset, ProjectCoin, ProjectNft,
-        ProjectToken, DEFAULT_DECIMALS,
+use crate::helpers::{
+    mocks::ibc::IbcTransferModule,
+    suite::{
+        codes::WithCodes,
+        types::{
+            CustomApp, GetDecimals, ProjectAccount, ProjectAsset, ProjectCoin, ProjectNft,
+            ProjectToken, DEFAULT_DECIMALS,
+        },
     },
 };
 
@@ -30,6 +33,10 @@ pub struct Project {
     // package code id
     cw20_base_code_id: u64,
     cw721_base_code_id: u64,
+    dex_code_id: u64,
+    attacker_code_id: u64,
+    receiver_code_id: u64,
+    oracle_code_id: u64,
 
     // contract code id
     platform_code_id: u64,
@@ -52,6 +59,10 @@ impl Project {
 
             cw20_base_code_id: 0,
             cw721_base_code_id: 0,
+            dex_code_id: 0,
+            attacker_code_id: 0,
+            receiver_code_id: 0,
+            oracle_code_id: 0,
 
             platform_code_id: 0,
 
@@ -69,10 +80,15 @@ impl Project {
         // register contracts code
         // packages
         let cw20_base_code_id = project.store_cw20_base_code();
-        // let cw721_base_code_id = project.store_cw721_base_code();
+        let cw721_base_code_id = project.store_cw721_base_code();
+        let dex_code_id = project.store_dex_code();
+        let attacker_code_id = project.store_attacker_code();
 
         // contracts
         let platform_code_id = project.store_platform_code();
+        // stored after the platform to keep its address
+        let receiver_code_id = project.store_receiver_code();
+        let oracle_code_id = project.store_oracle_code();
 
         // instantiate packages
 
@@ -81,31 +97,32 @@ impl Project {
             project.instantiate_cw20_base_token(cw20_base_code_id, project_token);
         }
 
-        // for _project_nft in ProjectNft::iter() {
-        //     project.instantiate_cw721_base_token(cw721_base_code_id);
-        // }
-
-        // // mint NFTs
-        // let token_id_list: Vec<u128> = vec![1, 2, 3];
-        // for collection in ProjectNft::iter() {
-        //     for (i, recipient) in [
-        //         ProjectAccount::Alice,
-        //         ProjectAccount::Bob,
-        //         ProjectAccount::John,
-        //         ProjectAccount::Kate,
-        //         ProjectAccount::Ruby,
-        //     ]
-        //     .iter()
-        //     .enumerate()
-        //     {
-        //         let nft_list: &Vec<u128> = &token_id_list
-        //             .iter()
-        //             .map(|x| x + (i as u128) * (token_id_list.len() as u128))
-        //             .collect();
-
-        //         project.mint_nft(ProjectAccount::Owner, recipient, collection, nft_list);
-        //     }
-        // }
+        // DON'T CHANGE NFT INIT ORDER AS ITS ADDRESSES ARE HARDCODED IN ProjectNft ENUM
+        for _project_nft in ProjectNft::iter() {
+            project.instantiate_cw721_base_token(cw721_base_code_id);
+        }
+
+        // mint NFTs
+        let token_id_list: Vec<u128> = vec![1, 2, 3];
+        for collection in ProjectNft::iter() {
+            for (i, recipient) in [
+                ProjectAccount::Alice,
+                ProjectAccount::Bob,
+                ProjectAccount::John,
+                ProjectAccount::Kate,
+                ProjectAccount::Ruby,
+            ]
+            .iter()
+            .enumerate()
+            {
+                let nft_list: &Vec<u128> = &token_id_list
+                    .iter()
+                    .map(|x| x + (i as u128) * (token_id_list.len() as u128))
+                    .collect();
+
+                project.mint_nft(ProjectAccount::Owner, recipient, collection, nft_list);
+            }
+        }
 
         // instantiate contracts
 
@@ -113,6 +130,11 @@ impl Project {
 
         project = Self {
             cw20_base_code_id,
+            cw721_base_code_id,
+            dex_code_id,
+            attacker_code_id,
+            receiver_code_id,
+            oracle_code_id,
 
             platform_code_id,
 
@@ -138,6 +160,22 @@ impl Project {
         self.cw721_base_code_id
     }
 
+    pub fn get_dex_code_id(&self) -> u64 {
+        self.dex_code_id
+    }
+
+    pub fn get_attacker_code_id(&self) -> u64 {
+        self.attacker_code_id
+    }
+
+    pub fn get_receiver_code_id(&self) -> u64 {
+        self.receiver_code_id
+    }
+
+    pub fn get_oracle_code_id(&self) -> u64 {
+        self.oracle_code_id
+    }
+
     pub fn get_platform_code_id(&self) -> u64 {
         self.platform_code_id
     }
@@ -409,6 +447,7 @@ impl Project {
         AppBuilder::new_custom()
             .with_api(MockApiBech32::new("wasm"))
             .with_wasm(WasmKeeper::new())
+            .with_ibc(IbcTransferModule)
             .with_block(BlockInfo {
                 height: block_info.height,
                 time: block_info.time,
@@ -1,7 +1,7 @@
 use cosmwasm_std::{testing::MockStorage, Addr, Binary, Decimal, Empty, StdResult};
 use cw_multi_test::{
     App, AppResponse, BankKeeper, DistributionKeeper, FailingModule, GovFailingModule,
-    IbcFailingModule, MockApiBech32, StakeKeeper, WasmKeeper,
+    MockApiBech32, StakeKeeper, WasmKeeper,
 };
 
 use anyhow::Error;
@@ -9,6 +9,8 @@ use strum_macros::{Display, EnumIter, IntoStaticStr};
 
 use cf_base::{assets::Token, converters::str_to_dec, math::P12};
 
+use crate::helpers::mocks::ibc::IbcTransferModule;
+
 pub const DEFAULT_FUNDS_AMOUNT: u128 = P12; // give each user 1 asset (1 CRD, 1 INJ, etc.)
 pub const INCREASED_FUNDS_AMOUNT: u128 = 100 * P12; // give admin such amount of assets to ensure providing 1e6 of assets to each pair
 
@@ -23,7 +25,7 @@ pub type CustomApp = App<
     WasmKeeper<Empty, Empty>,
     StakeKeeper,
     DistributionKeeper,
-    IbcFailingModule,
+    IbcTransferModule,
     GovFailingModule,
 >;
 
@@ -196,6 +198,15 @@ pub enum ProjectAsset {
     Token(ProjectToken),
 }
 
+impl From<ProjectAsset> for Token {
+    fn from(project_asset: ProjectAsset) -> Self {
+        match project_asset {
+            ProjectAsset::Coin(project_coin) => project_coin.into(),
+            ProjectAsset::Token(project_token) => project_token.into(),
+        }
+    }
+}
+
 impl From<ProjectCoin> for ProjectAsset {
     fn from(project_coin: ProjectCoin) -> Self {
         Self::Coin(project_coin)
@@ -0,0 +1,308 @@
+use cosmwasm_std::{coins, Addr, Api, CanonicalAddr, HexBinary, StdResult, Uint128};
+use cw_multi_test::{AppResponse, Executor};
+
+use cf_base::{
+    error::{parse_err, ContractError},
+    platform::{
+        msg::{ExecuteMsg, IbcLifecycleComplete, SudoMsg},
+        state::REVEAL_TIMEOUT,
+        types::{IbcTarget, Range, Side},
+    },
+};
+use hashing_helper::base::{calc_ibc_hooks_sender_hash, calc_seed_chain, ENC_KEY_LEN};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    mocks::ibc::{get_escrow_address, CLOSED_CHANNEL_ID},
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const CHANNEL_ID: &str = "channel-7";
+const RECEIVER: &str = "osmo1cyyzpxplxdzkeea7kwsydadg87357qnahakaks";
+
+fn prepare_project() -> StdResult<(Project, Addr, IbcTarget)> {
+    prepare_project_on(CHANNEL_ID)
+}
+
+fn prepare_project_on(channel_id: &str) -> StdResult<(Project, Addr, IbcTarget)> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, AMOUNT))?;
+
+    // ibc-hooks sends the transferred funds from the intermediate address
+    let target = IbcTarget {
+        channel_id: channel_id.to_string(),
+        receiver: RECEIVER.to_string(),
+    };
+    let intermediate_sender =
+        p.app
+            .api()
+            .addr_humanize(&CanonicalAddr::from(calc_ibc_hooks_sender_hash(
+                channel_id, RECEIVER,
+            )))?;
+    p.app
+        .send_tokens(
+            ProjectAccount::Admin.into(),
+            intermediate_sender.clone(),
+            &coins(10 * AMOUNT, ProjectCoin::Om.to_string()),
+        )
+        .unwrap();
+
+    Ok((p, intermediate_sender, target))
+}
+
+fn try_ibc_flip(
+    p: &mut Project,
+    sender: &Addr,
+    client_seed: Option<&str>,
+    payout_to: Option<&IbcTarget>,
+) -> StdResult<AppResponse> {
+    p.app
+        .execute_contract(
+            sender.clone(),
+            p.get_platform_address(),
+            &ExecuteMsg::Flip {
+                side: SIDE,
+                nft: None,
+                client_seed: client_seed.map(|x| x.to_string()),
+                callback: None,
+                amount: None,
+                payout_to: payout_to.cloned(),
+            },
+            &coins(AMOUNT, ProjectCoin::Om.to_string()),
+        )
+        .map_err(parse_err)
+}
+
+fn try_complete_transfer(p: &mut Project, msg: IbcLifecycleComplete) -> StdResult<AppResponse> {
+    p.app
+        .wasm_sudo(
+            p.get_platform_address(),
+            &SudoMsg::IbcLifecycleComplete(msg),
+        )
+        .map_err(parse_err)
+}
+
+fn query_escrow_balance(p: &Project) -> StdResult<u128> {
+    p.query_balance(
+        get_escrow_address(p.app.api(), CHANNEL_ID)?,
+        &ProjectCoin::Om,
+    )
+}
+
+#[test]
+fn ibc_target_must_match_sender() -> StdResult<()> {
+    let (mut p, intermediate_sender, target) = prepare_project()?;
+
+    // the target of another channel derives another intermediate address
+    let res = try_ibc_flip(
+        &mut p,
+        &intermediate_sender,
+        None,
+        Some(&IbcTarget {
+            channel_id: "channel-8".to_string(),
+            ..target.clone()
+        }),
+    )
+    .unwrap_err();
+    assert_error(&res, ContractError::ImproperIbcTarget);
+
+    // native users can't redirect the payouts
+    let res = try_ibc_flip(&mut p, &ProjectAccount::Alice.into(), None, Some(&target)).unwrap_err();
+    assert_error(&res, ContractError::ImproperIbcTarget);
+    assert_that(&p.platform_query_ibc_sender(ProjectAccount::Alice)?).is_none();
+
+    Ok(())
+}
+
+#[test]
+fn ibc_flip_pays_original_sender() -> StdResult<()> {
+    let (mut p, intermediate_sender, target) = prepare_project()?;
+
+    let res = try_ibc_flip(&mut p, &intermediate_sender, None, Some(&target))?;
+    assert_that(&get_attr(&res, "ibc_channel")).is_equal_to(Some(CHANNEL_ID.to_string()));
+    assert_that(&get_attr(&res, "ibc_receiver")).is_equal_to(Some(RECEIVER.to_string()));
+    assert_that(&p.platform_query_ibc_sender(&intermediate_sender)?)
+        .is_equal_to(Some(target.clone()));
+
+    // the mapping is kept, next flips don't pass the target
+    let mut prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    let mut flip_count = 1;
+    while prize == 0 {
+        p.wait(BLOCK);
+        let res = try_ibc_flip(&mut p, &intermediate_sender, None, None)?;
+        prize = get_attr(&res, "prize").unwrap().parse().unwrap();
+        flip_count += 1;
+
+        if prize != 0 {
+            assert_that(&get_attr(&res, "receiver")).is_equal_to(Some(RECEIVER.to_string()));
+        }
+    }
+
+    // the prize is transferred to the original chain instead of the intermediate address
+    assert_that(&query_escrow_balance(&p)?).is_equal_to(prize);
+    assert_that(&p.query_balance(intermediate_sender.clone(), &ProjectCoin::Om)?)
+        .is_equal_to(10 * AMOUNT - flip_count * AMOUNT);
+
+    // the stats are tracked for the intermediate address
+    let user = p.platform_query_user(intermediate_sender.to_string())?;
+    assert_that(&user.stats.bets.count).is_equal_to(flip_count as u32);
+    assert_that(&user.stats.wins.count).is_equal_to(1);
+
+    Ok(())
+}
+
+#[test]
+fn refunded_ibc_flip_is_returned_to_original_chain() -> StdResult<()> {
+    let (mut p, intermediate_sender, target) = prepare_project()?;
+    let head = calc_seed_chain(&[1; ENC_KEY_LEN], 3)[0];
+    p.platform_try_commit_seed_chain(ProjectAccount::Admin, &HexBinary::from(head))?;
+    p.wait(BLOCK);
+
+    let res = try_ibc_flip(&mut p, &intermediate_sender, Some("seed"), Some(&target))?;
+    assert_that(&get_attr(&res, "ibc_receiver")).is_equal_to(Some(RECEIVER.to_string()));
+
+    p.wait(REVEAL_TIMEOUT);
+    let res = p.platform_try_refund_flip(ProjectAccount::Bob, 0)?;
+    assert_that(&get_attr(&res, "channel_id")).is_equal_to(Some(CHANNEL_ID.to_string()));
+    assert_that(&query_escrow_balance(&p)?).is_equal_to(2 * AMOUNT);
+    assert_that(&p.platform_query_app_info()?.pending_bets.u128()).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn failed_ibc_payout_is_returned_unclaimed() -> StdResult<()> {
+    let (mut p, intermediate_sender, target) = prepare_project()?;
+
+    let mut res = try_ibc_flip(&mut p, &intermediate_sender, None, Some(&target))?;
+    let mut prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    while prize == 0 {
+        p.wait(BLOCK);
+        res = try_ibc_flip(&mut p, &intermediate_sender, None, None)?;
+        prize = get_attr(&res, "prize").unwrap().parse().unwrap();
+    }
+    let sequence: u64 = get_attr(&res, "sequence").unwrap().parse().unwrap();
+
+    // the transfer module refunds the escrowed funds on timeout
+    p.app
+        .send_tokens(
+            get_escrow_address(p.app.api(), CHANNEL_ID)?,
+            p.get_platform_address(),
+            &coins(prize, ProjectCoin::Om.to_string()),
+        )
+        .unwrap();
+    let app_info_before = p.platform_query_app_info()?;
+    let res = try_complete_transfer(
+        &mut p,
+        IbcLifecycleComplete::IbcTimeout {
+            channel: CHANNEL_ID.to_string(),
+            sequence,
+        },
+    )?;
+    assert_that(&get_attr(&res, "amount")).is_equal_to(Some(prize.to_string()));
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.balance).is_equal_to(app_info_before.balance + Uint128::new(prize));
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(prize);
+    assert_that(
+        &p.platform_query_user(intermediate_sender.to_string())?
+            .unclaimed
+            .u128(),
+    )
+    .is_equal_to(prize);
+    assert_that(
+        &p.platform_query_payout_queue_position(&intermediate_sender)?
+            .position,
+    )
+    .is_equal_to(Some(1));
+
+    // the payout is returned once
+    try_complete_transfer(
+        &mut p,
+        IbcLifecycleComplete::IbcTimeout {
+            channel: CHANNEL_ID.to_string(),
+            sequence,
+        },
+    )?;
+    assert_that(&p.platform_query_app_info()?).is_equal_to(app_info);
+
+    // the queued payout is sent again, the acknowledged transfer is completed
+    let res = p.platform_try_process_payouts(ProjectAccount::Bob, 1)?;
+    let sequence: u64 = get_attr(&res, "sequence").unwrap().parse().unwrap();
+    assert_that(&query_escrow_balance(&p)?).is_equal_to(prize);
+    try_complete_transfer(
+        &mut p,
+        IbcLifecycleComplete::IbcAck {
+            channel: CHANNEL_ID.to_string(),
+            sequence,
+            ack: String::default(),
+            success: true,
+        },
+    )?;
+    try_complete_transfer(
+        &mut p,
+        IbcLifecycleComplete::IbcAck {
+            channel: CHANNEL_ID.to_string(),
+            sequence,
+            ack: String::default(),
+            success: false,
+        },
+    )?;
+    assert_that(&p.platform_query_app_info()?.user_unclaimed.u128()).is_equal_to(0);
+    assert_that(
+        &p.platform_query_user(intermediate_sender.to_string())?
+            .unclaimed
+            .u128(),
+    )
+    .is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn rejected_ibc_payout_is_returned_unclaimed() -> StdResult<()> {
+    let (mut p, intermediate_sender, target) = prepare_project_on(CLOSED_CHANNEL_ID)?;
+
+    // the flip isn't reverted by the rejected transfer of the prize
+    let mut res = try_ibc_flip(&mut p, &intermediate_sender, None, Some(&target))?;
+    let mut prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    while prize == 0 {
+        p.wait(BLOCK);
+        res = try_ibc_flip(&mut p, &intermediate_sender, None, None)?;
+        prize = get_attr(&res, "prize").unwrap().parse().unwrap();
+    }
+    assert_that(&get_attr(&res, "payout")).is_equal_to(Some("auto_paid".to_string()));
+    assert_that(&get_attr(&res, "channel_id")).is_equal_to(Some(CLOSED_CHANNEL_ID.to_string()));
+    assert_that(&get_attr(&res, "sequence")).is_none();
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(prize);
+    assert_that(
+        &p.platform_query_user(intermediate_sender.to_string())?
+            .unclaimed
+            .u128(),
+    )
+    .is_equal_to(prize);
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?)
+        .is_equal_to(app_info.balance.u128());
+
+    // the deposit processing the queue isn't reverted, the payout stays unclaimed
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(prize);
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?)
+        .is_equal_to(app_info.balance.u128());
+    assert_that(
+        &p.platform_query_payout_queue_position(&intermediate_sender)?
+            .position,
+    )
+    .is_some();
+
+    Ok(())
+}
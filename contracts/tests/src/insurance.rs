@@ -0,0 +1,206 @@
+use cosmwasm_std::{Int256, StdResult, Uint128};
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::types::{InsuranceConfig, Range, Side},
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const DELAY: u64 = 3;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+
+fn get_insurance(rate: &str, target: u128) -> Option<InsuranceConfig> {
+    Some(InsuranceConfig {
+        rate: str_to_dec(rate),
+        target: Uint128::new(target),
+    })
+}
+
+#[test]
+fn insurance_guards() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        Some(ProjectAccount::Owner),
+        None,
+        None,
+    )?;
+
+    let res = p
+        .platform_try_update_insurance(ProjectAccount::Owner, get_insurance("0.1", AMOUNT))
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_insurance(ProjectAccount::Admin, get_insurance("1.1", AMOUNT))
+        .unwrap_err();
+    assert_error(&res, ContractError::InsuranceRateIsOutOfRange);
+
+    let res = p
+        .platform_try_update_insurance(ProjectAccount::Admin, get_insurance("0.1", 0))
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    let res = p
+        .platform_try_deposit_insurance(ProjectAccount::Alice, AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_deposit_insurance(ProjectAccount::Owner, AMOUNT, ProjectCoin::Usdc)
+        .unwrap_err();
+    assert_error(&res, ContractError::WrongAssetType);
+
+    // the worker can deposit, only the excess above the target can be withdrawn
+    p.platform_try_update_insurance(ProjectAccount::Admin, get_insurance("0.1", AMOUNT))?;
+    p.platform_try_deposit_insurance(ProjectAccount::Owner, 3 * AMOUNT, ProjectCoin::Om)?;
+    assert_that(&p.platform_query_insurance()?.available_to_withdraw.u128())
+        .is_equal_to(2 * AMOUNT);
+
+    let res = p
+        .platform_try_withdraw_insurance(ProjectAccount::Owner, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_withdraw_insurance(ProjectAccount::Admin, Some(2 * AMOUNT + 1), None)
+        .unwrap_err();
+    assert_error(&res, ContractError::NotEnoughLiquidity);
+
+    // the insurance isn't available to withdraw as revenue or deposited funds
+    let res = p
+        .platform_try_withdraw(ProjectAccount::Admin, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_withdraw_insurance(ProjectAccount::Admin, None, Some(ProjectAccount::Alice))?;
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after - alice_balance_before)).is_equal_to(2 * AMOUNT);
+
+    let res = p
+        .platform_try_withdraw_insurance(ProjectAccount::Admin, None, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    // the reserve is kept when the insurance is disabled and can be fully withdrawn
+    p.platform_try_update_insurance(ProjectAccount::Admin, None)?;
+    let insurance = p.platform_query_insurance()?;
+    assert_that(&insurance.config).is_none();
+    assert_that(&insurance.reserve.u128()).is_equal_to(AMOUNT);
+    assert_that(&insurance.available_to_withdraw.u128()).is_equal_to(AMOUNT);
+
+    p.platform_try_withdraw_insurance(ProjectAccount::Admin, None, None)?;
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(0);
+    assert_that(&app_info.balance.u128()).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn insurance_filled_by_lost_bets() -> StdResult<()> {
+    const ROUNDS: u128 = 3;
+    const TARGET: u128 = 250;
+
+    let mut p = Project::new();
+
+    // the user always loses with 100 % platform fee
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        Some("1"),
+    )?;
+    p.platform_try_update_insurance(ProjectAccount::Admin, get_insurance("0.1", TARGET))?;
+
+    for _ in 0..ROUNDS {
+        p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+        p.wait(DELAY);
+    }
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(TARGET);
+    assert_that(&app_info.balance.u128()).is_equal_to(ROUNDS * AMOUNT);
+    assert_that(&app_info.revenue.total).is_equal_to(Int256::from(ROUNDS * AMOUNT));
+    assert_that(&app_info.revenue.current).is_equal_to(Int256::from(ROUNDS * AMOUNT - TARGET));
+    assert_that(&p.platform_query_insurance()?.available_to_withdraw.u128()).is_equal_to(0);
+
+    // the revenue is withdrawn without the insurance
+    p.platform_try_withdraw(ProjectAccount::Admin, None, None)?;
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(TARGET);
+    assert_that(&app_info.balance.u128()).is_equal_to(TARGET);
+
+    Ok(())
+}
+
+#[test]
+fn insurance_pays_winner() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(0, AMOUNT)),
+        None,
+    )?;
+    p.platform_try_update_insurance(ProjectAccount::Admin, get_insurance("0.5", AMOUNT))?;
+
+    // lose, a half of the bet is moved to the insurance
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    p.platform_try_withdraw(ProjectAccount::Admin, None, None)?;
+    p.platform_try_deposit_insurance(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(3 * AMOUNT / 2);
+    assert_that(&app_info.revenue.current).is_equal_to(Int256::zero());
+
+    // win, the insurance covers the shortfall
+    p.wait(DELAY);
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after + AMOUNT - alice_balance_before)).is_equal_to(2 * AMOUNT);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(AMOUNT / 2);
+    assert_that(&app_info.balance.u128()).is_equal_to(AMOUNT / 2);
+    assert_that(&app_info.revenue.current).is_equal_to(Int256::zero());
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(0);
+
+    // win, the balance isn't enough even with the insurance
+    p.wait(DELAY);
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(AMOUNT / 2);
+    assert_that(&app_info.balance.u128()).is_equal_to(3 * AMOUNT / 2);
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(2 * AMOUNT);
+
+    // the insurance covers the shortfall of the queued payout
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT / 2, ProjectCoin::Om)?;
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.insurance.u128()).is_equal_to(0);
+    assert_that(&app_info.balance.u128()).is_equal_to(0);
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(0);
+    assert_that(&(app_info.revenue.current + Int256::from(app_info.deposited)))
+        .is_equal_to(Int256::zero());
+
+    Ok(())
+}
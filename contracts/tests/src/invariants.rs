@@ -0,0 +1,518 @@
+use cosmwasm_std::{Addr, Decimal, HexBinary, Int256, StdResult, Uint128};
+use cw_multi_test::AppResponse;
+use proptest::{collection::vec, option, prelude::*, test_runner::TestCaseError};
+
+use cf_base::{
+    error::decode_err,
+    platform::{
+        state::{FLIP_COOLDOWN, MAX_SESSION_DURATION, REVEAL_TIMEOUT},
+        types::{InsuranceConfig, RakebackConfig, Range, Side, Tier},
+    },
+};
+use hashing_helper::base::{calc_seed_chain, ENC_KEY_LEN};
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        codes::WithCodes,
+        core::Project,
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const USERS: [ProjectAccount; 4] = [
+    ProjectAccount::Alice,
+    ProjectAccount::Bob,
+    ProjectAccount::John,
+    ProjectAccount::Kate,
+];
+const MAX_ACTIONS: usize = 40;
+const LAST_SEED: [u8; ENC_KEY_LEN] = [7; ENC_KEY_LEN];
+const RANDOMNESS_FEE: u128 = 1_000;
+
+#[derive(Debug, Clone)]
+enum Action {
+    Flip {
+        user: usize,
+        side: Side,
+        amount: u128,
+    },
+    /// the bet is taken from the user balance, the prize is credited to it
+    FlipFromBalance {
+        user: usize,
+        side: Side,
+        amount: u128,
+    },
+    /// the session key flips on behalf of the owner, every user is granted by others
+    SessionFlip {
+        user: usize,
+        owner: usize,
+        side: Side,
+        amount: u128,
+        from_balance: bool,
+    },
+    /// settled when the seed of the flip round is revealed
+    FlipSeeded {
+        user: usize,
+        side: Side,
+        amount: u128,
+    },
+    SettleFlip {
+        user: usize,
+        id: u64,
+    },
+    RefundFlip {
+        user: usize,
+        id: u64,
+    },
+    /// reveals the next seed of the committed chain
+    RevealSeed,
+    /// the fee is the revenue, the randomness is sent to the receiver contract
+    RequestRandomness {
+        user: usize,
+        deferred: bool,
+    },
+    FulfillRandomness {
+        user: usize,
+        id: u64,
+    },
+    RefundRandomness {
+        user: usize,
+        id: u64,
+    },
+    /// the stake is taken from the bonus balance, only the winnings are paid
+    BonusFlip {
+        user: usize,
+        side: Side,
+        amount: u128,
+    },
+    CreditBonus {
+        user: usize,
+        amount: u128,
+    },
+    TopUp {
+        user: usize,
+        amount: u128,
+    },
+    WithdrawBalance {
+        user: usize,
+        amount: Option<u128>,
+    },
+    Claim {
+        user: usize,
+    },
+    ClaimRakeback {
+        user: usize,
+    },
+    Deposit {
+        amount: u128,
+    },
+    Withdraw {
+        amount: Option<u128>,
+        recipient: Option<usize>,
+    },
+    UpdateConfig {
+        bet_min: u128,
+        bet_max: u128,
+        platform_fee_percent: u64,
+    },
+    /// single VIP tier reached with the first bet
+    UpdateTiers {
+        fee_discount_percent: u64,
+        cashback_percent: Option<u64>,
+    },
+    UpdateRakeback {
+        rate_percent: u64,
+        epoch: u64,
+    },
+    /// splits the revenue between two recipients
+    UpdateRevenueSplit {
+        weight_percent: u64,
+    },
+    DistributeRevenue {
+        user: usize,
+    },
+    ProcessPayouts {
+        user: usize,
+        limit: u32,
+    },
+    /// the insurance is filled only by lost bets
+    UpdateInsurance {
+        rate_percent: u64,
+        target: u128,
+    },
+    WithdrawInsurance {
+        amount: Option<u128>,
+    },
+    Pause,
+    Unpause,
+    Wait {
+        seconds: u64,
+    },
+}
+
+fn user_strategy() -> impl Strategy<Value = usize> {
+    0..USERS.len()
+}
+
+fn side_strategy() -> impl Strategy<Value = Side> {
+    prop_oneof![Just(Side::Head), Just(Side::Tail)]
+}
+
+/// Amounts are mostly valid but can be out of the bet range to trigger errors.
+/// Zero amounts are skipped as the bank module rejects them before the contract
+fn action_strategy() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        8 => (user_strategy(), side_strategy(), 1..=25_000_000_u128)
+            .prop_map(|(user, side, amount)| Action::Flip { user, side, amount }),
+        2 => (user_strategy(), side_strategy(), 1..=25_000_000_u128)
+            .prop_map(|(user, side, amount)| Action::FlipFromBalance { user, side, amount }),
+        2 => (
+            user_strategy(),
+            user_strategy(),
+            side_strategy(),
+            1..=25_000_000_u128,
+            any::<bool>()
+        )
+            .prop_map(|(user, owner, side, amount, from_balance)| Action::SessionFlip {
+                user,
+                owner,
+                side,
+                amount,
+                from_balance
+            }),
+        3 => (user_strategy(), side_strategy(), 1..=25_000_000_u128)
+            .prop_map(|(user, side, amount)| Action::FlipSeeded { user, side, amount }),
+        2 => (user_strategy(), 0..8_u64).prop_map(|(user, id)| Action::SettleFlip { user, id }),
+        1 => (user_strategy(), 0..8_u64).prop_map(|(user, id)| Action::RefundFlip { user, id }),
+        2 => Just(Action::RevealSeed),
+        1 => (user_strategy(), any::<bool>())
+            .prop_map(|(user, deferred)| Action::RequestRandomness { user, deferred }),
+        1 => (user_strategy(), 0..4_u64)
+            .prop_map(|(user, id)| Action::FulfillRandomness { user, id }),
+        1 => (user_strategy(), 0..4_u64).prop_map(|(user, id)| Action::RefundRandomness { user, id }),
+        2 => (user_strategy(), side_strategy(), 1..=25_000_000_u128)
+            .prop_map(|(user, side, amount)| Action::BonusFlip { user, side, amount }),
+        1 => (user_strategy(), 1..=50_000_000_u128)
+            .prop_map(|(user, amount)| Action::CreditBonus { user, amount }),
+        2 => (user_strategy(), 1..=50_000_000_u128)
+            .prop_map(|(user, amount)| Action::TopUp { user, amount }),
+        1 => (user_strategy(), option::of(1..=50_000_000_u128))
+            .prop_map(|(user, amount)| Action::WithdrawBalance { user, amount }),
+        2 => user_strategy().prop_map(|user| Action::Claim { user }),
+        1 => user_strategy().prop_map(|user| Action::ClaimRakeback { user }),
+        2 => (1..=50_000_000_u128).prop_map(|amount| Action::Deposit { amount }),
+        2 => (option::of(1..=100_000_000_u128), option::of(user_strategy()))
+            .prop_map(|(amount, recipient)| Action::Withdraw { amount, recipient }),
+        1 => (0..=5_000_000_u128, 0..=30_000_000_u128, 0..=25_u64).prop_map(
+            |(bet_min, bet_max, platform_fee_percent)| Action::UpdateConfig {
+                bet_min,
+                bet_max,
+                platform_fee_percent,
+            }
+        ),
+        1 => (0..=100_u64, option::of(0..=100_u64)).prop_map(
+            |(fee_discount_percent, cashback_percent)| Action::UpdateTiers {
+                fee_discount_percent,
+                cashback_percent,
+            }
+        ),
+        1 => (0..=100_u64, 1..=4 * FLIP_COOLDOWN).prop_map(|(rate_percent, epoch)| {
+            Action::UpdateRakeback {
+                rate_percent,
+                epoch,
+            }
+        }),
+        1 => (1..=99_u64).prop_map(|weight_percent| Action::UpdateRevenueSplit { weight_percent }),
+        1 => user_strategy().prop_map(|user| Action::DistributeRevenue { user }),
+        1 => (user_strategy(), 1..=3_u32)
+            .prop_map(|(user, limit)| Action::ProcessPayouts { user, limit }),
+        1 => (0..=100_u64, 1..=20_000_000_u128).prop_map(|(rate_percent, target)| {
+            Action::UpdateInsurance {
+                rate_percent,
+                target,
+            }
+        }),
+        1 => option::of(1..=5_000_000_u128).prop_map(|amount| Action::WithdrawInsurance { amount }),
+        1 => Just(Action::Pause),
+        1 => Just(Action::Unpause),
+        2 => (0..=2 * FLIP_COOLDOWN).prop_map(|seconds| Action::Wait { seconds }),
+        // new blocks are required to reveal seeds
+        2 => (5..=15_u64).prop_map(|seconds| Action::Wait { seconds }),
+        1 => Just(Action::Wait { seconds: REVEAL_TIMEOUT }),
+    ]
+}
+
+fn apply(p: &mut Project, receiver: &Addr, action: &Action) -> StdResult<AppResponse> {
+    match action.to_owned() {
+        Action::Flip { user, side, amount } => {
+            p.platform_try_flip(USERS[user], side, amount, ProjectCoin::Om)
+        }
+        Action::FlipFromBalance { user, side, amount } => {
+            p.platform_try_flip_from_balance(USERS[user], side, amount)
+        }
+        Action::SessionFlip {
+            user,
+            owner,
+            side,
+            amount,
+            from_balance,
+        } => {
+            if from_balance {
+                p.platform_try_session_flip_from_balance(USERS[user], USERS[owner], side, amount)
+            } else {
+                p.platform_try_session_flip(
+                    USERS[user],
+                    USERS[owner],
+                    side,
+                    amount,
+                    ProjectCoin::Om,
+                )
+            }
+        }
+        Action::FlipSeeded { user, side, amount } => {
+            p.platform_try_flip_seeded(USERS[user], side, amount, ProjectCoin::Om, "seed")
+        }
+        Action::SettleFlip { user, id } => p.platform_try_settle_flip(USERS[user], id),
+        Action::RefundFlip { user, id } => p.platform_try_refund_flip(USERS[user], id),
+        Action::RevealSeed => {
+            let round = p
+                .platform_query_seed_chain()?
+                .map(|x| x.round)
+                .unwrap_or_default();
+            let seed = calc_seed_chain(&LAST_SEED, MAX_ACTIONS)
+                .get(round as usize + 1)
+                .copied()
+                .unwrap_or_default();
+
+            p.platform_try_reveal_seed(ProjectAccount::Admin, &HexBinary::from(seed))
+        }
+        Action::RequestRandomness { user, deferred } => p.platform_try_request_randomness(
+            USERS[user],
+            "job",
+            receiver,
+            b"",
+            deferred,
+            RANDOMNESS_FEE,
+            ProjectCoin::Om,
+        ),
+        Action::FulfillRandomness { user, id } => {
+            p.platform_try_fulfill_randomness(USERS[user], id)
+        }
+        Action::RefundRandomness { user, id } => p.platform_try_refund_randomness(USERS[user], id),
+        Action::BonusFlip { user, side, amount } => {
+            p.platform_try_bonus_flip(USERS[user], side, amount)
+        }
+        Action::CreditBonus { user, amount } => {
+            p.platform_try_credit_bonus(ProjectAccount::Admin, &[(USERS[user], amount)], 1)
+        }
+        Action::TopUp { user, amount } => {
+            p.platform_try_top_up(USERS[user], amount, ProjectCoin::Om)
+        }
+        Action::WithdrawBalance { user, amount } => {
+            p.platform_try_withdraw_balance(USERS[user], amount)
+        }
+        Action::Claim { user } => p.platform_try_claim(USERS[user]),
+        Action::ClaimRakeback { user } => p.platform_try_claim_rakeback(USERS[user]),
+        Action::Deposit { amount } => {
+            p.platform_try_deposit(ProjectAccount::Admin, amount, ProjectCoin::Om)
+        }
+        Action::Withdraw { amount, recipient } => {
+            p.platform_try_withdraw(ProjectAccount::Admin, amount, recipient.map(|x| USERS[x]))
+        }
+        Action::UpdateConfig {
+            bet_min,
+            bet_max,
+            platform_fee_percent,
+        } => p.platform_try_update_config(
+            ProjectAccount::Admin,
+            None,
+            None,
+            Some(Range::new(bet_min, bet_max)),
+            Some(&format!("0.{:02}", platform_fee_percent)),
+        ),
+        Action::UpdateTiers {
+            fee_discount_percent,
+            cashback_percent,
+        } => {
+            let bet = p.platform_query_config()?.bet;
+            let to_dec = |x: u64| Decimal::from_ratio(x, 100_u64);
+
+            p.platform_try_update_tiers(
+                ProjectAccount::Admin,
+                vec![
+                    Tier {
+                        threshold: Uint128::zero(),
+                        bet: bet.clone(),
+                        fee_discount: Decimal::zero(),
+                        cashback: None,
+                    },
+                    Tier {
+                        threshold: Uint128::one(),
+                        bet,
+                        fee_discount: to_dec(fee_discount_percent),
+                        cashback: cashback_percent.map(to_dec),
+                    },
+                ],
+            )
+        }
+        Action::UpdateRakeback {
+            rate_percent,
+            epoch,
+        } => p.platform_try_update_rakeback(
+            ProjectAccount::Admin,
+            Some(RakebackConfig {
+                rate: Decimal::from_ratio(rate_percent, 100_u64),
+                epoch,
+            }),
+        ),
+        Action::UpdateRevenueSplit { weight_percent } => {
+            let weight = format!("0.{:02}", weight_percent);
+            let rest = format!("0.{:02}", 100 - weight_percent);
+
+            p.platform_try_update_revenue_split(
+                ProjectAccount::Admin,
+                &[
+                    (ProjectAccount::Owner, &weight),
+                    (ProjectAccount::Ruby, &rest),
+                ],
+            )
+        }
+        Action::DistributeRevenue { user } => p.platform_try_distribute_revenue(USERS[user]),
+        Action::ProcessPayouts { user, limit } => {
+            p.platform_try_process_payouts(USERS[user], limit)
+        }
+        Action::UpdateInsurance {
+            rate_percent,
+            target,
+        } => p.platform_try_update_insurance(
+            ProjectAccount::Admin,
+            Some(InsuranceConfig {
+                rate: Decimal::from_ratio(rate_percent, 100_u64),
+                target: Uint128::new(target),
+            }),
+        ),
+        Action::WithdrawInsurance { amount } => {
+            p.platform_try_withdraw_insurance(ProjectAccount::Admin, amount, None)
+        }
+        Action::Pause => p.platform_try_pause(ProjectAccount::Admin),
+        Action::Unpause => p.platform_try_unpause(ProjectAccount::Admin),
+        Action::Wait { seconds } => {
+            p.wait(seconds);
+            Ok(AppResponse::default())
+        }
+    }
+}
+
+fn check_invariants(p: &Project) -> Result<(), TestCaseError> {
+    let app_info = p.platform_query_app_info()?;
+    let user_list = p.platform_query_user_list(USERS.len() as u32 + 1, None)?;
+    let bank_balance = p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?;
+    let available_to_withdraw = p.platform_query_available_to_withdraw()?;
+    let required_to_deposit = p.platform_query_required_to_deposit()?;
+
+    // balance and bets of pending flips are backed by real funds
+    prop_assert_eq!(
+        (app_info.balance + app_info.pending_bets).u128(),
+        bank_balance
+    );
+
+    // balance = revenue_current + deposited + user_unclaimed + user_rakeback + insurance + user_balances
+    prop_assert_eq!(
+        Int256::from(app_info.balance),
+        app_info.revenue.current
+            + Int256::from(app_info.deposited)
+            + Int256::from(app_info.user_unclaimed)
+            + Int256::from(app_info.user_rakeback)
+            + Int256::from(app_info.insurance)
+            + Int256::from(app_info.user_balances)
+    );
+    prop_assert!(app_info.revenue.current <= app_info.revenue.total);
+
+    // user balances and the insurance are never spent on other payouts. Accrued rakeback is
+    // moved from the revenue which can be already withdrawn, so it isn't always backed
+    prop_assert!(app_info.balance >= app_info.user_balances + app_info.insurance);
+
+    // users are in sync with app info
+    let sum = |f: fn(&cf_base::platform::types::UserInfo) -> Uint128| -> Uint128 {
+        user_list.iter().map(|x| f(&x.info)).sum()
+    };
+    prop_assert_eq!(sum(|x| x.unclaimed), app_info.user_unclaimed);
+    prop_assert_eq!(sum(|x| x.stats.bets.value), app_info.user_stats.bets.value);
+    prop_assert_eq!(sum(|x| x.stats.wins.value), app_info.user_stats.wins.value);
+
+    // accrued rakeback includes finished epochs that aren't settled yet
+    let mut user_rakeback = Uint128::zero();
+    for user in USERS {
+        user_rakeback += p.platform_query_user_rakeback(user)?.accrued;
+    }
+    prop_assert!(user_rakeback >= app_info.user_rakeback);
+
+    let mut user_balances = Uint128::zero();
+    for user in USERS {
+        user_balances += p.platform_query_user_balance(user)?;
+    }
+    prop_assert_eq!(user_balances, app_info.user_balances);
+
+    // bonus balances aren't backed by the balance
+    let mut bonus_balances = Uint128::zero();
+    for user in USERS {
+        bonus_balances += p.platform_query_user_bonus(user)?.balance;
+    }
+    prop_assert_eq!(bonus_balances, app_info.bonus_balances);
+
+    // withdrawing can't affect user unclaimed, rakeback, balances and the insurance
+    let user_rewards = app_info.user_unclaimed + app_info.user_rakeback + app_info.user_balances;
+    prop_assert!(
+        available_to_withdraw
+            <= app_info
+                .balance
+                .saturating_sub(user_rewards + app_info.insurance)
+    );
+    prop_assert_eq!(
+        required_to_deposit,
+        user_rewards.saturating_sub(app_info.balance)
+    );
+
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn accounting_invariants(action_list in vec(action_strategy(), 1..=MAX_ACTIONS)) {
+        let mut p = Project::new();
+        let head = calc_seed_chain(&LAST_SEED, MAX_ACTIONS)[0];
+        p.platform_try_commit_seed_chain(ProjectAccount::Admin, &HexBinary::from(head))?;
+        p.platform_try_update_randomness_fee(ProjectAccount::Admin, Some(RANDOMNESS_FEE))?;
+        let receiver = p.instantiate_receiver(p.get_receiver_code_id());
+        p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver]))?;
+        for (i, owner) in USERS.into_iter().enumerate() {
+            for (_, session) in USERS.into_iter().enumerate().filter(|(j, _)| j != &i) {
+                p.platform_try_grant_session(
+                    owner,
+                    session,
+                    100_000_000,
+                    MAX_SESSION_DURATION,
+                    None,
+                    None,
+                )?;
+            }
+        }
+        check_invariants(&p)?;
+
+        for action in &action_list {
+            if let Err(err) = apply(&mut p, &receiver, action) {
+                // only contract errors are expected
+                prop_assert!(
+                    decode_err(&err.to_string()).is_some(),
+                    "{:?} failed with {}",
+                    action,
+                    err
+                );
+            }
+
+            check_invariants(&p)?;
+            p.wait(1);
+        }
+    }
+}
@@ -1,8 +1,58 @@
 #[cfg(test)]
+pub mod bonus_balance;
+#[cfg(test)]
+pub mod buyback;
+#[cfg(test)]
+pub mod contract_callers;
+#[cfg(test)]
+pub mod flip_callback;
+#[cfg(test)]
+pub mod flip_signed;
+#[cfg(test)]
+pub mod ibc_hooks;
+#[cfg(test)]
+pub mod insurance;
+#[cfg(test)]
+pub mod invariants;
+#[cfg(test)]
+pub mod nft_flip;
+#[cfg(test)]
+pub mod nft_perks;
+#[cfg(test)]
+pub mod oracle_bet_range;
+#[cfg(test)]
+pub mod payout_queue;
+#[cfg(test)]
 pub mod platform;
+#[cfg(test)]
+pub mod rakeback;
+#[cfg(test)]
+pub mod randomness;
+#[cfg(test)]
+pub mod randomness_service;
+#[cfg(test)]
+pub mod revenue_split;
+#[cfg(test)]
+pub mod seed_chain;
+#[cfg(test)]
+pub mod session_keys;
+#[cfg(test)]
+pub mod tiers;
+#[cfg(test)]
+pub mod user_balance;
 
 pub mod helpers {
     pub mod platform;
+    pub mod stats;
+
+    pub mod mocks {
+        pub mod attacker;
+        pub mod cw721;
+        pub mod dex;
+        pub mod ibc;
+        pub mod oracle;
+        pub mod receiver;
+    }
 
     pub mod suite {
         pub mod codes;
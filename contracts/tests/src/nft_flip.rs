@@ -0,0 +1,227 @@
+use cosmwasm_std::{Int256, StdResult, Uint128};
+
+use cf_base::{
+    error::ContractError,
+    platform::{
+        msg::{HouseNftRespItem, NftFloorPriceRespItem},
+        state::FLIP_COOLDOWN,
+        types::Side,
+    },
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin, ProjectNft},
+    },
+};
+
+const PRICE: u128 = 1_000;
+
+fn is_owner(p: &Project, owner: ProjectAccount, collection: ProjectNft, token_id: u128) -> bool {
+    p.query_nft(owner, collection)
+        .contains(&token_id.to_string())
+}
+
+#[test]
+fn nft_flip_guards() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        Some(ProjectAccount::Owner),
+        None,
+        None,
+    )?;
+
+    let res = p
+        .platform_try_flip_nft(ProjectAccount::Alice, Side::Head, ProjectNft::Gopniks, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::NftCollectionIsNotWhitelisted);
+    assert_that(&is_owner(&p, ProjectAccount::Alice, ProjectNft::Gopniks, 1)).is_true();
+
+    let res = p
+        .platform_try_update_nft_floor_price(
+            ProjectAccount::Owner,
+            ProjectNft::Gopniks,
+            Some(PRICE),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_nft_floor_price(ProjectAccount::Admin, ProjectNft::Gopniks, Some(0))
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    let res = p
+        .platform_try_update_nft_floor_price(ProjectAccount::Admin, ProjectNft::Gopniks, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::NftCollectionIsNotWhitelisted);
+
+    p.platform_try_update_nft_floor_price(ProjectAccount::Admin, ProjectNft::Gopniks, Some(PRICE))?;
+
+    // the worker can update the price of whitelisted collection only
+    p.platform_try_update_nft_floor_price(
+        ProjectAccount::Owner,
+        ProjectNft::Gopniks,
+        Some(2 * PRICE),
+    )?;
+    assert_that(&p.platform_query_nft_floor_prices()?).is_equal_to(vec![NftFloorPriceRespItem {
+        collection: ProjectNft::Gopniks.into(),
+        price: Uint128::new(2 * PRICE),
+    }]);
+
+    let res = p
+        .platform_try_update_nft_floor_price(ProjectAccount::Owner, ProjectNft::Gopniks, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_withdraw_nft(ProjectAccount::Admin, ProjectNft::Gopniks, 1, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::HouseNftIsNotFound);
+
+    p.platform_try_pause(ProjectAccount::Admin)?;
+    let res = p
+        .platform_try_flip_nft(ProjectAccount::Alice, Side::Head, ProjectNft::Gopniks, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::ContractIsPaused);
+
+    Ok(())
+}
+
+#[test]
+fn nft_flip_lose_and_withdraw() -> StdResult<()> {
+    let mut p = Project::new();
+
+    // the user can't win with 100 % platform fee
+    p.platform_try_update_config(ProjectAccount::Admin, None, None, None, Some("1"))?;
+    p.platform_try_update_nft_floor_price(ProjectAccount::Admin, ProjectNft::Gopniks, Some(PRICE))?;
+
+    for token_id in 1..=3 {
+        p.platform_try_flip_nft(
+            ProjectAccount::Alice,
+            Side::Head,
+            ProjectNft::Gopniks,
+            token_id,
+        )?;
+        p.wait(FLIP_COOLDOWN);
+    }
+
+    assert_that(&p.query_nft(ProjectAccount::Alice, ProjectNft::Gopniks)).is_empty();
+    assert_that(&p.query_nft(p.get_platform_address(), ProjectNft::Gopniks)).has_length(3);
+
+    let house_nfts = p.platform_query_house_nfts(10, Some((ProjectNft::Gopniks, 1)))?;
+    assert_that(&house_nfts).is_equal_to(vec![
+        HouseNftRespItem {
+            collection: ProjectNft::Gopniks.into(),
+            token_id: "2".to_string(),
+            price: Uint128::new(PRICE),
+        },
+        HouseNftRespItem {
+            collection: ProjectNft::Gopniks.into(),
+            token_id: "3".to_string(),
+            price: Uint128::new(PRICE),
+        },
+    ]);
+
+    // NFT flips don't change the balance and the stats
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.balance.u128()).is_equal_to(0);
+    assert_that(&app_info.user_stats.bets.count).is_equal_to(0);
+
+    let res = p
+        .platform_try_withdraw_nft(ProjectAccount::Alice, ProjectNft::Gopniks, 1, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    p.platform_try_withdraw_nft(
+        ProjectAccount::Admin,
+        ProjectNft::Gopniks,
+        1,
+        Some(ProjectAccount::Bob),
+    )?;
+    assert_that(&is_owner(&p, ProjectAccount::Bob, ProjectNft::Gopniks, 1)).is_true();
+    assert_that(&p.platform_query_house_nfts(10, None)?).has_length(2);
+
+    Ok(())
+}
+
+#[test]
+fn nft_flip_win() -> StdResult<()> {
+    const TOKEN_ID: u128 = 4;
+
+    let mut p = Project::new();
+
+    p.platform_try_update_config(ProjectAccount::Admin, None, None, None, Some("0"))?;
+    p.platform_try_update_nft_floor_price(
+        ProjectAccount::Admin,
+        ProjectNft::Pinjeons,
+        Some(PRICE),
+    )?;
+
+    // the prize is unclaimed without liquidity, lost NFT is returned to try again
+    loop {
+        p.platform_try_flip_nft(
+            ProjectAccount::Bob,
+            Side::Tail,
+            ProjectNft::Pinjeons,
+            TOKEN_ID,
+        )?;
+        p.wait(FLIP_COOLDOWN);
+
+        if is_owner(&p, ProjectAccount::Bob, ProjectNft::Pinjeons, TOKEN_ID) {
+            break;
+        }
+
+        p.platform_try_withdraw_nft(
+            ProjectAccount::Admin,
+            ProjectNft::Pinjeons,
+            TOKEN_ID,
+            Some(ProjectAccount::Bob),
+        )?;
+    }
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&p.platform_query_user(ProjectAccount::Bob)?.unclaimed.u128()).is_equal_to(PRICE);
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(PRICE);
+    assert_that(&app_info.revenue.current).is_equal_to(Int256::from(-(PRICE as i128)));
+    assert_that(&p.platform_query_required_to_deposit()?.u128()).is_equal_to(PRICE);
+
+    // the prize is paid automatically with enough liquidity
+    // the queued prize is paid on deposit
+    p.platform_try_deposit(ProjectAccount::Admin, 100 * PRICE, ProjectCoin::Om)?;
+    assert_that(&p.platform_query_user(ProjectAccount::Bob)?.unclaimed.u128()).is_equal_to(0);
+    let bob_balance = p.query_balance(ProjectAccount::Bob, &ProjectCoin::Om)?;
+
+    loop {
+        p.platform_try_flip_nft(
+            ProjectAccount::Bob,
+            Side::Tail,
+            ProjectNft::Pinjeons,
+            TOKEN_ID,
+        )?;
+        p.wait(FLIP_COOLDOWN);
+
+        if is_owner(&p, ProjectAccount::Bob, ProjectNft::Pinjeons, TOKEN_ID) {
+            break;
+        }
+
+        p.platform_try_withdraw_nft(
+            ProjectAccount::Admin,
+            ProjectNft::Pinjeons,
+            TOKEN_ID,
+            Some(ProjectAccount::Bob),
+        )?;
+    }
+
+    assert_that(&(p.query_balance(ProjectAccount::Bob, &ProjectCoin::Om)? - bob_balance))
+        .is_equal_to(PRICE);
+    assert_that(&p.platform_query_app_info()?.balance.u128()).is_equal_to(98 * PRICE);
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use cosmwasm_std::{StdResult, Uint128};
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::{
+        msg::NftPerksRespItem,
+        state::FLIP_COOLDOWN,
+        types::{NftPerk, Range, Side},
+    },
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin, ProjectNft},
+    },
+};
+
+const AMOUNT: u128 = 1_000;
+
+fn nft_perk(fee_discount: &str, bet_max: Option<u128>) -> NftPerk {
+    NftPerk {
+        fee_discount: str_to_dec(fee_discount),
+        bet_max: bet_max.map(Uint128::new),
+    }
+}
+
+#[test]
+fn nft_perk_guards() -> StdResult<()> {
+    let mut p = Project::new();
+
+    let res = p
+        .platform_try_update_nft_perk(
+            ProjectAccount::Alice,
+            ProjectNft::Gopniks,
+            Some(nft_perk("0.5", None)),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_nft_perk(
+            ProjectAccount::Admin,
+            ProjectNft::Gopniks,
+            Some(nft_perk("1.5", None)),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::NftDiscountIsOutOfRange);
+
+    let res = p
+        .platform_try_update_nft_perk(
+            ProjectAccount::Admin,
+            ProjectNft::Gopniks,
+            Some(nft_perk("0.5", Some(0))),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroMaxBet);
+
+    let res = p
+        .platform_try_update_nft_perk(ProjectAccount::Admin, ProjectNft::Gopniks, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::NftCollectionIsNotFound);
+
+    let res = p
+        .platform_try_flip_with_nft(
+            ProjectAccount::Alice,
+            Side::Head,
+            AMOUNT,
+            ProjectCoin::Om,
+            ProjectNft::Gopniks,
+            1,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::NftCollectionIsNotFound);
+
+    p.platform_try_update_nft_perk(
+        ProjectAccount::Admin,
+        ProjectNft::Gopniks,
+        Some(nft_perk("0.5", None)),
+    )?;
+    assert_that(&p.platform_query_nft_perks()?).is_equal_to(vec![NftPerksRespItem {
+        collection: ProjectNft::Gopniks.into(),
+        perk: nft_perk("0.5", None),
+    }]);
+
+    // token 4 belongs to Bob
+    let res = p
+        .platform_try_flip_with_nft(
+            ProjectAccount::Alice,
+            Side::Head,
+            AMOUNT,
+            ProjectCoin::Om,
+            ProjectNft::Gopniks,
+            4,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::NftIsNotOwned);
+
+    p.platform_try_update_nft_perk(ProjectAccount::Admin, ProjectNft::Gopniks, None)?;
+    assert_that(&p.platform_query_nft_perks()?).is_empty();
+
+    Ok(())
+}
+
+#[test]
+fn higher_max_bet() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        None,
+    )?;
+    p.platform_try_update_nft_perk(
+        ProjectAccount::Admin,
+        ProjectNft::Pinjeons,
+        Some(nft_perk("0", Some(5 * AMOUNT))),
+    )?;
+
+    let res = p
+        .platform_try_flip(
+            ProjectAccount::Alice,
+            Side::Head,
+            5 * AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+
+    let res = p.platform_try_flip_with_nft(
+        ProjectAccount::Alice,
+        Side::Head,
+        5 * AMOUNT,
+        ProjectCoin::Om,
+        ProjectNft::Pinjeons,
+        2,
+    )?;
+    assert_that(&res.events.iter().any(|x| {
+        x.attributes
+            .iter()
+            .any(|y| y.key == "nft_token_id" && y.value == "2")
+    }))
+    .is_true();
+    p.wait(FLIP_COOLDOWN);
+
+    // perks follow the NFT after transfer
+    p.transfer_nft(
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        ProjectNft::Pinjeons,
+        2,
+    );
+
+    let res = p
+        .platform_try_flip_with_nft(
+            ProjectAccount::Alice,
+            Side::Head,
+            5 * AMOUNT,
+            ProjectCoin::Om,
+            ProjectNft::Pinjeons,
+            2,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::NftIsNotOwned);
+
+    p.platform_try_flip_with_nft(
+        ProjectAccount::Bob,
+        Side::Head,
+        5 * AMOUNT,
+        ProjectCoin::Om,
+        ProjectNft::Pinjeons,
+        2,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn fee_discount() -> StdResult<()> {
+    const ROUNDS: u32 = 20;
+
+    let mut p = Project::new();
+
+    // the user can't win without discount
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        Some("1"),
+    )?;
+    p.platform_try_update_nft_perk(
+        ProjectAccount::Admin,
+        ProjectNft::Gopniks,
+        Some(nft_perk("1", None)),
+    )?;
+
+    for _ in 0..ROUNDS {
+        p.platform_try_flip(ProjectAccount::Alice, Side::Head, AMOUNT, ProjectCoin::Om)?;
+        p.wait(FLIP_COOLDOWN);
+    }
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .wins
+            .count,
+    )
+    .is_equal_to(0);
+
+    for _ in 0..ROUNDS {
+        p.platform_try_flip_with_nft(
+            ProjectAccount::Alice,
+            Side::Head,
+            AMOUNT,
+            ProjectCoin::Om,
+            ProjectNft::Gopniks,
+            1,
+        )?;
+        p.wait(FLIP_COOLDOWN);
+    }
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .wins
+            .count,
+    )
+    .is_greater_than(0);
+
+    Ok(())
+}
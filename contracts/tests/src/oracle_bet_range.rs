@@ -0,0 +1,221 @@
+use cosmwasm_std::{Addr, Decimal, StdResult, Uint128};
+use cw_multi_test::Executor;
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::types::{Range, Side, Tier},
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    mocks::oracle,
+    platform::PlatformExtension,
+    suite::{
+        codes::WithCodes,
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const MAX_AGE: u64 = 600;
+/// OM price in the reference currency
+const PRICE: &str = "0.5";
+
+fn usd_bet() -> Range {
+    Range::new(AMOUNT / 2, 5 * AMOUNT / 2)
+}
+
+fn static_bet() -> Range {
+    Range::new(AMOUNT, 10 * AMOUNT)
+}
+
+fn prepare_project() -> StdResult<(Project, Addr)> {
+    let mut p = Project::with_bet_range(None, static_bet())?;
+    let oracle_address = p.instantiate_oracle(p.get_oracle_code_id(), Some(PRICE));
+
+    Ok((p, oracle_address))
+}
+
+fn set_price(p: &mut Project, oracle_address: &Addr, price: Option<&str>) {
+    p.app
+        .execute_contract(
+            ProjectAccount::Admin.into(),
+            oracle_address.clone(),
+            &oracle::ExecuteMsg::SetPrice {
+                price: price.map(str_to_dec),
+            },
+            &[],
+        )
+        .unwrap();
+}
+
+#[test]
+fn update_oracle_validation() -> StdResult<()> {
+    let (mut p, oracle_address) = prepare_project()?;
+
+    let res = p
+        .platform_try_update_oracle(
+            ProjectAccount::Alice,
+            Some((&oracle_address, usd_bet(), MAX_AGE)),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_oracle(
+            ProjectAccount::Admin,
+            Some((&oracle_address, Range::new(AMOUNT, AMOUNT / 2), MAX_AGE)),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperMinBet);
+
+    let res = p
+        .platform_try_update_oracle(
+            ProjectAccount::Admin,
+            Some((&oracle_address, Range::new(0u128, 0u128), MAX_AGE)),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroMaxBet);
+
+    let res = p
+        .platform_try_update_oracle(ProjectAccount::Admin, Some((&oracle_address, usd_bet(), 0)))
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperOracleMaxAge);
+
+    p.platform_try_update_oracle(
+        ProjectAccount::Admin,
+        Some((&oracle_address, usd_bet(), MAX_AGE)),
+    )?;
+    let oracle = p.platform_query_oracle()?.unwrap();
+    assert_that(&oracle.contract).is_equal_to(oracle_address.to_string());
+    assert_that(&oracle.bet).is_equal_to(usd_bet());
+
+    p.platform_try_update_oracle(ProjectAccount::Admin, None)?;
+    assert_that(&p.platform_query_oracle()?).is_none();
+    assert_that(&p.platform_query_bet_range()?.bet).is_equal_to(static_bet());
+
+    Ok(())
+}
+
+#[test]
+fn bet_range_follows_oracle_price() -> StdResult<()> {
+    let (mut p, oracle_address) = prepare_project()?;
+    p.platform_try_update_oracle(
+        ProjectAccount::Admin,
+        Some((&oracle_address, usd_bet(), MAX_AGE)),
+    )?;
+
+    let bet_range = p.platform_query_bet_range()?;
+    assert_that(&bet_range.bet).is_equal_to(Range::new(AMOUNT, 5 * AMOUNT));
+    assert_that(&bet_range.price).is_equal_to(Some(str_to_dec(PRICE)));
+
+    // the static range allows the bet, the converted one doesn't
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, SIDE, 6 * AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 5 * AMOUNT, ProjectCoin::Om)?;
+
+    // OM is twice as expensive, the range is twice as small
+    set_price(&mut p, &oracle_address, Some("1"));
+    p.wait(BLOCK);
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, SIDE, 3 * AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+
+    let bet = Range::new(AMOUNT / 2, 5 * AMOUNT / 2);
+    assert_that(&p.platform_query_bet_range()?.bet).is_equal_to(&bet);
+    assert_that(&p.platform_query_user_tier(ProjectAccount::Alice)?.tier.bet).is_equal_to(bet);
+
+    Ok(())
+}
+
+#[test]
+fn bet_range_falls_back_to_static() -> StdResult<()> {
+    let (mut p, oracle_address) = prepare_project()?;
+    p.platform_try_update_oracle(
+        ProjectAccount::Admin,
+        Some((&oracle_address, usd_bet(), MAX_AGE)),
+    )?;
+
+    // the price is stale
+    p.wait(MAX_AGE + 1);
+    let bet_range = p.platform_query_bet_range()?;
+    assert_that(&bet_range.bet).is_equal_to(static_bet());
+    assert_that(&bet_range.price).is_none();
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 10 * AMOUNT, ProjectCoin::Om)?;
+
+    set_price(&mut p, &oracle_address, Some(PRICE));
+    assert_that(&p.platform_query_bet_range()?.price).is_some();
+
+    // the oracle fails
+    set_price(&mut p, &oracle_address, None);
+    p.wait(BLOCK);
+    assert_that(&p.platform_query_bet_range()?.bet).is_equal_to(static_bet());
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 10 * AMOUNT, ProjectCoin::Om)?;
+
+    // zero price is ignored
+    set_price(&mut p, &oracle_address, Some("0"));
+    assert_that(&p.platform_query_bet_range()?.price).is_none();
+
+    Ok(())
+}
+
+#[test]
+fn oracle_range_bounds_tiers() -> StdResult<()> {
+    let (mut p, oracle_address) = prepare_project()?;
+    p.platform_try_update_tiers(
+        ProjectAccount::Admin,
+        vec![
+            Tier {
+                threshold: Uint128::zero(),
+                bet: static_bet(),
+                fee_discount: Decimal::zero(),
+                cashback: None,
+            },
+            Tier {
+                threshold: Uint128::new(100 * AMOUNT),
+                bet: Range::new(2 * AMOUNT, 20 * AMOUNT),
+                fee_discount: Decimal::zero(),
+                cashback: None,
+            },
+        ],
+    )?;
+    p.platform_try_update_oracle(
+        ProjectAccount::Admin,
+        Some((&oracle_address, usd_bet(), MAX_AGE)),
+    )?;
+
+    // the tier range is limited by the converted range
+    let bet = Range::new(AMOUNT, 5 * AMOUNT);
+    assert_that(&p.platform_query_user_tier(ProjectAccount::Alice)?.tier.bet).is_equal_to(&bet);
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, SIDE, 6 * AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 5 * AMOUNT, ProjectCoin::Om)?;
+
+    // the higher tier keeps its min bet within the converted range
+    p.platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Alice, Some(1))?;
+    assert_that(&p.platform_query_user_tier(ProjectAccount::Alice)?.tier.bet)
+        .is_equal_to(Range::new(2 * AMOUNT, 5 * AMOUNT));
+    p.wait(BLOCK);
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, SIDE, 10 * AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+
+    // the tier range is used as is if the price is stale
+    p.wait(MAX_AGE + 1);
+    assert_that(&p.platform_query_user_tier(ProjectAccount::Alice)?.tier.bet)
+        .is_equal_to(Range::new(2 * AMOUNT, 20 * AMOUNT));
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 10 * AMOUNT, ProjectCoin::Om)?;
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+use cosmwasm_std::{Addr, StdResult, Uint128};
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::{
+        state::{PAYOUT_QUEUE, PAYOUT_QUEUE_ID, PAYOUT_QUEUE_IDS, PAYOUT_QUEUE_LENGTH},
+        types::{Range, Side, Tier},
+    },
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const DELAY: u64 = 3;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+
+#[test]
+fn payout_queue_fifo() -> StdResult<()> {
+    let mut p = Project::new();
+
+    // users always lose with 100 % platform fee and get full cashback
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        Some("1"),
+    )?;
+    p.platform_try_update_tiers(
+        ProjectAccount::Admin,
+        vec![Tier {
+            threshold: Uint128::zero(),
+            bet: Range::new(AMOUNT, AMOUNT),
+            fee_discount: str_to_dec("0"),
+            cashback: Some(str_to_dec("1")),
+        }],
+    )?;
+
+    let res = p
+        .platform_try_process_payouts(ProjectAccount::Alice, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::PayoutQueueIsEmpty);
+
+    for user in [
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        ProjectAccount::John,
+    ] {
+        p.platform_try_flip(user, SIDE, AMOUNT, ProjectCoin::Om)?;
+    }
+
+    for (position, user) in [
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        ProjectAccount::John,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let resp = p.platform_query_payout_queue_position(user)?;
+        assert_that(&resp.position).is_equal_to(Some(position as u32 + 1));
+        assert_that(&resp.length).is_equal_to(3);
+        assert_that(&resp.unclaimed.u128()).is_equal_to(AMOUNT);
+    }
+
+    // claiming leaves the queue, the position is the distance from the head
+    p.platform_try_claim(ProjectAccount::Bob)?;
+    let resp = p.platform_query_payout_queue_position(ProjectAccount::Bob)?;
+    assert_that(&resp.position).is_none();
+    assert_that(&resp.length).is_equal_to(2);
+    assert_that(
+        &p.platform_query_payout_queue_position(ProjectAccount::John)?
+            .position,
+    )
+    .is_equal_to(Some(3));
+
+    // anyone can pay the queue head
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_process_payouts(ProjectAccount::Kate, 1)?;
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after - alice_balance_before)).is_equal_to(AMOUNT);
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .unclaimed
+            .u128(),
+    )
+    .is_equal_to(0);
+    assert_that(
+        &p.platform_query_payout_queue_position(ProjectAccount::John)?
+            .position,
+    )
+    .is_equal_to(Some(1));
+
+    // a new reward is queued at the end
+    p.wait(DELAY);
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    let resp = p.platform_query_payout_queue_position(ProjectAccount::Alice)?;
+    assert_that(&resp.position).is_equal_to(Some(2));
+    assert_that(&resp.length).is_equal_to(2);
+
+    // the deposit pays the rest
+    let john_balance_before = p.query_balance(ProjectAccount::John, &ProjectCoin::Om)?;
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
+    let john_balance_after = p.query_balance(ProjectAccount::John, &ProjectCoin::Om)?;
+    assert_that(&(john_balance_after - john_balance_before)).is_equal_to(AMOUNT);
+    assert_that(
+        &p.platform_query_payout_queue_position(ProjectAccount::Alice)?
+            .length,
+    )
+    .is_equal_to(0);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(0);
+    assert_that(&app_info.balance.u128()).is_equal_to(AMOUNT);
+
+    let res = p
+        .platform_try_process_payouts(ProjectAccount::Alice, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::PayoutQueueIsEmpty);
+
+    Ok(())
+}
+
+#[test]
+fn payout_queue_liquidity() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(0, AMOUNT)),
+        None,
+    )?;
+
+    // lose, win, win without enough liquidity
+    for _ in 0..3 {
+        p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+        p.wait(DELAY);
+    }
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(2 * AMOUNT);
+    assert_that(&app_info.balance.u128()).is_equal_to(AMOUNT);
+    assert_that(
+        &p.platform_query_payout_queue_position(ProjectAccount::Alice)?
+            .position,
+    )
+    .is_equal_to(Some(1));
+
+    let res = p
+        .platform_try_process_payouts(ProjectAccount::Bob, 10)
+        .unwrap_err();
+    assert_error(&res, ContractError::NotEnoughLiquidity);
+
+    // the queue isn't processed while paused
+    p.platform_try_pause(ProjectAccount::Admin)?;
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT / 2, ProjectCoin::Om)?;
+    let res = p
+        .platform_try_process_payouts(ProjectAccount::Bob, 10)
+        .unwrap_err();
+    assert_error(&res, ContractError::ContractIsPaused);
+    p.platform_try_unpause(ProjectAccount::Admin)?;
+
+    // the partial deposit isn't enough
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT / 4, ProjectCoin::Om)?;
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .unclaimed
+            .u128(),
+    )
+    .is_equal_to(2 * AMOUNT);
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT / 4, ProjectCoin::Om)?;
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after - alice_balance_before)).is_equal_to(2 * AMOUNT);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(0);
+    assert_that(&app_info.balance.u128()).is_equal_to(0);
+    assert_that(
+        &p.platform_query_payout_queue_position(ProjectAccount::Alice)?
+            .position,
+    )
+    .is_none();
+
+    Ok(())
+}
+
+#[test]
+fn payout_queue_backfill() -> StdResult<()> {
+    let mut p = Project::new();
+    let users = [
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        ProjectAccount::John,
+    ];
+
+    // users always lose with 100 % platform fee and get full cashback
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        Some("1"),
+    )?;
+    p.platform_try_update_tiers(
+        ProjectAccount::Admin,
+        vec![Tier {
+            threshold: Uint128::zero(),
+            bet: Range::new(AMOUNT, AMOUNT),
+            fee_discount: str_to_dec("0"),
+            cashback: Some(str_to_dec("1")),
+        }],
+    )?;
+
+    for user in users {
+        p.platform_try_flip(user, SIDE, AMOUNT, ProjectCoin::Om)?;
+    }
+
+    // rewards accrued before the payout queue was added
+    let platform_address = p.get_platform_address();
+    let mut storage = p.app.contract_storage_mut(&platform_address);
+    PAYOUT_QUEUE.clear(&mut *storage);
+    PAYOUT_QUEUE_IDS.clear(&mut *storage);
+    PAYOUT_QUEUE_ID.remove(&mut *storage);
+    PAYOUT_QUEUE_LENGTH.remove(&mut *storage);
+    drop(storage);
+    assert_that(
+        &p.platform_query_payout_queue_position(ProjectAccount::Alice)?
+            .length,
+    )
+    .is_equal_to(0);
+
+    let res = p
+        .platform_try_enqueue_payouts(ProjectAccount::Alice, None, 2)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    // users are scanned in address order
+    let mut address_list: Vec<_> = users.iter().map(|x| Addr::from(*x)).collect();
+    address_list.sort();
+
+    let res = p.platform_try_enqueue_payouts(ProjectAccount::Admin, None, 2)?;
+    assert_that(&get_attr(&res, "users")).is_equal_to(Some("2".to_string()));
+    assert_that(&get_attr(&res, "last")).is_equal_to(Some(address_list[1].to_string()));
+
+    let res = p.platform_try_enqueue_payouts(ProjectAccount::Admin, Some(&address_list[1]), 2)?;
+    assert_that(&get_attr(&res, "users")).is_equal_to(Some("1".to_string()));
+    assert_that(&get_attr(&res, "last")).is_equal_to(Some(address_list[2].to_string()));
+
+    let res = p.platform_try_enqueue_payouts(ProjectAccount::Admin, Some(&address_list[2]), 2)?;
+    assert_that(&get_attr(&res, "users")).is_equal_to(Some("0".to_string()));
+    assert_that(&get_attr(&res, "last")).is_none();
+
+    // queued users aren't added twice
+    let res = p.platform_try_enqueue_payouts(ProjectAccount::Admin, None, 10)?;
+    assert_that(&get_attr(&res, "users")).is_equal_to(Some("0".to_string()));
+
+    for (position, address) in address_list.iter().enumerate() {
+        let user = users
+            .into_iter()
+            .find(|x| Addr::from(*x) == address)
+            .unwrap();
+        let resp = p.platform_query_payout_queue_position(user)?;
+        assert_that(&resp.position).is_equal_to(Some(position as u32 + 1));
+        assert_that(&resp.length).is_equal_to(3);
+    }
+
+    Ok(())
+}
@@ -1,19 +1,22 @@
-use cosmwasm_std::{Int256, StdResult, Uint128};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Int256, SignedDecimal, StdError, StdResult, Uint128};
 use cw_multi_test::Executor;
+use cw_storage_plus::Item;
 
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use cf_base::{
-    error::ContractError,
+    error::{decode_err, ContractError},
     platform::{
         msg::MigrateMsg,
-        types::{AppInfo, Range, Side, Stats, StatsItem},
+        state::CONTRACT_NAME,
+        types::{AppInfo, Range, Revenue, Side, Stats, StatsItem},
     },
 };
 use speculoos::assert_that;
 
 use crate::helpers::{
-    platform::PlatformExtension,
+    platform::{get_event_attrs, to_attrs, PlatformExtension},
     suite::{
         core::{assert_error, Project},
         types::{ProjectAccount, ProjectCoin},
@@ -33,13 +36,76 @@ fn migrate_default() {
             ProjectAccount::Admin.into(),
             p.get_platform_address(),
             &MigrateMsg {
-                version: "1.0.0".to_string(),
+                version: "1.1.0".to_string(),
             },
             p.get_platform_code_id(),
         )
         .unwrap();
 }
 
+/// `AppInfo` saved by 1.0.0
+#[cw_serde]
+struct AppInfoV1 {
+    user_stats: Stats,
+    user_unclaimed: Uint128,
+    average_fee: SignedDecimal,
+    deposited: Uint128,
+    balance: Uint128,
+    revenue: Revenue,
+}
+
+#[test]
+fn migrate_from_v1_state() -> StdResult<()> {
+    const AMOUNT: u128 = 1_000;
+
+    let mut p = Project::new();
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        None,
+    )?;
+    p.platform_try_deposit(ProjectAccount::Admin, 100 * AMOUNT, ProjectCoin::Om)?;
+
+    let app_info = p.platform_query_app_info()?;
+    let platform_address = p.get_platform_address();
+    let mut storage = p.app.contract_storage_mut(&platform_address);
+    Item::new("app_info").save(
+        &mut *storage,
+        &AppInfoV1 {
+            user_stats: app_info.user_stats,
+            user_unclaimed: app_info.user_unclaimed,
+            average_fee: app_info.average_fee,
+            deposited: app_info.deposited,
+            balance: app_info.balance,
+            revenue: app_info.revenue,
+        },
+    )?;
+    cw2::set_contract_version(&mut *storage, CONTRACT_NAME, "1.0.0")?;
+    drop(storage);
+
+    p.app
+        .migrate_contract(
+            ProjectAccount::Admin.into(),
+            p.get_platform_address(),
+            &MigrateMsg {
+                version: "1.1.0".to_string(),
+            },
+            p.get_platform_code_id(),
+        )
+        .unwrap();
+
+    // the fields added after 1.0.0 are defaulted
+    p.platform_try_flip(ProjectAccount::Alice, Side::Head, AMOUNT, ProjectCoin::Om)?;
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_stats.bets.count).is_equal_to(1);
+    assert_that(&app_info.pending_bets).is_equal_to(Uint128::zero());
+    assert_that(&app_info.bonus_balances).is_equal_to(Uint128::zero());
+
+    Ok(())
+}
+
 #[test]
 fn guards() -> StdResult<()> {
     const SIDE: Side = Side::Head;
@@ -148,6 +214,169 @@ fn guards() -> StdResult<()> {
     Ok(())
 }
 
+#[test]
+fn error_codes() -> StdResult<()> {
+    const AMOUNT: u128 = 1_000;
+
+    let mut p = Project::new();
+
+    // contract errors
+    let res = p
+        .platform_try_update_config(
+            ProjectAccount::Alice,
+            None,
+            None,
+            Some(Range::new(0, AMOUNT)),
+            None,
+        )
+        .unwrap_err();
+    assert_that(&decode_err(&res.to_string())).is_equal_to(Some(ContractError::Unauthorized));
+    assert_that(&ContractError::Unauthorized.code()).is_equal_to(2);
+
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, Side::Head, AMOUNT, ProjectCoin::Om)
+        .unwrap_err();
+    assert_that(&decode_err(&res.to_string())).is_equal_to(Some(ContractError::BetIsOutOfRange));
+
+    // contract errors converted to StdError and back
+    let err = ContractError::ParameterIsNotFound {
+        value: "worker".to_string(),
+    };
+    let res: ContractError = StdError::from(err.clone()).into();
+    assert_that(&res).is_equal_to(&err);
+
+    // the tag injected before the encoded error is ignored
+    let err = ContractError::CustomError {
+        val: ContractError::Unauthorized.to_string(),
+    };
+    assert_that(&decode_err(&err.to_string())).is_equal_to(Some(err.clone()));
+    let res: ContractError = StdError::from(err.clone()).into();
+    assert_that(&res).is_equal_to(&err);
+
+    // the encoded error must end the message
+    let msg = format!("{} injected", ContractError::Unauthorized);
+    assert_that(&decode_err(&msg)).is_equal_to(None);
+
+    // std errors
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, Side::Head, 0, ProjectCoin::Om)
+        .unwrap_err();
+    assert_that(&decode_err(&res.to_string())).is_equal_to(None);
+
+    Ok(())
+}
+
+#[test]
+fn events() -> StdResult<()> {
+    const DELAY: u64 = 3;
+    const SIDE: Side = Side::Head;
+    const AMOUNT: u128 = 1_000;
+
+    let mut p = Project::new();
+    let alice = &ProjectAccount::Alice.to_string();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(0, AMOUNT)),
+        None,
+    )?;
+    let res = p.platform_try_deposit(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
+    assert_that(&get_event_attrs(&res)).is_equal_to(to_attrs(&[
+        ("action", "deposit"),
+        ("sender", &ProjectAccount::Admin.to_string()),
+        ("amount", "1000"),
+        ("balance", "1000"),
+        ("deposited", "1000"),
+        ("revenue_total", "0"),
+        ("revenue_current", "0"),
+        ("user_unclaimed", "0"),
+        ("insurance", "0"),
+        ("pending_bets", "0"),
+        ("user_balances", "0"),
+        ("bonus_balances", "0"),
+    ]));
+
+    // lose
+    let res = p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    let block_time = p.get_block_time().to_string();
+    assert_that(&get_event_attrs(&res)).is_equal_to(to_attrs(&[
+        ("action", "flip"),
+        ("user", alice),
+        ("side", "head"),
+        ("amount", "1000"),
+        ("random_weight", "0.690849493452539158"),
+        ("outcome", "lose"),
+        ("payout", "none"),
+        ("prize", "0"),
+        ("unclaimed", "0"),
+        ("last_flip_date", &block_time),
+        ("balance", "2000"),
+        ("deposited", "1000"),
+        ("revenue_total", "1000"),
+        ("revenue_current", "1000"),
+        ("user_unclaimed", "0"),
+        ("insurance", "0"),
+        ("pending_bets", "0"),
+        ("user_balances", "0"),
+        ("bonus_balances", "0"),
+    ]));
+
+    // win, auto paid
+    p.wait(DELAY);
+    let res = p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    let attrs = get_event_attrs(&res);
+    assert_that(&attrs[5..9].to_vec()).is_equal_to(to_attrs(&[
+        ("outcome", "win"),
+        ("payout", "auto_paid"),
+        ("prize", "2000"),
+        ("unclaimed", "0"),
+    ]));
+
+    // win, unclaimed
+    p.wait(DELAY);
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    p.wait(DELAY);
+    let res = p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
+    let attrs = get_event_attrs(&res);
+    assert_that(&attrs[5..9].to_vec()).is_equal_to(to_attrs(&[
+        ("outcome", "win"),
+        ("payout", "unclaimed"),
+        ("prize", "2000"),
+        ("unclaimed", "2000"),
+    ]));
+    assert_that(&attrs[14]).is_equal_to(("user_unclaimed".to_string(), "2000".to_string()));
+
+    // deposit, the payout queue is paid
+    let res = p.platform_try_deposit(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
+    assert_that(&get_event_attrs(&res)).is_equal_to(to_attrs(&[
+        ("action", "deposit"),
+        ("sender", &ProjectAccount::Admin.to_string()),
+        ("amount", "1000"),
+        ("payouts", "1"),
+        ("payout_0", &format!("{}:2000", alice)),
+        ("balance", "0"),
+        ("deposited", "2000"),
+        ("revenue_total", "-2000"),
+        ("revenue_current", "-2000"),
+        ("user_unclaimed", "0"),
+        ("insurance", "0"),
+        ("pending_bets", "0"),
+        ("user_balances", "0"),
+        ("bonus_balances", "0"),
+    ]));
+
+    // admin actions
+    let res = p.platform_try_pause(ProjectAccount::Admin)?;
+    assert_that(&get_event_attrs(&res)).is_equal_to(to_attrs(&[
+        ("action", "pause"),
+        ("sender", &ProjectAccount::Admin.to_string()),
+    ]));
+
+    Ok(())
+}
+
 #[test]
 fn ns_matters_no_delay() -> StdResult<()> {
     const ROUNDS: u16 = 1_000;
@@ -856,8 +1085,8 @@ fn win_win_lose_deposit_claim() -> StdResult<()> {
     p.wait(1);
     p.platform_try_flip(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om)?;
 
+    // the deposit pays the payout queue
     p.platform_try_deposit(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
-    p.platform_try_claim(ProjectAccount::Alice)?;
 
     let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
     let platform_balance_after = p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?;
@@ -970,6 +1199,11 @@ fn balance_manipulations() -> StdResult<()> {
     let AppInfo {
         user_stats,
         user_unclaimed,
+        user_rakeback,
+        insurance,
+        pending_bets,
+        user_balances,
+        bonus_balances,
         average_fee,
         deposited,
         balance,
@@ -983,6 +1217,11 @@ fn balance_manipulations() -> StdResult<()> {
     assert_that(&user_stats.wins.count).is_equal_to(87);
     assert_that(&user_stats.wins.value.u128()).is_equal_to(174_000);
     assert_that(&user_unclaimed.u128()).is_equal_to(10_000);
+    assert_that(&user_rakeback.u128()).is_equal_to(0);
+    assert_that(&insurance.u128()).is_equal_to(0);
+    assert_that(&pending_bets.u128()).is_equal_to(0);
+    assert_that(&user_balances.u128()).is_equal_to(0);
+    assert_that(&bonus_balances.u128()).is_equal_to(0);
     assert_that(&average_fee.to_string().as_str()).is_equal_to("0.13");
     assert_that(&deposited.u128()).is_equal_to(0);
     assert_that(&balance.u128()).is_equal_to(36_000);
@@ -1000,9 +1239,10 @@ fn balance_manipulations() -> StdResult<()> {
         ..
     } = p.platform_query_app_info()?;
 
-    assert_that(&user_unclaimed.u128()).is_equal_to(10_000);
+    // the deposit pays the payout queue
+    assert_that(&user_unclaimed.u128()).is_equal_to(0);
     assert_that(&deposited.u128()).is_equal_to(1_000);
-    assert_that(&balance.u128()).is_equal_to(11_000);
+    assert_that(&balance.u128()).is_equal_to(1_000);
     assert_that(&revenue.total).is_equal_to(Int256::from(26_000));
     assert_that(&revenue.current).is_equal_to(Int256::from(0));
 
@@ -1017,6 +1257,11 @@ fn balance_manipulations() -> StdResult<()> {
     let AppInfo {
         user_stats,
         user_unclaimed,
+        user_rakeback,
+        insurance,
+        pending_bets,
+        user_balances,
+        bonus_balances,
         average_fee,
         deposited,
         balance,
@@ -1025,6 +1270,11 @@ fn balance_manipulations() -> StdResult<()> {
 
     assert_that(&user_stats.wins.value.u128()).is_equal_to(360_000);
     assert_that(&user_unclaimed.u128()).is_equal_to(0);
+    assert_that(&user_rakeback.u128()).is_equal_to(0);
+    assert_that(&insurance.u128()).is_equal_to(0);
+    assert_that(&pending_bets.u128()).is_equal_to(0);
+    assert_that(&user_balances.u128()).is_equal_to(0);
+    assert_that(&bonus_balances.u128()).is_equal_to(0);
     assert_that(&average_fee.to_string().as_str()).is_equal_to("0.1");
     assert_that(&deposited.u128()).is_equal_to(1_000);
     assert_that(&balance.u128()).is_equal_to(15_000);
@@ -0,0 +1,231 @@
+use cosmwasm_std::{Int256, StdResult, Uint128};
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::{
+        msg::UserRakebackResp,
+        state::FLIP_COOLDOWN,
+        types::{RakebackConfig, Range, Side},
+    },
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const EPOCH: u64 = 100;
+const AMOUNT: u128 = 1_000;
+
+fn rakeback_config() -> RakebackConfig {
+    RakebackConfig {
+        rate: str_to_dec("0.1"),
+        epoch: EPOCH,
+    }
+}
+
+/// The user always loses with 100 % platform fee
+fn get_project() -> StdResult<Project> {
+    let mut p = Project::with_losing_bets(None, AMOUNT)?;
+
+    p.platform_try_update_rakeback(ProjectAccount::Admin, Some(rakeback_config()))?;
+
+    Ok(p)
+}
+
+fn wait_next_epoch(p: &mut Project) {
+    p.wait(EPOCH - p.get_block_time() % EPOCH);
+}
+
+#[test]
+fn rakeback_guards() -> StdResult<()> {
+    let mut p = Project::new();
+
+    let res = p
+        .platform_try_update_rakeback(ProjectAccount::Alice, Some(rakeback_config()))
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_rakeback(
+            ProjectAccount::Admin,
+            Some(RakebackConfig {
+                rate: str_to_dec("1.5"),
+                epoch: EPOCH,
+            }),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::RakebackRateIsOutOfRange);
+
+    let res = p
+        .platform_try_update_rakeback(
+            ProjectAccount::Admin,
+            Some(RakebackConfig {
+                epoch: 0,
+                ..rakeback_config()
+            }),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroRakebackEpoch);
+
+    let res = p
+        .platform_try_claim_rakeback(ProjectAccount::Alice)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroRewardsAmount);
+
+    assert_that(&p.platform_query_rakeback()?).is_equal_to(None);
+    p.platform_try_update_rakeback(ProjectAccount::Admin, Some(rakeback_config()))?;
+    assert_that(&p.platform_query_rakeback()?).is_equal_to(Some(rakeback_config()));
+
+    Ok(())
+}
+
+#[test]
+fn history_before_enabling_is_ignored() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        Some("1"),
+    )?;
+    p.flip_rounds(AMOUNT, 5)?;
+
+    p.platform_try_update_rakeback(ProjectAccount::Admin, Some(rakeback_config()))?;
+    wait_next_epoch(&mut p);
+    p.flip_rounds(AMOUNT, 1)?;
+    wait_next_epoch(&mut p);
+
+    let UserRakebackResp { accrued, .. } = p.platform_query_user_rakeback(ProjectAccount::Alice)?;
+    assert_that(&accrued.u128()).is_equal_to(AMOUNT / 10);
+
+    Ok(())
+}
+
+#[test]
+fn rakeback_accrual_and_claim() -> StdResult<()> {
+    const ROUNDS: u32 = 5;
+    const RAKEBACK: u128 = ROUNDS as u128 * AMOUNT / 10;
+
+    let mut p = get_project()?;
+
+    wait_next_epoch(&mut p);
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+
+    let UserRakebackResp {
+        epoch,
+        accrued,
+        pending,
+    } = p.platform_query_user_rakeback(ProjectAccount::Alice)?;
+    assert_that(&epoch).is_equal_to(p.get_block_time() / EPOCH);
+    assert_that(&accrued.u128()).is_equal_to(0);
+    assert_that(&pending.u128()).is_equal_to(RAKEBACK);
+
+    // the epoch is finished but isn't settled yet
+    wait_next_epoch(&mut p);
+    let UserRakebackResp {
+        accrued, pending, ..
+    } = p.platform_query_user_rakeback(ProjectAccount::Alice)?;
+    assert_that(&accrued.u128()).is_equal_to(RAKEBACK);
+    assert_that(&pending.u128()).is_equal_to(0);
+
+    // the claim settles the epoch
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_claim_rakeback(ProjectAccount::Alice)?;
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after - alice_balance_before)).is_equal_to(RAKEBACK);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_rakeback.u128()).is_equal_to(0);
+    assert_that(&app_info.balance.u128()).is_equal_to(ROUNDS as u128 * AMOUNT - RAKEBACK);
+    assert_that(&app_info.revenue.current)
+        .is_equal_to(Int256::from(ROUNDS as u128 * AMOUNT - RAKEBACK));
+
+    let res = p
+        .platform_try_claim_rakeback(ProjectAccount::Alice)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroRewardsAmount);
+
+    // the flip settles the epoch, accrued rakeback can't be withdrawn
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+    wait_next_epoch(&mut p);
+    let available_to_withdraw = p.platform_query_available_to_withdraw()?;
+
+    let res = p.platform_try_flip(ProjectAccount::Alice, Side::Head, AMOUNT, ProjectCoin::Om)?;
+    assert_that(&res.events.iter().any(|x| {
+        x.attributes
+            .iter()
+            .any(|y| y.key == "rakeback" && y.value == RAKEBACK.to_string())
+    }))
+    .is_true();
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_rakeback.u128()).is_equal_to(RAKEBACK);
+    assert_that(&p.platform_query_available_to_withdraw()?)
+        .is_equal_to(available_to_withdraw + Uint128::new(AMOUNT - RAKEBACK));
+
+    p.platform_try_withdraw(ProjectAccount::Admin, None, None)?;
+    assert_that(&p.platform_query_required_to_deposit()?).is_equal_to(Uint128::zero());
+    p.platform_try_claim_rakeback(ProjectAccount::Alice)?;
+
+    Ok(())
+}
+
+#[test]
+fn rakeback_claim_does_not_use_unclaimed_winnings() -> StdResult<()> {
+    const ROUNDS: u32 = 5;
+    const RAKEBACK: u128 = ROUNDS as u128 * AMOUNT / 10;
+
+    let mut p = get_project()?;
+
+    // the rakeback of finished epoch is settled by the next flip
+    wait_next_epoch(&mut p);
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+    wait_next_epoch(&mut p);
+    p.flip_rounds(AMOUNT, 1)?;
+    assert_that(&p.platform_query_app_info()?.user_rakeback.u128()).is_equal_to(RAKEBACK);
+
+    // the revenue is withdrawn, bob's prize isn't covered and is added to unclaimed
+    p.platform_try_withdraw(ProjectAccount::Admin, None, None)?;
+    p.platform_try_update_config(ProjectAccount::Admin, None, None, None, Some("0"))?;
+    loop {
+        let res = p.platform_try_flip(ProjectAccount::Bob, Side::Head, AMOUNT, ProjectCoin::Om)?;
+        p.wait(FLIP_COOLDOWN);
+
+        if get_attr(&res, "payout") == Some("unclaimed".to_string()) {
+            break;
+        }
+
+        p.platform_try_withdraw(ProjectAccount::Admin, None, None)?;
+    }
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(2 * AMOUNT);
+    assert_that(&app_info.balance.u128()).is_less_than(app_info.user_unclaimed.u128());
+
+    // the claim can't take the funds owed to bob
+    let res = p
+        .platform_try_claim_rakeback(ProjectAccount::Alice)
+        .unwrap_err();
+    assert_error(&res, ContractError::NotEnoughLiquidity);
+
+    // the deposit pays bob first, then the rakeback is claimed
+    p.platform_try_deposit(ProjectAccount::Admin, AMOUNT, ProjectCoin::Om)?;
+    assert_that(&p.platform_query_app_info()?.user_unclaimed.u128()).is_equal_to(0);
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_claim_rakeback(ProjectAccount::Alice)?;
+    assert_that(
+        &(p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)? - alice_balance_before),
+    )
+    .is_equal_to(RAKEBACK);
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use cosmwasm_std::{
+    testing::{mock_env, MockApi},
+    Addr, Decimal, Env, Timestamp,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use cf_base::{
+    converters::{hash_bytes_to_norm_dec, str_to_dec},
+    platform::state::{FLIP_COOLDOWN, SEED},
+};
+use platform::helpers::get_random_weight;
+
+use crate::helpers::stats::{self, TestResult};
+
+/// Default significance level, can be set by `RANDOMNESS_SIGNIFICANCE_LEVEL` env var
+const SIGNIFICANCE_LEVEL: f64 = 0.001;
+/// Default sample size for each scenario, can be set by `RANDOMNESS_SAMPLE_SIZE` env var
+const SAMPLE_SIZE: usize = 20_000;
+const BINS: usize = 100;
+const INITIAL_TIME_NS: u64 = 1_737_999_612_123_456_789;
+
+fn get_significance_level() -> f64 {
+    std::env::var("RANDOMNESS_SIGNIFICANCE_LEVEL")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(SIGNIFICANCE_LEVEL)
+}
+
+fn get_sample_size() -> usize {
+    std::env::var("RANDOMNESS_SAMPLE_SIZE")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(SAMPLE_SIZE)
+}
+
+fn get_env(time_ns: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_nanos(time_ns);
+    env
+}
+
+fn get_address(id: usize) -> Addr {
+    MockApi::default().addr_make(&format!("user{}", id))
+}
+
+fn dec_to_f64(dec: Decimal) -> f64 {
+    dec.to_string().parse().unwrap()
+}
+
+#[track_caller]
+fn assert_uniform(sample: &[f64], significance_level: f64) {
+    let failed_list: Vec<TestResult> = stats::run_all(sample, BINS)
+        .into_iter()
+        .filter(|x| !x.is_passed(significance_level))
+        .collect();
+
+    assert!(
+        failed_list.is_empty(),
+        "bias is detected at significance level {}: {:#?}",
+        significance_level,
+        failed_list
+    );
+}
+
+#[test]
+fn detectors_catch_bias() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let sample: Vec<f64> = (0..SAMPLE_SIZE).map(|_| rng.gen()).collect();
+    assert_uniform(&sample, SIGNIFICANCE_LEVEL);
+
+    // skewed distribution
+    let skewed: Vec<f64> = sample.iter().map(|x| x.powf(1.05)).collect();
+    assert!(!stats::chi_square(&skewed, BINS).is_passed(SIGNIFICANCE_LEVEL));
+    assert!(!stats::kolmogorov_smirnov(&skewed).is_passed(SIGNIFICANCE_LEVEL));
+
+    // uniform but sticky values
+    let sticky: Vec<f64> = sample
+        .iter()
+        .enumerate()
+        .map(|(i, x)| if i % 4 == 0 { *x } else { sample[i - i % 4] })
+        .collect();
+    assert!(!stats::runs(&sticky).is_passed(SIGNIFICANCE_LEVEL));
+    assert!(!stats::serial_correlation(&sticky).is_passed(SIGNIFICANCE_LEVEL));
+
+    // each value depends on the previous one
+    let correlated: Vec<f64> = sample
+        .windows(2)
+        .map(|x| (0.8 * x[0] + 0.2 * x[1]).fract())
+        .collect();
+    assert!(!stats::serial_correlation(&correlated).is_passed(SIGNIFICANCE_LEVEL));
+}
+
+#[test]
+fn hash_mapping_is_uniform() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let sample: Vec<f64> = (0..10 * get_sample_size())
+        .map(|_| dec_to_f64(hash_bytes_to_norm_dec(&rng.gen())))
+        .collect();
+
+    assert_uniform(&sample, get_significance_level());
+}
+
+#[test]
+fn varied_block_nanos() {
+    let address = get_address(0);
+    let previous_weight = str_to_dec(SEED);
+
+    let sample: Vec<f64> = (0..get_sample_size() as u64)
+        .map(|i| {
+            dec_to_f64(
+                get_random_weight(&get_env(INITIAL_TIME_NS + i), &address, &previous_weight)
+                    .unwrap(),
+            )
+        })
+        .collect();
+
+    assert_uniform(&sample, get_significance_level());
+}
+
+#[test]
+fn varied_addresses() {
+    let env = get_env(INITIAL_TIME_NS);
+    let previous_weight = str_to_dec(SEED);
+
+    let sample: Vec<f64> = (0..get_sample_size())
+        .map(|i| dec_to_f64(get_random_weight(&env, &get_address(i), &previous_weight).unwrap()))
+        .collect();
+
+    assert_uniform(&sample, get_significance_level());
+}
+
+#[test]
+fn chained_normalized_decimal() {
+    const USERS: usize = 10;
+
+    // the same as sequential flips: each weight is used as seed for the next one
+    let mut previous_weight = str_to_dec(SEED);
+    let mut time_ns = INITIAL_TIME_NS;
+    let mut sample: Vec<f64> = vec![];
+
+    for i in 0..get_sample_size() {
+        let weight =
+            get_random_weight(&get_env(time_ns), &get_address(i % USERS), &previous_weight)
+                .unwrap();
+
+        sample.push(dec_to_f64(weight));
+        previous_weight = weight;
+        time_ns += FLIP_COOLDOWN * 1_000_000_000;
+    }
+
+    assert_uniform(&sample, get_significance_level());
+}
@@ -0,0 +1,329 @@
+use cosmwasm_std::{Addr, HexBinary, Int256, StdResult, Uint128};
+
+use cf_base::{
+    error::ContractError,
+    platform::{msg::CallbackMsg, state::REVEAL_TIMEOUT},
+};
+use hashing_helper::base::{calc_seed_chain, calc_sha256, ENC_KEY_LEN};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    mocks::receiver::FAILURE_MSG,
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        codes::WithCodes,
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const FEE: u128 = 100;
+const JOB_ID: &str = "raffle-42";
+const MSG: &[u8] = b"draw";
+
+fn prepare_project() -> StdResult<(Project, Addr)> {
+    let mut p = Project::new();
+
+    p.platform_try_update_randomness_fee(ProjectAccount::Admin, Some(FEE))?;
+    let receiver_address = p.instantiate_receiver(p.get_receiver_code_id());
+
+    Ok((p, receiver_address))
+}
+
+#[test]
+fn request_randomness_validation() -> StdResult<()> {
+    let mut p = Project::new();
+    let receiver_address = p.instantiate_receiver(p.get_receiver_code_id());
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver_address]))?;
+
+    let res = p
+        .platform_try_request_randomness(
+            ProjectAccount::Alice,
+            JOB_ID,
+            &receiver_address,
+            MSG,
+            false,
+            FEE,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::RandomnessIsDisabled);
+
+    let res = p
+        .platform_try_update_randomness_fee(ProjectAccount::Admin, Some(0))
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    let res = p
+        .platform_try_update_randomness_fee(ProjectAccount::Alice, Some(FEE))
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    p.platform_try_update_randomness_fee(ProjectAccount::Admin, Some(FEE))?;
+    assert_that(&p.platform_query_randomness_fee()?).is_equal_to(Some(Uint128::new(FEE)));
+
+    let res = p
+        .platform_try_request_randomness(
+            ProjectAccount::Alice,
+            JOB_ID,
+            &receiver_address,
+            MSG,
+            false,
+            FEE - 1,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperRandomnessFee);
+
+    let res = p
+        .platform_try_request_randomness(
+            ProjectAccount::Alice,
+            "",
+            &receiver_address,
+            MSG,
+            false,
+            FEE,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperJobId);
+
+    // deferred requests require the seed chain
+    let res = p
+        .platform_try_request_randomness(
+            ProjectAccount::Alice,
+            JOB_ID,
+            &receiver_address,
+            MSG,
+            true,
+            FEE,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedChainIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn immediate_randomness_is_delivered() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+
+    let res = p
+        .platform_try_request_randomness(
+            ProjectAccount::Alice,
+            JOB_ID,
+            &receiver_address,
+            MSG,
+            false,
+            FEE,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::CallbackIsNotAllowed);
+
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver_address]))?;
+    let res = p.platform_try_request_randomness(
+        ProjectAccount::Alice,
+        JOB_ID,
+        &receiver_address,
+        MSG,
+        false,
+        FEE,
+        ProjectCoin::Om,
+    )?;
+    let randomness = HexBinary::from_hex(&get_attr(&res, "randomness").unwrap())?;
+    assert_that(&randomness.len()).is_equal_to(ENC_KEY_LEN);
+    assert_that(&p.query_callbacks(&receiver_address)?).is_equal_to(vec![
+        CallbackMsg::RandomnessCallback {
+            request_id: 0,
+            job_id: JOB_ID.to_string(),
+            randomness: randomness.clone(),
+            msg: MSG.into(),
+        },
+    ]);
+
+    // the fee is the revenue
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.balance).is_equal_to(Uint128::new(FEE));
+    assert_that(&app_info.revenue.total).is_equal_to(Int256::from(FEE));
+
+    // the entropy chain is continued
+    let res = p.platform_try_request_randomness(
+        ProjectAccount::Alice,
+        JOB_ID,
+        &receiver_address,
+        MSG,
+        false,
+        FEE,
+        ProjectCoin::Om,
+    )?;
+    assert_that(&get_attr(&res, "id")).is_equal_to(Some("1".to_string()));
+    assert_that(&get_attr(&res, "randomness")).is_not_equal_to(Some(randomness.to_hex()));
+
+    Ok(())
+}
+
+#[test]
+fn deferred_randomness_is_fulfilled_by_seed() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+    let seed_list: Vec<HexBinary> = calc_seed_chain(&[1; ENC_KEY_LEN], 3)
+        .into_iter()
+        .map(HexBinary::from)
+        .collect();
+
+    // deferred randomness can't be reverted, the callback contract isn't whitelisted
+    p.platform_try_commit_seed_chain(ProjectAccount::Admin, &seed_list[0])?;
+    p.wait(BLOCK);
+    let res = p.platform_try_request_randomness(
+        ProjectAccount::Alice,
+        JOB_ID,
+        &receiver_address,
+        MSG,
+        true,
+        FEE,
+        ProjectCoin::Om,
+    )?;
+    assert_that(&get_attr(&res, "round")).is_equal_to(Some("2".to_string()));
+    assert_that(&get_attr(&res, "randomness")).is_none();
+    assert_that(&p.platform_query_randomness_requests(10, None)?.len()).is_equal_to(1);
+
+    // the fee is pending until the request is fulfilled
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.pending_bets).is_equal_to(Uint128::new(FEE));
+    assert_that(&app_info.balance).is_equal_to(Uint128::zero());
+
+    p.platform_try_reveal_seed(ProjectAccount::Admin, &seed_list[1])?;
+    p.wait(BLOCK);
+    let res = p
+        .platform_try_fulfill_randomness(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedIsNotRevealed);
+
+    // the chain can't be replaced while the request is pending
+    let res = p
+        .platform_try_commit_seed_chain(ProjectAccount::Admin, &seed_list[0])
+        .unwrap_err();
+    assert_error(&res, ContractError::PendingFlipsExist);
+
+    p.platform_try_reveal_seed(ProjectAccount::Admin, &seed_list[2])?;
+    p.wait(BLOCK);
+    let res = p.platform_try_fulfill_randomness(ProjectAccount::Bob, 0)?;
+
+    let expected = HexBinary::from(calc_sha256(
+        format!("{}:{}:{}", seed_list[2].to_hex(), JOB_ID, 0).as_bytes(),
+    ));
+    assert_that(&get_attr(&res, "randomness")).is_equal_to(Some(expected.to_hex()));
+    assert_that(&p.query_callbacks(&receiver_address)?).is_equal_to(vec![
+        CallbackMsg::RandomnessCallback {
+            request_id: 0,
+            job_id: JOB_ID.to_string(),
+            randomness: expected,
+            msg: MSG.into(),
+        },
+    ]);
+    assert_that(&p.platform_query_randomness_requests(10, None)?).is_empty();
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.pending_bets).is_equal_to(Uint128::zero());
+    assert_that(&app_info.balance).is_equal_to(Uint128::new(FEE));
+    assert_that(&app_info.revenue.total).is_equal_to(Int256::from(FEE));
+
+    let res = p
+        .platform_try_fulfill_randomness(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::RandomnessRequestIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn deferred_randomness_is_refunded_after_timeout() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+    let seed_list: Vec<HexBinary> = calc_seed_chain(&[1; ENC_KEY_LEN], 3)
+        .into_iter()
+        .map(HexBinary::from)
+        .collect();
+
+    p.platform_try_commit_seed_chain(ProjectAccount::Admin, &seed_list[0])?;
+    p.wait(BLOCK);
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    for _ in 0..2 {
+        p.platform_try_request_randomness(
+            ProjectAccount::Alice,
+            JOB_ID,
+            &receiver_address,
+            MSG,
+            true,
+            FEE,
+            ProjectCoin::Om,
+        )?;
+    }
+
+    let res = p
+        .platform_try_refund_randomness(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::RefundIsNotAvailable);
+
+    // anyone can refund the fee to the requester after the timeout
+    p.wait(REVEAL_TIMEOUT);
+    let res = p.platform_try_refund_randomness(ProjectAccount::Bob, 0)?;
+    assert_that(&get_attr(&res, "fee")).is_equal_to(Some(FEE.to_string()));
+    assert_that(&(p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)? + FEE))
+        .is_equal_to(alice_balance_before);
+    assert_that(&p.platform_query_randomness_requests(10, None)?.len()).is_equal_to(1);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.pending_bets).is_equal_to(Uint128::new(FEE));
+    assert_that(&app_info.balance).is_equal_to(Uint128::zero());
+    assert_that(&app_info.revenue.total).is_equal_to(Int256::zero());
+
+    let res = p
+        .platform_try_refund_randomness(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::RandomnessRequestIsNotFound);
+
+    // the request with revealed seed can't be refunded
+    p.platform_try_reveal_seed(ProjectAccount::Admin, &seed_list[1])?;
+    p.wait(BLOCK);
+    p.platform_try_reveal_seed(ProjectAccount::Admin, &seed_list[2])?;
+    let res = p
+        .platform_try_refund_randomness(ProjectAccount::Bob, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::RefundIsNotAvailable);
+
+    p.platform_try_fulfill_randomness(ProjectAccount::Bob, 1)?;
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.pending_bets).is_equal_to(Uint128::zero());
+    assert_that(&app_info.balance).is_equal_to(Uint128::new(FEE));
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?).is_equal_to(FEE);
+
+    Ok(())
+}
+
+#[test]
+fn failed_callback_keeps_fee() -> StdResult<()> {
+    let (mut p, receiver_address) = prepare_project()?;
+    p.platform_try_update_contract_callers(ProjectAccount::Admin, Some(&[&receiver_address]))?;
+
+    let res = p.platform_try_request_randomness(
+        ProjectAccount::Alice,
+        JOB_ID,
+        &receiver_address,
+        FAILURE_MSG,
+        false,
+        FEE,
+        ProjectCoin::Om,
+    )?;
+    assert_that(&res.events.iter().any(|x| {
+        x.attributes
+            .iter()
+            .any(|y| y.key == "action" && y.value == "randomness_callback_failed")
+    }))
+    .is_true();
+    assert_that(&p.query_callbacks(&receiver_address)?).is_empty();
+    assert_that(&p.platform_query_app_info()?.balance).is_equal_to(Uint128::new(FEE));
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use cosmwasm_std::{Int256, StdResult, Uint128};
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::{msg::RevenueSplitRespItem, state::MAX_REVENUE_RECIPIENTS},
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const AMOUNT: u128 = 1_000;
+
+const SPLIT: [(ProjectAccount, &str); 3] = [
+    (ProjectAccount::John, "0.5"),
+    (ProjectAccount::Kate, "0.3"),
+    (ProjectAccount::Ruby, "0.2"),
+];
+
+fn query_balances(p: &Project) -> StdResult<Vec<u128>> {
+    SPLIT
+        .iter()
+        .map(|(x, _)| p.query_balance(x, &ProjectCoin::Om))
+        .collect()
+}
+
+#[test]
+fn revenue_split_guards() -> StdResult<()> {
+    let mut p = Project::with_losing_bets(None, AMOUNT)?;
+
+    let res = p
+        .platform_try_distribute_revenue(ProjectAccount::Bob)
+        .unwrap_err();
+    assert_error(&res, ContractError::RevenueSplitIsNotFound);
+
+    let res = p
+        .platform_try_update_revenue_split(ProjectAccount::Alice, &SPLIT)
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_revenue_split(
+            ProjectAccount::Admin,
+            &[(ProjectAccount::John, "0.5"), (ProjectAccount::Kate, "0.4")],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperRevenueSplit);
+
+    let res = p
+        .platform_try_update_revenue_split(
+            ProjectAccount::Admin,
+            &[(ProjectAccount::John, "0.5"), (ProjectAccount::John, "0.5")],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperRevenueSplit);
+
+    let res = p
+        .platform_try_update_revenue_split(
+            ProjectAccount::Admin,
+            &[(ProjectAccount::John, "1"), (ProjectAccount::Kate, "0")],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperRevenueSplit);
+
+    let res = p
+        .platform_try_update_revenue_split(
+            ProjectAccount::Admin,
+            &[(ProjectAccount::John, "0.1"); MAX_REVENUE_RECIPIENTS + 1],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::RevenueRecipientLimit);
+
+    p.platform_try_update_revenue_split(ProjectAccount::Admin, &SPLIT)?;
+
+    // nothing to distribute
+    let res = p
+        .platform_try_distribute_revenue(ProjectAccount::Bob)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    // deposited funds aren't distributed
+    p.platform_try_deposit(ProjectAccount::Admin, 10 * AMOUNT, ProjectCoin::Om)?;
+    let res = p
+        .platform_try_distribute_revenue(ProjectAccount::Bob)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    p.platform_try_update_revenue_split(ProjectAccount::Admin, &[])?;
+    let res = p
+        .platform_try_distribute_revenue(ProjectAccount::Bob)
+        .unwrap_err();
+    assert_error(&res, ContractError::RevenueSplitIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn revenue_distribution() -> StdResult<()> {
+    const ROUNDS: u32 = 10;
+    const REVENUE: u128 = ROUNDS as u128 * AMOUNT;
+    const DEPOSIT: u128 = 5 * AMOUNT;
+
+    let mut p = Project::with_losing_bets(None, AMOUNT)?;
+
+    p.platform_try_update_revenue_split(ProjectAccount::Admin, &SPLIT)?;
+    p.platform_try_deposit(ProjectAccount::Admin, DEPOSIT, ProjectCoin::Om)?;
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+
+    let balances_before = query_balances(&p)?;
+    p.platform_try_distribute_revenue(ProjectAccount::Bob)?;
+    let balances_after = query_balances(&p)?;
+
+    let expected: Vec<u128> = SPLIT
+        .iter()
+        .map(|(_, x)| Uint128::new(REVENUE).mul_floor(str_to_dec(x)).u128())
+        .collect();
+    let received: Vec<u128> = balances_after
+        .iter()
+        .zip(balances_before)
+        .map(|(after, before)| after - before)
+        .collect();
+    assert_that(&received).is_equal_to(expected.clone());
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.balance.u128()).is_equal_to(DEPOSIT);
+    assert_that(&app_info.deposited.u128()).is_equal_to(DEPOSIT);
+    assert_that(&app_info.revenue.current).is_equal_to(Int256::zero());
+    assert_that(&app_info.revenue.total).is_equal_to(Int256::from(REVENUE));
+    assert_that(&p.platform_query_available_to_withdraw()?.u128()).is_equal_to(DEPOSIT);
+
+    let res = p
+        .platform_try_distribute_revenue(ProjectAccount::Bob)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    // lifetime totals are accumulated
+    p.flip_rounds(AMOUNT, ROUNDS)?;
+    p.platform_try_distribute_revenue(ProjectAccount::Alice)?;
+
+    let split = p.platform_query_revenue_split()?;
+    assert_that(&split).is_equal_to(
+        SPLIT
+            .iter()
+            .zip(expected)
+            .map(|((address, weight), x)| RevenueSplitRespItem {
+                address: (*address).into(),
+                weight: str_to_dec(weight),
+                distributed: Uint128::new(2 * x),
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    Ok(())
+}
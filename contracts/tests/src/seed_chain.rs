@@ -0,0 +1,326 @@
+use cosmwasm_std::{HexBinary, Int256, StdResult};
+
+use cf_base::{
+    error::ContractError,
+    hash_generator::types::Hash,
+    platform::{
+        state::REVEAL_TIMEOUT,
+        types::{Range, Side},
+    },
+};
+use hashing_helper::base::{calc_seed_chain, calc_sha256, ENC_KEY_LEN};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const CLIENT_SEED: &str = "alice's lucky seed";
+const CHAIN_LENGTH: usize = 10;
+
+fn get_seed_list(last_seed: u8) -> Vec<HexBinary> {
+    calc_seed_chain(&[last_seed; ENC_KEY_LEN], CHAIN_LENGTH)
+        .into_iter()
+        .map(HexBinary::from)
+        .collect()
+}
+
+fn assert_pending_bets(p: &Project, amount: u128) -> StdResult<()> {
+    let app_info = p.platform_query_app_info()?;
+    let bank_balance = p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?;
+
+    assert_that(&app_info.pending_bets.u128()).is_equal_to(amount);
+    assert_that(&(app_info.balance.u128() + amount)).is_equal_to(bank_balance);
+
+    Ok(())
+}
+
+#[test]
+fn seed_chain_guards() -> StdResult<()> {
+    let mut p = Project::with_bet_range(Some(ProjectAccount::Owner), Range::new(AMOUNT, AMOUNT))?;
+    let seed_list = get_seed_list(1);
+
+    let res = p
+        .platform_try_flip_seeded(
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+            CLIENT_SEED,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedChainIsNotFound);
+
+    let res = p
+        .platform_try_commit_seed_chain(ProjectAccount::Alice, &seed_list[0])
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_commit_seed_chain(ProjectAccount::Admin, &HexBinary::from(&[1; 16]))
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperSeed);
+
+    // the worker can commit
+    p.platform_try_commit_seed_chain(ProjectAccount::Owner, &seed_list[0])?;
+
+    let res = p
+        .platform_try_reveal_seed(ProjectAccount::Owner, &seed_list[1])
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedIsRevealedInBlock);
+
+    p.wait(BLOCK);
+    let res = p
+        .platform_try_reveal_seed(ProjectAccount::Owner, &seed_list[2])
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperSeed);
+
+    let res = p
+        .platform_try_flip_seeded(ProjectAccount::Alice, SIDE, AMOUNT, ProjectCoin::Om, "")
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperClientSeed);
+
+    let res = p
+        .platform_try_flip_seeded(
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+            &"x".repeat(65),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperClientSeed);
+
+    let res = p
+        .platform_try_settle_flip(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::PendingFlipIsNotFound);
+
+    p.platform_try_flip_seeded(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        CLIENT_SEED,
+    )?;
+
+    let res = p
+        .platform_try_settle_flip(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedIsNotRevealed);
+
+    let res = p
+        .platform_try_refund_flip(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::RefundIsNotAvailable);
+
+    // pending flip is bound to the round after the next one
+    p.platform_try_reveal_seed(ProjectAccount::Owner, &seed_list[1])?;
+    let res = p
+        .platform_try_settle_flip(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedIsNotRevealed);
+
+    let res = p
+        .platform_try_commit_seed_chain(ProjectAccount::Admin, &get_seed_list(2)[0])
+        .unwrap_err();
+    assert_error(&res, ContractError::SeedIsRevealedInBlock);
+
+    p.wait(BLOCK);
+    let res = p
+        .platform_try_commit_seed_chain(ProjectAccount::Admin, &get_seed_list(2)[0])
+        .unwrap_err();
+    assert_error(&res, ContractError::PendingFlipsExist);
+
+    Ok(())
+}
+
+#[test]
+fn seeded_flip_is_verifiable() -> StdResult<()> {
+    let mut p = Project::with_bet_range(Some(ProjectAccount::Owner), Range::new(AMOUNT, AMOUNT))?;
+    let seed_list = get_seed_list(1);
+
+    p.platform_try_commit_seed_chain(ProjectAccount::Owner, &seed_list[0])?;
+    p.wait(BLOCK);
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_flip_seeded(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        CLIENT_SEED,
+    )?;
+    assert_pending_bets(&p, AMOUNT)?;
+
+    let pending_flips = p.platform_query_pending_flips(10, None)?;
+    assert_that(&pending_flips.len()).is_equal_to(1);
+    assert_that(&pending_flips[0].flip.round).is_equal_to(2);
+    assert_that(&pending_flips[0].flip.nonce).is_equal_to(0);
+
+    // the user stats are updated on settlement
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .bets
+            .count,
+    )
+    .is_equal_to(0);
+
+    for seed in &seed_list[1..=2] {
+        p.platform_try_reveal_seed(ProjectAccount::Owner, seed)?;
+        p.wait(BLOCK);
+    }
+    assert_that(&p.platform_query_seed_chain()?.unwrap().round).is_equal_to(2);
+    assert_that(&p.platform_query_revealed_seed(2)?).is_equal_to(Some(seed_list[2].clone()));
+
+    // anyone can settle
+    let res = p.platform_try_settle_flip(ProjectAccount::Bob, 0)?;
+    assert_pending_bets(&p, 0)?;
+    assert_that(&p.platform_query_pending_flips(10, None)?).is_empty();
+
+    // the outcome is reproduced with the revealed seed
+    let data = format!("{}:{}:{}", seed_list[2].to_hex(), CLIENT_SEED, 0);
+    let random_weight = Hash::from(calc_sha256(data.as_bytes())).to_norm_dec();
+    assert_that(&get_attr(&res, "random_weight")).is_equal_to(Some(random_weight.to_string()));
+    assert_that(&get_attr(&res, "seed")).is_equal_to(Some(seed_list[2].to_hex()));
+
+    let prize = get_attr(&res, "prize").unwrap().parse::<u128>().unwrap();
+    let alice_balance_after = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    assert_that(&(alice_balance_after + AMOUNT)).is_equal_to(alice_balance_before + prize);
+
+    let user = p.platform_query_user(ProjectAccount::Alice)?;
+    assert_that(&user.stats.bets.count).is_equal_to(1);
+    assert_that(&user.stats.wins.value.u128()).is_equal_to(prize);
+
+    // the nonce makes next flip with the same client seed different
+    p.platform_try_flip_seeded(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        CLIENT_SEED,
+    )?;
+    assert_that(&p.platform_query_pending_flips(10, None)?[0].flip.nonce).is_equal_to(1);
+
+    let res = p
+        .platform_try_settle_flip(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::PendingFlipIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn pending_flip_is_refunded_after_timeout() -> StdResult<()> {
+    let mut p = Project::with_bet_range(Some(ProjectAccount::Owner), Range::new(AMOUNT, AMOUNT))?;
+    let seed_list = get_seed_list(1);
+
+    p.platform_try_commit_seed_chain(ProjectAccount::Owner, &seed_list[0])?;
+    p.wait(BLOCK);
+
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    p.platform_try_flip_seeded(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        CLIENT_SEED,
+    )?;
+
+    // the seed isn't revealed in time, refund is available even on pause
+    p.wait(REVEAL_TIMEOUT);
+    p.platform_try_pause(ProjectAccount::Admin)?;
+    let app_info_before = p.platform_query_app_info()?;
+    let res = p.platform_try_refund_flip(ProjectAccount::Bob, 0)?;
+    p.platform_try_unpause(ProjectAccount::Admin)?;
+
+    // the house pays the bet as the win for the missed reveal
+    assert_that(&get_attr(&res, "penalty")).is_equal_to(Some(AMOUNT.to_string()));
+    assert_that(&get_attr(&res, "payout")).is_equal_to(Some("auto_paid".to_string()));
+    assert_pending_bets(&p, 0)?;
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before + AMOUNT);
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.balance.u128()).is_equal_to(app_info_before.balance.u128() - AMOUNT);
+    assert_that(&app_info.revenue.current)
+        .is_equal_to(app_info_before.revenue.current - Int256::from(AMOUNT));
+
+    // late reveal doesn't allow to settle refunded flip
+    for seed in &seed_list[1..=2] {
+        p.platform_try_reveal_seed(ProjectAccount::Owner, seed)?;
+        p.wait(BLOCK);
+    }
+    let res = p
+        .platform_try_settle_flip(ProjectAccount::Bob, 0)
+        .unwrap_err();
+    assert_error(&res, ContractError::PendingFlipIsNotFound);
+
+    // revealed seed makes refund unavailable
+    p.platform_try_flip_seeded(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        CLIENT_SEED,
+    )?;
+    for seed in &seed_list[3..=4] {
+        p.platform_try_reveal_seed(ProjectAccount::Owner, seed)?;
+        p.wait(BLOCK);
+    }
+    p.wait(REVEAL_TIMEOUT);
+
+    let res = p
+        .platform_try_refund_flip(ProjectAccount::Bob, 1)
+        .unwrap_err();
+    assert_error(&res, ContractError::RefundIsNotAvailable);
+
+    p.platform_try_settle_flip(ProjectAccount::Bob, 1)?;
+    assert_pending_bets(&p, 0)?;
+
+    Ok(())
+}
+
+#[test]
+fn new_chain_continues_rounds() -> StdResult<()> {
+    let mut p = Project::with_bet_range(Some(ProjectAccount::Owner), Range::new(AMOUNT, AMOUNT))?;
+    let seed_list = get_seed_list(1);
+    let next_seed_list = get_seed_list(2);
+
+    p.platform_try_commit_seed_chain(ProjectAccount::Owner, &seed_list[0])?;
+    p.wait(BLOCK);
+    p.platform_try_flip_seeded(
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+        CLIENT_SEED,
+    )?;
+
+    for seed in &seed_list[1..=2] {
+        p.platform_try_reveal_seed(ProjectAccount::Owner, seed)?;
+        p.wait(BLOCK);
+    }
+    p.platform_try_settle_flip(ProjectAccount::Bob, 0)?;
+
+    // settled flips don't block the new chain
+    p.platform_try_commit_seed_chain(ProjectAccount::Owner, &next_seed_list[0])?;
+    let seed_chain = p.platform_query_seed_chain()?.unwrap();
+    assert_that(&seed_chain.head).is_equal_to(&next_seed_list[0]);
+    assert_that(&seed_chain.round).is_equal_to(2);
+
+    // revealed seeds of the previous chain are kept
+    p.wait(BLOCK);
+    p.platform_try_reveal_seed(ProjectAccount::Owner, &next_seed_list[1])?;
+    assert_that(&p.platform_query_revealed_seed(2)?).is_equal_to(Some(seed_list[2].clone()));
+    assert_that(&p.platform_query_revealed_seed(3)?).is_equal_to(Some(next_seed_list[1].clone()));
+
+    Ok(())
+}
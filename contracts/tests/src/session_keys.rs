@@ -0,0 +1,242 @@
+use cosmwasm_std::{StdResult, Uint128};
+
+use cf_base::{
+    error::ContractError,
+    platform::{
+        state::MAX_SESSION_DURATION,
+        types::{Range, Side},
+    },
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const SPEND_CAP: u128 = 2 * AMOUNT;
+const DURATION: u64 = 3_600;
+
+#[test]
+fn grant_and_revoke_session() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+
+    let res = p
+        .platform_try_grant_session(
+            ProjectAccount::Alice,
+            ProjectAccount::Bob,
+            SPEND_CAP,
+            MAX_SESSION_DURATION + 1,
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperSessionDuration);
+
+    let res = p
+        .platform_try_grant_session(
+            ProjectAccount::Alice,
+            ProjectAccount::Bob,
+            0,
+            DURATION,
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    p.platform_try_grant_session(
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        SPEND_CAP,
+        DURATION,
+        Some(SIDE),
+        None,
+    )?;
+    let grant_list = p.platform_query_session_grants(ProjectAccount::Alice, 10, None)?;
+    assert_that(&grant_list.len()).is_equal_to(1);
+    assert_that(&grant_list[0].session.to_string()).is_equal_to(ProjectAccount::Bob.to_string());
+    assert_that(&grant_list[0].grant.spend_cap).is_equal_to(Uint128::new(SPEND_CAP));
+
+    p.platform_try_revoke_session(ProjectAccount::Alice, ProjectAccount::Bob)?;
+    assert_that(&p.platform_query_session_grants(ProjectAccount::Alice, 10, None)?).is_empty();
+
+    let res = p
+        .platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionIsNotFound);
+
+    let res = p
+        .platform_try_revoke_session(ProjectAccount::Alice, ProjectAccount::Bob)
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn session_flip_pays_owner() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+    p.platform_try_grant_session(
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        SPEND_CAP,
+        DURATION,
+        None,
+        None,
+    )?;
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    let bob_balance_before = p.query_balance(ProjectAccount::Bob, &ProjectCoin::Om)?;
+
+    let res = p.platform_try_session_flip(
+        ProjectAccount::Bob,
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+        ProjectCoin::Om,
+    )?;
+    assert_that(&get_attr(&res, "user")).is_equal_to(Some(ProjectAccount::Alice.to_string()));
+    assert_that(&get_attr(&res, "session")).is_equal_to(Some(ProjectAccount::Bob.to_string()));
+
+    // the session key pays the bet, the prize goes to the owner
+    let prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    assert_that(&p.query_balance(ProjectAccount::Bob, &ProjectCoin::Om)?)
+        .is_equal_to(bob_balance_before - AMOUNT);
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before + prize);
+    assert_that(
+        &p.platform_query_user(ProjectAccount::Alice)?
+            .stats
+            .bets
+            .count,
+    )
+    .is_equal_to(1);
+
+    // the bet from the owner balance, the prize is credited to it
+    p.platform_try_top_up(ProjectAccount::Alice, AMOUNT, ProjectCoin::Om)?;
+    p.wait(BLOCK);
+    let res = p.platform_try_session_flip_from_balance(
+        ProjectAccount::Bob,
+        ProjectAccount::Alice,
+        SIDE,
+        AMOUNT,
+    )?;
+    let prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128()).is_equal_to(prize);
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Bob)?.u128()).is_equal_to(0);
+
+    let grant_list = p.platform_query_session_grants(ProjectAccount::Alice, 10, None)?;
+    assert_that(&grant_list[0].grant.spent).is_equal_to(Uint128::new(SPEND_CAP));
+
+    Ok(())
+}
+
+#[test]
+fn session_flip_is_limited_by_grant() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+    p.platform_try_grant_session(
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        SPEND_CAP,
+        DURATION,
+        Some(SIDE),
+        Some(Range::new(AMOUNT, AMOUNT)),
+    )?;
+
+    let res = p
+        .platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::Alice,
+            Side::Tail,
+            AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionBetIsNotAllowed);
+
+    let res = p
+        .platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::Alice,
+            SIDE,
+            2 * AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionBetIsNotAllowed);
+
+    // the grant is bound to the owner
+    let res = p
+        .platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::John,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionIsNotFound);
+
+    for _ in 0..2 {
+        p.platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+        )?;
+        p.wait(BLOCK);
+    }
+
+    let res = p
+        .platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionSpendCapIsExceeded);
+
+    Ok(())
+}
+
+#[test]
+fn session_expires() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, 2 * AMOUNT))?;
+    p.platform_try_grant_session(
+        ProjectAccount::Alice,
+        ProjectAccount::Bob,
+        SPEND_CAP,
+        DURATION,
+        None,
+        None,
+    )?;
+
+    p.wait(DURATION);
+    let res = p
+        .platform_try_session_flip(
+            ProjectAccount::Bob,
+            ProjectAccount::Alice,
+            SIDE,
+            AMOUNT,
+            ProjectCoin::Om,
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::SessionIsExpired);
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use cosmwasm_std::{Decimal, Int256, StdResult, Uint128};
+
+use cf_base::{
+    converters::str_to_dec,
+    error::ContractError,
+    platform::{
+        msg::TierProgress,
+        state::{FLIP_COOLDOWN, MAX_TIERS},
+        types::{Range, Side, Tier},
+    },
+};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::PlatformExtension,
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+fn tier(threshold: u128, bet_min: u128, bet_max: u128, fee_discount: &str) -> Tier {
+    Tier {
+        threshold: Uint128::new(threshold),
+        bet: Range::new(bet_min, bet_max),
+        fee_discount: str_to_dec(fee_discount),
+        cashback: None,
+    }
+}
+
+fn default_tiers() -> Vec<Tier> {
+    vec![
+        tier(0, 1_000, 2_000, "0"),
+        tier(5_000, 1_000, 5_000, "0.2"),
+        tier(20_000, 1_000, 10_000, "0.5"),
+    ]
+}
+
+#[test]
+fn tier_guards() -> StdResult<()> {
+    let mut p = Project::new();
+
+    let res = p
+        .platform_try_update_tiers(ProjectAccount::Alice, default_tiers())
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_update_tiers(ProjectAccount::Admin, vec![tier(1, 1_000, 2_000, "0")])
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperTierThresholds);
+
+    let res = p
+        .platform_try_update_tiers(
+            ProjectAccount::Admin,
+            vec![tier(0, 1_000, 2_000, "0"), tier(0, 1_000, 2_000, "0")],
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperTierThresholds);
+
+    let res = p
+        .platform_try_update_tiers(ProjectAccount::Admin, vec![tier(0, 2_000, 1_000, "0")])
+        .unwrap_err();
+    assert_error(&res, ContractError::ImproperMinBet);
+
+    let res = p
+        .platform_try_update_tiers(ProjectAccount::Admin, vec![tier(0, 1_000, 2_000, "1.5")])
+        .unwrap_err();
+    assert_error(&res, ContractError::TierRateIsOutOfRange);
+
+    let res = p
+        .platform_try_update_tiers(
+            ProjectAccount::Admin,
+            (0..=MAX_TIERS as u128)
+                .map(|x| tier(x, 1_000, 2_000, "0"))
+                .collect(),
+        )
+        .unwrap_err();
+    assert_error(&res, ContractError::TierLimit);
+
+    let res = p
+        .platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Alice, Some(0))
+        .unwrap_err();
+    assert_error(&res, ContractError::TierIsNotFound);
+
+    p.platform_try_update_tiers(ProjectAccount::Admin, default_tiers())?;
+    assert_that(&p.platform_query_tiers()?).is_equal_to(default_tiers());
+
+    let res = p
+        .platform_try_set_user_tier(ProjectAccount::Alice, ProjectAccount::Alice, Some(2))
+        .unwrap_err();
+    assert_error(&res, ContractError::Unauthorized);
+
+    let res = p
+        .platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Alice, Some(3))
+        .unwrap_err();
+    assert_error(&res, ContractError::TierIsNotFound);
+
+    Ok(())
+}
+
+#[test]
+fn tiers_by_bets_value() -> StdResult<()> {
+    const SIDE: Side = Side::Head;
+
+    let mut p = Project::new();
+
+    // base tier is taken from config
+    let user_tier = p.platform_query_user_tier(ProjectAccount::Alice)?;
+    assert_that(&user_tier.level).is_equal_to(0);
+    assert_that(&user_tier.tier.bet).is_equal_to(p.platform_query_config()?.bet);
+    assert_that(&user_tier.next).is_equal_to(None);
+
+    p.platform_try_update_tiers(ProjectAccount::Admin, default_tiers())?;
+
+    let res = p
+        .platform_try_flip(ProjectAccount::Alice, SIDE, 3_000, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+
+    for _ in 0..2 {
+        p.platform_try_flip(ProjectAccount::Alice, SIDE, 2_000, ProjectCoin::Om)?;
+        p.wait(FLIP_COOLDOWN);
+    }
+
+    let user_tier = p.platform_query_user_tier(ProjectAccount::Alice)?;
+    assert_that(&user_tier.level).is_equal_to(0);
+    assert_that(&user_tier.bets_value.u128()).is_equal_to(4_000);
+    assert_that(&user_tier.next).is_equal_to(Some(TierProgress {
+        level: 1,
+        threshold: Uint128::new(5_000),
+        remaining: Uint128::new(1_000),
+        progress: str_to_dec("0.8"),
+    }));
+
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 1_000, ProjectCoin::Om)?;
+    p.wait(FLIP_COOLDOWN);
+
+    let user_tier = p.platform_query_user_tier(ProjectAccount::Alice)?;
+    assert_that(&user_tier.level).is_equal_to(1);
+    assert_that(&user_tier.tier).is_equal_to(default_tiers()[1].clone());
+    assert_that(&user_tier.next.map(|x| x.remaining.u128())).is_equal_to(Some(15_000));
+
+    p.platform_try_flip(ProjectAccount::Alice, SIDE, 5_000, ProjectCoin::Om)?;
+
+    // other users keep base tier
+    let res = p
+        .platform_try_flip(ProjectAccount::Bob, SIDE, 5_000, ProjectCoin::Om)
+        .unwrap_err();
+    assert_error(&res, ContractError::BetIsOutOfRange);
+
+    Ok(())
+}
+
+#[test]
+fn assigned_tier() -> StdResult<()> {
+    let mut p = Project::new();
+
+    p.platform_try_update_tiers(ProjectAccount::Admin, default_tiers())?;
+    p.platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Bob, Some(2))?;
+
+    let user_tier = p.platform_query_user_tier(ProjectAccount::Bob)?;
+    assert_that(&user_tier.level).is_equal_to(2);
+    assert_that(&user_tier.is_assigned).is_true();
+    // progress is calculated by bets value
+    assert_that(&user_tier.next.map(|x| x.level)).is_equal_to(Some(1));
+
+    p.platform_try_flip(ProjectAccount::Bob, Side::Tail, 10_000, ProjectCoin::Om)?;
+    p.wait(FLIP_COOLDOWN);
+
+    // assigned level is clamped to the last tier
+    p.platform_try_update_tiers(ProjectAccount::Admin, default_tiers()[..2].to_vec())?;
+    let user_tier = p.platform_query_user_tier(ProjectAccount::Bob)?;
+    assert_that(&user_tier.level).is_equal_to(1);
+
+    // reached level is used after removing
+    p.platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Bob, Some(0))?;
+    assert_that(&p.platform_query_user_tier(ProjectAccount::Bob)?.level).is_equal_to(0);
+
+    p.platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Bob, None)?;
+    let user_tier = p.platform_query_user_tier(ProjectAccount::Bob)?;
+    assert_that(&user_tier.level).is_equal_to(1);
+    assert_that(&user_tier.is_assigned).is_false();
+
+    Ok(())
+}
+
+#[test]
+fn fee_discount_and_cashback() -> StdResult<()> {
+    const ROUNDS: u32 = 20;
+    const AMOUNT: u128 = 1_000;
+
+    let mut p = Project::new();
+
+    // the user can't win without discount
+    p.platform_try_update_config(ProjectAccount::Admin, None, None, None, Some("1"))?;
+    p.platform_try_update_tiers(
+        ProjectAccount::Admin,
+        vec![
+            Tier {
+                cashback: Some(str_to_dec("0.1")),
+                ..tier(0, AMOUNT, AMOUNT, "0")
+            },
+            tier(1_000_000, AMOUNT, AMOUNT, "1"),
+        ],
+    )?;
+
+    for _ in 0..ROUNDS {
+        p.platform_try_flip(ProjectAccount::Alice, Side::Head, AMOUNT, ProjectCoin::Om)?;
+        p.wait(FLIP_COOLDOWN);
+    }
+
+    let user = p.platform_query_user(ProjectAccount::Alice)?;
+    let app_info = p.platform_query_app_info()?;
+    let cashback = ROUNDS as u128 * AMOUNT / 10;
+
+    assert_that(&user.stats.wins.count).is_equal_to(0);
+    assert_that(&user.unclaimed.u128()).is_equal_to(cashback);
+    assert_that(&app_info.user_unclaimed.u128()).is_equal_to(cashback);
+    assert_that(&app_info.balance.u128()).is_equal_to(ROUNDS as u128 * AMOUNT);
+    assert_that(&app_info.revenue.current)
+        .is_equal_to(Int256::from(ROUNDS as u128 * AMOUNT - cashback));
+
+    p.platform_try_claim(ProjectAccount::Alice)?;
+
+    // zero fee with full discount
+    p.platform_try_set_user_tier(ProjectAccount::Admin, ProjectAccount::Alice, Some(1))?;
+    assert_that(
+        &p.platform_query_user_tier(ProjectAccount::Alice)?
+            .tier
+            .get_fee(Decimal::one()),
+    )
+    .is_equal_to(Decimal::zero());
+
+    for _ in 0..ROUNDS {
+        p.platform_try_flip(ProjectAccount::Alice, Side::Head, AMOUNT, ProjectCoin::Om)?;
+        p.wait(FLIP_COOLDOWN);
+    }
+
+    let user = p.platform_query_user(ProjectAccount::Alice)?;
+    assert_that(&user.stats.wins.count).is_greater_than(0);
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use cosmwasm_std::{HexBinary, StdResult, Uint128};
+use cw_multi_test::Executor;
+
+use cf_base::{
+    error::{parse_err, ContractError},
+    platform::{
+        msg::ExecuteMsg,
+        state::REVEAL_TIMEOUT,
+        types::{Payout, Range, Side},
+    },
+};
+use hashing_helper::base::{calc_seed_chain, ENC_KEY_LEN};
+use speculoos::prelude::*;
+
+use crate::helpers::{
+    platform::{get_attr, PlatformExtension},
+    suite::{
+        core::{assert_error, Project},
+        types::{ProjectAccount, ProjectCoin},
+    },
+};
+
+const BLOCK: u64 = 5;
+const SIDE: Side = Side::Head;
+const AMOUNT: u128 = 1_000;
+const TOP_UP: u128 = 3 * AMOUNT;
+
+#[test]
+fn top_up_and_withdraw_balance() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, AMOUNT))?;
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+
+    p.platform_try_top_up(ProjectAccount::Alice, TOP_UP, ProjectCoin::Om)?;
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128()).is_equal_to(TOP_UP);
+    assert_that(&p.platform_query_app_info()?.user_balances.u128()).is_equal_to(TOP_UP);
+
+    let res = p
+        .platform_try_withdraw_balance(ProjectAccount::Alice, Some(TOP_UP + 1))
+        .unwrap_err();
+    assert_error(&res, ContractError::InsufficientUserBalance);
+
+    p.platform_try_withdraw_balance(ProjectAccount::Alice, Some(AMOUNT))?;
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128())
+        .is_equal_to(TOP_UP - AMOUNT);
+
+    p.platform_try_withdraw_balance(ProjectAccount::Alice, None)?;
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128()).is_equal_to(0);
+    assert_that(&p.platform_query_app_info()?.user_balances.u128()).is_equal_to(0);
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before);
+
+    let res = p
+        .platform_try_withdraw_balance(ProjectAccount::Alice, None)
+        .unwrap_err();
+    assert_error(&res, ContractError::ZeroAmount);
+
+    Ok(())
+}
+
+#[test]
+fn flip_from_balance_credits_prize() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, AMOUNT))?;
+
+    let res = p
+        .platform_try_flip_from_balance(ProjectAccount::Alice, SIDE, AMOUNT)
+        .unwrap_err();
+    assert_error(&res, ContractError::InsufficientUserBalance);
+
+    p.platform_try_top_up(ProjectAccount::Alice, TOP_UP, ProjectCoin::Om)?;
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+
+    let res = p.platform_try_flip_from_balance(ProjectAccount::Alice, SIDE, AMOUNT)?;
+    assert_that(&get_attr(&res, "from_balance")).is_equal_to(Some("true".to_string()));
+
+    // the prize is credited without the transfer
+    let prize: u128 = get_attr(&res, "prize").unwrap().parse().unwrap();
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128())
+        .is_equal_to(TOP_UP - AMOUNT + prize);
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before);
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_balances.u128()).is_equal_to(TOP_UP - AMOUNT + prize);
+    assert_that(&app_info.user_stats.bets.count).is_equal_to(1);
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?)
+        .is_equal_to(app_info.balance.u128());
+
+    Ok(())
+}
+
+#[test]
+fn user_balances_are_not_withdrawable_by_admin() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, AMOUNT))?;
+    p.platform_try_top_up(ProjectAccount::Alice, TOP_UP, ProjectCoin::Om)?;
+
+    assert_that(&p.platform_query_available_to_withdraw()?.u128()).is_equal_to(100 * AMOUNT);
+    assert_that(&p.platform_query_required_to_deposit()?).is_equal_to(Uint128::zero());
+
+    let res = p
+        .platform_try_withdraw(ProjectAccount::Admin, Some(100 * AMOUNT + 1), None)
+        .unwrap_err();
+    assert_error(&res, ContractError::NotEnoughLiquidity);
+
+    p.platform_try_withdraw(ProjectAccount::Admin, None, None)?;
+    p.platform_try_withdraw_balance(ProjectAccount::Alice, None)?;
+    assert_that(&p.query_balance(p.get_platform_address(), &ProjectCoin::Om)?).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn user_balances_do_not_pay_other_users() -> StdResult<()> {
+    // no house deposit, only the player balance
+    let mut p = Project::new();
+    p.platform_try_update_config(
+        ProjectAccount::Admin,
+        None,
+        None,
+        Some(Range::new(AMOUNT, AMOUNT)),
+        None,
+    )?;
+    p.platform_try_top_up(ProjectAccount::Alice, 10 * AMOUNT, ProjectCoin::Om)?;
+
+    let mut losses: u128 = 0;
+    loop {
+        let res = p.platform_try_flip(ProjectAccount::Bob, SIDE, AMOUNT, ProjectCoin::Om)?;
+        p.wait(BLOCK);
+
+        match get_attr(&res, "payout").unwrap().parse::<Payout>()? {
+            Payout::None => losses += 1,
+            // the prize is auto paid only if lost bets cover it
+            payout => {
+                let expected = if losses == 0 {
+                    Payout::Unclaimed
+                } else {
+                    Payout::AutoPaid
+                };
+                assert_that(&payout).is_equal_to(expected);
+                break;
+            }
+        }
+    }
+
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.user_balances.u128()).is_equal_to(10 * AMOUNT);
+    assert_that(&app_info.balance.u128()).is_greater_than_or_equal_to(10 * AMOUNT);
+
+    // the unclaimed prize isn't paid from the player balance
+    let bob_unclaimed = p.platform_query_user(ProjectAccount::Bob)?.unclaimed;
+    if !bob_unclaimed.is_zero() {
+        let res = p.platform_try_claim(ProjectAccount::Bob).unwrap_err();
+        assert_error(&res, ContractError::NotEnoughLiquidity);
+    }
+
+    p.platform_try_withdraw_balance(ProjectAccount::Alice, Some(10 * AMOUNT))?;
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128()).is_equal_to(0);
+    assert_that(&p.platform_query_app_info()?.user_balances.u128()).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn refunded_seeded_flip_is_credited_to_balance() -> StdResult<()> {
+    let mut p = Project::with_bet_range(None, Range::new(AMOUNT, AMOUNT))?;
+    let head = calc_seed_chain(&[1; ENC_KEY_LEN], 3)[0];
+    p.platform_try_commit_seed_chain(ProjectAccount::Admin, &HexBinary::from(head))?;
+    p.platform_try_top_up(ProjectAccount::Alice, TOP_UP, ProjectCoin::Om)?;
+    p.wait(BLOCK);
+
+    p.app
+        .execute_contract(
+            ProjectAccount::Alice.into(),
+            p.get_platform_address(),
+            &ExecuteMsg::Flip {
+                side: SIDE,
+                nft: None,
+                client_seed: Some("seed".to_string()),
+                callback: None,
+                amount: Some(Uint128::new(AMOUNT)),
+                payout_to: None,
+            },
+            &[],
+        )
+        .map_err(parse_err)?;
+    let app_info = p.platform_query_app_info()?;
+    assert_that(&app_info.pending_bets.u128()).is_equal_to(AMOUNT);
+    assert_that(&app_info.user_balances.u128()).is_equal_to(TOP_UP - AMOUNT);
+
+    p.wait(REVEAL_TIMEOUT);
+    let alice_balance_before = p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?;
+    let res = p.platform_try_refund_flip(ProjectAccount::Bob, 0)?;
+    assert_that(&get_attr(&res, "from_balance")).is_equal_to(Some("true".to_string()));
+
+    // the bet and the penalty for the missed reveal
+    assert_that(&p.platform_query_user_balance(ProjectAccount::Alice)?.u128())
+        .is_equal_to(TOP_UP + AMOUNT);
+    assert_that(&p.query_balance(ProjectAccount::Alice, &ProjectCoin::Om)?)
+        .is_equal_to(alice_balance_before);
+    assert_that(&p.platform_query_app_info()?.pending_bets.u128()).is_equal_to(0);
+
+    Ok(())
+}
@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Uint128, Decimal, InstantiateMsg, Range, ExecuteMsg, Side, QueryMsg, MigrateMsg, SignedDecimal, Int256, AppInfo, Revenue, Stats, StatsItem, Addr, Config, UserInfo, ArrayOfUserListRespItem, UserListRespItem } from "./Platform.types";
+import { Uint128, Decimal, InstantiateMsg, Range, ExecuteMsg, Binary, Side, HexBinary, TokenUnverified, Callback, Nft, IbcTarget, Cw721ReceiveMsg, Tier, RakebackConfig, InsuranceConfig, NftPerk, RevenueRecipient, BuybackConfig, OracleConfig, BonusCredit, PromoCode, QueryMsg, MigrateMsg, SudoMsg, IbcLifecycleComplete, SignedDecimal, Int256, AppInfo, Revenue, Stats, StatsItem, BetRangeResp, NullableBuybackConfig, ArrayOfBuybackListRespItem, BuybackListRespItem, Buyback, Addr, Config, NullableArrayOfAddr, Boolean, ArrayOfHouseNftRespItem, HouseNftRespItem, NullableIbcTarget, InsuranceResp, ArrayOfNftFloorPriceRespItem, NftFloorPriceRespItem, ArrayOfNftPerksRespItem, NftPerksRespItem, NullableOracleConfig, PayoutQueuePositionResp, ArrayOfPendingFlipsRespItem, PendingFlipsRespItem, PendingFlip, Bet, NullablePromoCode, NullableRakebackConfig, NullableUint128, ArrayOfRandomnessRequestsRespItem, RandomnessRequestsRespItem, RandomnessRequest, NullableHexBinary, ArrayOfRevenueSplitRespItem, RevenueSplitRespItem, NullableSeedChain, SeedChain, ArrayOfSessionGrantsRespItem, SessionGrantsRespItem, SessionGrant, Uint64, ArrayOfTier, UserInfo, BonusInfo, ArrayOfUserListRespItem, UserListRespItem, UserRakebackResp, UserTierResp, TierProgress } from "./Platform.types";
 export interface PlatformReadOnlyInterface {
   contractAddress: string;
   config: () => Promise<Config>;
@@ -25,6 +25,101 @@ export interface PlatformReadOnlyInterface {
     amount: number;
     startAfter?: string;
   }) => Promise<ArrayOfUserListRespItem>;
+  tiers: () => Promise<ArrayOfTier>;
+  userTier: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<UserTierResp>;
+  userBalance: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<Uint128>;
+  userBonus: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<BonusInfo>;
+  signedNonce: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<Uint64>;
+  ibcSender: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<NullableIbcTarget>;
+  sessionGrants: ({
+    amount,
+    owner,
+    startAfter
+  }: {
+    amount: number;
+    owner: string;
+    startAfter?: string;
+  }) => Promise<ArrayOfSessionGrantsRespItem>;
+  payoutQueuePosition: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<PayoutQueuePositionResp>;
+  seedChain: () => Promise<NullableSeedChain>;
+  revealedSeed: ({
+    round
+  }: {
+    round: number;
+  }) => Promise<NullableHexBinary>;
+  pendingFlips: ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: number;
+  }) => Promise<ArrayOfPendingFlipsRespItem>;
+  randomnessFee: () => Promise<NullableUint128>;
+  randomnessRequests: ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: number;
+  }) => Promise<ArrayOfRandomnessRequestsRespItem>;
+  rakeback: () => Promise<NullableRakebackConfig>;
+  userRakeback: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<UserRakebackResp>;
+  insurance: () => Promise<InsuranceResp>;
+  contractCallers: () => Promise<NullableArrayOfAddr>;
+  deferredOnly: () => Promise<Boolean>;
+  nftPerks: () => Promise<ArrayOfNftPerksRespItem>;
+  revenueSplit: () => Promise<ArrayOfRevenueSplitRespItem>;
+  buyback: () => Promise<NullableBuybackConfig>;
+  oracle: () => Promise<NullableOracleConfig>;
+  betRange: () => Promise<BetRangeResp>;
+  promoCode: ({
+    hash
+  }: {
+    hash: HexBinary;
+  }) => Promise<NullablePromoCode>;
+  buybackList: ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: number;
+  }) => Promise<ArrayOfBuybackListRespItem>;
+  nftFloorPrices: () => Promise<ArrayOfNftFloorPriceRespItem>;
+  houseNfts: ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: Nft;
+  }) => Promise<ArrayOfHouseNftRespItem>;
 }
 export class PlatformQueryClient implements PlatformReadOnlyInterface {
   client: CosmWasmClient;
@@ -38,6 +133,33 @@ export class PlatformQueryClient implements PlatformReadOnlyInterface {
     this.availableToWithdraw = this.availableToWithdraw.bind(this);
     this.user = this.user.bind(this);
     this.userList = this.userList.bind(this);
+    this.tiers = this.tiers.bind(this);
+    this.userTier = this.userTier.bind(this);
+    this.userBalance = this.userBalance.bind(this);
+    this.userBonus = this.userBonus.bind(this);
+    this.signedNonce = this.signedNonce.bind(this);
+    this.ibcSender = this.ibcSender.bind(this);
+    this.sessionGrants = this.sessionGrants.bind(this);
+    this.payoutQueuePosition = this.payoutQueuePosition.bind(this);
+    this.seedChain = this.seedChain.bind(this);
+    this.revealedSeed = this.revealedSeed.bind(this);
+    this.pendingFlips = this.pendingFlips.bind(this);
+    this.randomnessFee = this.randomnessFee.bind(this);
+    this.randomnessRequests = this.randomnessRequests.bind(this);
+    this.rakeback = this.rakeback.bind(this);
+    this.userRakeback = this.userRakeback.bind(this);
+    this.insurance = this.insurance.bind(this);
+    this.contractCallers = this.contractCallers.bind(this);
+    this.deferredOnly = this.deferredOnly.bind(this);
+    this.nftPerks = this.nftPerks.bind(this);
+    this.revenueSplit = this.revenueSplit.bind(this);
+    this.buyback = this.buyback.bind(this);
+    this.oracle = this.oracle.bind(this);
+    this.betRange = this.betRange.bind(this);
+    this.promoCode = this.promoCode.bind(this);
+    this.buybackList = this.buybackList.bind(this);
+    this.nftFloorPrices = this.nftFloorPrices.bind(this);
+    this.houseNfts = this.houseNfts.bind(this);
   }
   config = async (): Promise<Config> => {
     return this.client.queryContractSmart(this.contractAddress, {
@@ -84,16 +206,356 @@ export class PlatformQueryClient implements PlatformReadOnlyInterface {
       }
     });
   };
+  tiers = async (): Promise<ArrayOfTier> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      tiers: {}
+    });
+  };
+  userTier = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<UserTierResp> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      user_tier: {
+        address
+      }
+    });
+  };
+  userBalance = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<Uint128> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      user_balance: {
+        address
+      }
+    });
+  };
+  userBonus = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<BonusInfo> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      user_bonus: {
+        address
+      }
+    });
+  };
+  signedNonce = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<Uint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      signed_nonce: {
+        address
+      }
+    });
+  };
+  ibcSender = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<NullableIbcTarget> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      ibc_sender: {
+        address
+      }
+    });
+  };
+  sessionGrants = async ({
+    amount,
+    owner,
+    startAfter
+  }: {
+    amount: number;
+    owner: string;
+    startAfter?: string;
+  }): Promise<ArrayOfSessionGrantsRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      session_grants: {
+        amount,
+        owner,
+        start_after: startAfter
+      }
+    });
+  };
+  payoutQueuePosition = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<PayoutQueuePositionResp> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      payout_queue_position: {
+        address
+      }
+    });
+  };
+  seedChain = async (): Promise<NullableSeedChain> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      seed_chain: {}
+    });
+  };
+  revealedSeed = async ({
+    round
+  }: {
+    round: number;
+  }): Promise<NullableHexBinary> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      revealed_seed: {
+        round
+      }
+    });
+  };
+  pendingFlips = async ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: number;
+  }): Promise<ArrayOfPendingFlipsRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pending_flips: {
+        amount,
+        start_after: startAfter
+      }
+    });
+  };
+  randomnessFee = async (): Promise<NullableUint128> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      randomness_fee: {}
+    });
+  };
+  randomnessRequests = async ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: number;
+  }): Promise<ArrayOfRandomnessRequestsRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      randomness_requests: {
+        amount,
+        start_after: startAfter
+      }
+    });
+  };
+  rakeback = async (): Promise<NullableRakebackConfig> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      rakeback: {}
+    });
+  };
+  userRakeback = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<UserRakebackResp> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      user_rakeback: {
+        address
+      }
+    });
+  };
+  insurance = async (): Promise<InsuranceResp> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      insurance: {}
+    });
+  };
+  contractCallers = async (): Promise<NullableArrayOfAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      contract_callers: {}
+    });
+  };
+  deferredOnly = async (): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      deferred_only: {}
+    });
+  };
+  nftPerks = async (): Promise<ArrayOfNftPerksRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      nft_perks: {}
+    });
+  };
+  revenueSplit = async (): Promise<ArrayOfRevenueSplitRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      revenue_split: {}
+    });
+  };
+  buyback = async (): Promise<NullableBuybackConfig> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      buyback: {}
+    });
+  };
+  oracle = async (): Promise<NullableOracleConfig> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      oracle: {}
+    });
+  };
+  betRange = async (): Promise<BetRangeResp> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      bet_range: {}
+    });
+  };
+  promoCode = async ({
+    hash
+  }: {
+    hash: HexBinary;
+  }): Promise<NullablePromoCode> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      promo_code: {
+        hash
+      }
+    });
+  };
+  buybackList = async ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: number;
+  }): Promise<ArrayOfBuybackListRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      buyback_list: {
+        amount,
+        start_after: startAfter
+      }
+    });
+  };
+  nftFloorPrices = async (): Promise<ArrayOfNftFloorPriceRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      nft_floor_prices: {}
+    });
+  };
+  houseNfts = async ({
+    amount,
+    startAfter
+  }: {
+    amount: number;
+    startAfter?: Nft;
+  }): Promise<ArrayOfHouseNftRespItem> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      house_nfts: {
+        amount,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface PlatformInterface extends PlatformReadOnlyInterface {
   contractAddress: string;
   sender: string;
   flip: ({
+    amount,
+    callback,
+    clientSeed,
+    nft,
+    payoutTo,
+    side
+  }: {
+    amount?: Uint128;
+    callback?: Callback;
+    clientSeed?: string;
+    nft?: Nft;
+    payoutTo?: IbcTarget;
+    side: Side;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  sessionFlip: ({
+    amount,
+    clientSeed,
+    owner,
+    side
+  }: {
+    amount?: Uint128;
+    clientSeed?: string;
+    owner: string;
+    side: Side;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  flipSigned: ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: Binary;
+    pubkey: Binary;
+    signature: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  grantSession: ({
+    bet,
+    duration,
+    session,
+    side,
+    spendCap
+  }: {
+    bet?: Range;
+    duration: number;
+    session: string;
+    side?: Side;
+    spendCap: Uint128;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeSession: ({
+    session
+  }: {
+    session: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  bonusFlip: ({
+    amount,
     side
   }: {
+    amount: Uint128;
     side: Side;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  redeemPromoCode: ({
+    code
+  }: {
+    code: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  topUp: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawBalance: ({
+    amount
+  }: {
+    amount?: Uint128;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  settleFlip: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  refundFlip: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  requestRandomness: ({
+    callback,
+    deferred,
+    jobId
+  }: {
+    callback: Callback;
+    deferred: boolean;
+    jobId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  fulfillRandomness: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  refundRandomness: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   claim: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  claimRakeback: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  distributeRevenue: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  processPayouts: ({
+    limit
+  }: {
+    limit: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  receiveNft: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   acceptAdminRole: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   deposit: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   withdraw: ({
@@ -103,6 +565,38 @@ export interface PlatformInterface extends PlatformReadOnlyInterface {
     amount?: Uint128;
     recipient?: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  depositInsurance: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawInsurance: ({
+    amount,
+    recipient
+  }: {
+    amount?: Uint128;
+    recipient?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  buyback: ({
+    beliefPrice,
+    maxSpread
+  }: {
+    beliefPrice?: Decimal;
+    maxSpread?: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  commitSeedChain: ({
+    head
+  }: {
+    head: HexBinary;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  revealSeed: ({
+    seed
+  }: {
+    seed: HexBinary;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  enqueuePayouts: ({
+    limit,
+    startAfter
+  }: {
+    limit: number;
+    startAfter?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   updateConfig: ({
     admin,
     bet,
@@ -114,8 +608,97 @@ export interface PlatformInterface extends PlatformReadOnlyInterface {
     platformFee?: Decimal;
     worker?: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  pause: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  unpause: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateTiers: ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  setUserTier: ({
+    address,
+    tier
+  }: {
+    address: string;
+    tier?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateRakeback: ({
+    rakeback
+  }: {
+    rakeback?: RakebackConfig;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateInsurance: ({
+    insurance
+  }: {
+    insurance?: InsuranceConfig;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateContractCallers: ({
+    whitelist
+  }: {
+    whitelist?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateDeferredOnly: ({
+    enabled
+  }: {
+    enabled: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateRandomnessFee: ({
+    fee
+  }: {
+    fee?: Uint128;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateNftPerk: ({
+    collection,
+    perk
+  }: {
+    collection: string;
+    perk?: NftPerk;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateRevenueSplit: ({
+    recipients
+  }: {
+    recipients: RevenueRecipient[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateBuyback: ({
+    buyback
+  }: {
+    buyback?: BuybackConfig;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateOracle: ({
+    oracle
+  }: {
+    oracle?: OracleConfig;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  creditBonus: ({
+    credits,
+    wagering
+  }: {
+    credits: BonusCredit[];
+    wagering: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updatePromoCode: ({
+    hash,
+    promo
+  }: {
+    hash: HexBinary;
+    promo?: PromoCode;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateNftFloorPrice: ({
+    collection,
+    price
+  }: {
+    collection: string;
+    price?: Uint128;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawNft: ({
+    collection,
+    recipient,
+    tokenId
+  }: {
+    collection: string;
+    recipient?: string;
+    tokenId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  pause: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  unpause: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class PlatformClient extends PlatformQueryClient implements PlatformInterface {
   client: SigningCosmWasmClient;
@@ -127,30 +710,282 @@ export class PlatformClient extends PlatformQueryClient implements PlatformInter
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.flip = this.flip.bind(this);
+    this.sessionFlip = this.sessionFlip.bind(this);
+    this.flipSigned = this.flipSigned.bind(this);
+    this.grantSession = this.grantSession.bind(this);
+    this.revokeSession = this.revokeSession.bind(this);
+    this.bonusFlip = this.bonusFlip.bind(this);
+    this.redeemPromoCode = this.redeemPromoCode.bind(this);
+    this.topUp = this.topUp.bind(this);
+    this.withdrawBalance = this.withdrawBalance.bind(this);
+    this.settleFlip = this.settleFlip.bind(this);
+    this.refundFlip = this.refundFlip.bind(this);
+    this.requestRandomness = this.requestRandomness.bind(this);
+    this.fulfillRandomness = this.fulfillRandomness.bind(this);
+    this.refundRandomness = this.refundRandomness.bind(this);
     this.claim = this.claim.bind(this);
+    this.claimRakeback = this.claimRakeback.bind(this);
+    this.distributeRevenue = this.distributeRevenue.bind(this);
+    this.processPayouts = this.processPayouts.bind(this);
+    this.receiveNft = this.receiveNft.bind(this);
     this.acceptAdminRole = this.acceptAdminRole.bind(this);
     this.deposit = this.deposit.bind(this);
     this.withdraw = this.withdraw.bind(this);
+    this.depositInsurance = this.depositInsurance.bind(this);
+    this.withdrawInsurance = this.withdrawInsurance.bind(this);
+    this.buyback = this.buyback.bind(this);
+    this.commitSeedChain = this.commitSeedChain.bind(this);
+    this.revealSeed = this.revealSeed.bind(this);
+    this.enqueuePayouts = this.enqueuePayouts.bind(this);
     this.updateConfig = this.updateConfig.bind(this);
+    this.updateTiers = this.updateTiers.bind(this);
+    this.setUserTier = this.setUserTier.bind(this);
+    this.updateRakeback = this.updateRakeback.bind(this);
+    this.updateInsurance = this.updateInsurance.bind(this);
+    this.updateContractCallers = this.updateContractCallers.bind(this);
+    this.updateDeferredOnly = this.updateDeferredOnly.bind(this);
+    this.updateRandomnessFee = this.updateRandomnessFee.bind(this);
+    this.updateNftPerk = this.updateNftPerk.bind(this);
+    this.updateRevenueSplit = this.updateRevenueSplit.bind(this);
+    this.updateBuyback = this.updateBuyback.bind(this);
+    this.updateOracle = this.updateOracle.bind(this);
+    this.creditBonus = this.creditBonus.bind(this);
+    this.updatePromoCode = this.updatePromoCode.bind(this);
+    this.updateNftFloorPrice = this.updateNftFloorPrice.bind(this);
+    this.withdrawNft = this.withdrawNft.bind(this);
     this.pause = this.pause.bind(this);
     this.unpause = this.unpause.bind(this);
   }
   flip = async ({
+    amount,
+    callback,
+    clientSeed,
+    nft,
+    payoutTo,
     side
   }: {
+    amount?: Uint128;
+    callback?: Callback;
+    clientSeed?: string;
+    nft?: Nft;
+    payoutTo?: IbcTarget;
     side: Side;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       flip: {
+        amount,
+        callback,
+        client_seed: clientSeed,
+        nft,
+        payout_to: payoutTo,
+        side
+      }
+    }, fee, memo, _funds);
+  };
+  sessionFlip = async ({
+    amount,
+    clientSeed,
+    owner,
+    side
+  }: {
+    amount?: Uint128;
+    clientSeed?: string;
+    owner: string;
+    side: Side;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      session_flip: {
+        amount,
+        client_seed: clientSeed,
+        owner,
+        side
+      }
+    }, fee, memo, _funds);
+  };
+  flipSigned = async ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: Binary;
+    pubkey: Binary;
+    signature: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      flip_signed: {
+        payload,
+        pubkey,
+        signature
+      }
+    }, fee, memo, _funds);
+  };
+  grantSession = async ({
+    bet,
+    duration,
+    session,
+    side,
+    spendCap
+  }: {
+    bet?: Range;
+    duration: number;
+    session: string;
+    side?: Side;
+    spendCap: Uint128;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      grant_session: {
+        bet,
+        duration,
+        session,
+        side,
+        spend_cap: spendCap
+      }
+    }, fee, memo, _funds);
+  };
+  revokeSession = async ({
+    session
+  }: {
+    session: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_session: {
+        session
+      }
+    }, fee, memo, _funds);
+  };
+  bonusFlip = async ({
+    amount,
+    side
+  }: {
+    amount: Uint128;
+    side: Side;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      bonus_flip: {
+        amount,
         side
       }
     }, fee, memo, _funds);
   };
+  redeemPromoCode = async ({
+    code
+  }: {
+    code: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      redeem_promo_code: {
+        code
+      }
+    }, fee, memo, _funds);
+  };
+  topUp = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      top_up: {}
+    }, fee, memo, _funds);
+  };
+  withdrawBalance = async ({
+    amount
+  }: {
+    amount?: Uint128;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_balance: {
+        amount
+      }
+    }, fee, memo, _funds);
+  };
+  settleFlip = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      settle_flip: {
+        id
+      }
+    }, fee, memo, _funds);
+  };
+  refundFlip = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      refund_flip: {
+        id
+      }
+    }, fee, memo, _funds);
+  };
+  requestRandomness = async ({
+    callback,
+    deferred,
+    jobId
+  }: {
+    callback: Callback;
+    deferred: boolean;
+    jobId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      request_randomness: {
+        callback,
+        deferred,
+        job_id: jobId
+      }
+    }, fee, memo, _funds);
+  };
+  fulfillRandomness = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      fulfill_randomness: {
+        id
+      }
+    }, fee, memo, _funds);
+  };
+  refundRandomness = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      refund_randomness: {
+        id
+      }
+    }, fee, memo, _funds);
+  };
   claim = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       claim: {}
     }, fee, memo, _funds);
   };
+  claimRakeback = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_rakeback: {}
+    }, fee, memo, _funds);
+  };
+  distributeRevenue = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      distribute_revenue: {}
+    }, fee, memo, _funds);
+  };
+  processPayouts = async ({
+    limit
+  }: {
+    limit: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      process_payouts: {
+        limit
+      }
+    }, fee, memo, _funds);
+  };
+  receiveNft = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      receive_nft: {}
+    }, fee, memo, _funds);
+  };
   acceptAdminRole = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       accept_admin_role: {}
@@ -175,6 +1010,75 @@ export class PlatformClient extends PlatformQueryClient implements PlatformInter
       }
     }, fee, memo, _funds);
   };
+  depositInsurance = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      deposit_insurance: {}
+    }, fee, memo, _funds);
+  };
+  withdrawInsurance = async ({
+    amount,
+    recipient
+  }: {
+    amount?: Uint128;
+    recipient?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_insurance: {
+        amount,
+        recipient
+      }
+    }, fee, memo, _funds);
+  };
+  buyback = async ({
+    beliefPrice,
+    maxSpread
+  }: {
+    beliefPrice?: Decimal;
+    maxSpread?: Decimal;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      buyback: {
+        belief_price: beliefPrice,
+        max_spread: maxSpread
+      }
+    }, fee, memo, _funds);
+  };
+  commitSeedChain = async ({
+    head
+  }: {
+    head: HexBinary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      commit_seed_chain: {
+        head
+      }
+    }, fee, memo, _funds);
+  };
+  revealSeed = async ({
+    seed
+  }: {
+    seed: HexBinary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reveal_seed: {
+        seed
+      }
+    }, fee, memo, _funds);
+  };
+  enqueuePayouts = async ({
+    limit,
+    startAfter
+  }: {
+    limit: number;
+    startAfter?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      enqueue_payouts: {
+        limit,
+        start_after: startAfter
+      }
+    }, fee, memo, _funds);
+  };
   updateConfig = async ({
     admin,
     bet,
@@ -195,6 +1099,192 @@ export class PlatformClient extends PlatformQueryClient implements PlatformInter
       }
     }, fee, memo, _funds);
   };
+  updateTiers = async ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_tiers: {
+        tiers
+      }
+    }, fee, memo, _funds);
+  };
+  setUserTier = async ({
+    address,
+    tier
+  }: {
+    address: string;
+    tier?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_user_tier: {
+        address,
+        tier
+      }
+    }, fee, memo, _funds);
+  };
+  updateRakeback = async ({
+    rakeback
+  }: {
+    rakeback?: RakebackConfig;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_rakeback: {
+        rakeback
+      }
+    }, fee, memo, _funds);
+  };
+  updateInsurance = async ({
+    insurance
+  }: {
+    insurance?: InsuranceConfig;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_insurance: {
+        insurance
+      }
+    }, fee, memo, _funds);
+  };
+  updateContractCallers = async ({
+    whitelist
+  }: {
+    whitelist?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_contract_callers: {
+        whitelist
+      }
+    }, fee, memo, _funds);
+  };
+  updateDeferredOnly = async ({
+    enabled
+  }: {
+    enabled: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_deferred_only: {
+        enabled
+      }
+    }, fee, memo, _funds);
+  };
+  updateRandomnessFee = async ({
+    fee
+  }: {
+    fee?: Uint128;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_randomness_fee: {
+        fee
+      }
+    }, fee, memo, _funds);
+  };
+  updateNftPerk = async ({
+    collection,
+    perk
+  }: {
+    collection: string;
+    perk?: NftPerk;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_nft_perk: {
+        collection,
+        perk
+      }
+    }, fee, memo, _funds);
+  };
+  updateRevenueSplit = async ({
+    recipients
+  }: {
+    recipients: RevenueRecipient[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_revenue_split: {
+        recipients
+      }
+    }, fee, memo, _funds);
+  };
+  updateBuyback = async ({
+    buyback
+  }: {
+    buyback?: BuybackConfig;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_buyback: {
+        buyback
+      }
+    }, fee, memo, _funds);
+  };
+  updateOracle = async ({
+    oracle
+  }: {
+    oracle?: OracleConfig;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_oracle: {
+        oracle
+      }
+    }, fee, memo, _funds);
+  };
+  creditBonus = async ({
+    credits,
+    wagering
+  }: {
+    credits: BonusCredit[];
+    wagering: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      credit_bonus: {
+        credits,
+        wagering
+      }
+    }, fee, memo, _funds);
+  };
+  updatePromoCode = async ({
+    hash,
+    promo
+  }: {
+    hash: HexBinary;
+    promo?: PromoCode;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_promo_code: {
+        hash,
+        promo
+      }
+    }, fee, memo, _funds);
+  };
+  updateNftFloorPrice = async ({
+    collection,
+    price
+  }: {
+    collection: string;
+    price?: Uint128;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_nft_floor_price: {
+        collection,
+        price
+      }
+    }, fee, memo, _funds);
+  };
+  withdrawNft = async ({
+    collection,
+    recipient,
+    tokenId
+  }: {
+    collection: string;
+    recipient?: string;
+    tokenId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_nft: {
+        collection,
+        recipient,
+        token_id: tokenId
+      }
+    }, fee, memo, _funds);
+  };
   pause = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       pause: {}
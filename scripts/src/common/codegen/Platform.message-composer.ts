@@ -8,16 +8,119 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Uint128, Decimal, InstantiateMsg, Range, ExecuteMsg, Side, QueryMsg, MigrateMsg, SignedDecimal, Int256, AppInfo, Revenue, Stats, StatsItem, Addr, Config, UserInfo, ArrayOfUserListRespItem, UserListRespItem } from "./Platform.types";
+import { Uint128, Decimal, InstantiateMsg, Range, ExecuteMsg, Binary, Side, HexBinary, TokenUnverified, Callback, Nft, IbcTarget, Cw721ReceiveMsg, Tier, RakebackConfig, InsuranceConfig, NftPerk, RevenueRecipient, BuybackConfig, OracleConfig, BonusCredit, PromoCode, QueryMsg, MigrateMsg, SudoMsg, IbcLifecycleComplete, SignedDecimal, Int256, AppInfo, Revenue, Stats, StatsItem, BetRangeResp, NullableBuybackConfig, ArrayOfBuybackListRespItem, BuybackListRespItem, Buyback, Addr, Config, NullableArrayOfAddr, Boolean, ArrayOfHouseNftRespItem, HouseNftRespItem, NullableIbcTarget, InsuranceResp, ArrayOfNftFloorPriceRespItem, NftFloorPriceRespItem, ArrayOfNftPerksRespItem, NftPerksRespItem, NullableOracleConfig, PayoutQueuePositionResp, ArrayOfPendingFlipsRespItem, PendingFlipsRespItem, PendingFlip, Bet, NullablePromoCode, NullableRakebackConfig, NullableUint128, ArrayOfRandomnessRequestsRespItem, RandomnessRequestsRespItem, RandomnessRequest, NullableHexBinary, ArrayOfRevenueSplitRespItem, RevenueSplitRespItem, NullableSeedChain, SeedChain, ArrayOfSessionGrantsRespItem, SessionGrantsRespItem, SessionGrant, Uint64, ArrayOfTier, UserInfo, BonusInfo, ArrayOfUserListRespItem, UserListRespItem, UserRakebackResp, UserTierResp, TierProgress } from "./Platform.types";
 export interface PlatformMsg {
   contractAddress: string;
   sender: string;
   flip: ({
+    amount,
+    callback,
+    clientSeed,
+    nft,
+    payoutTo,
+    side
+  }: {
+    amount?: Uint128;
+    callback?: Callback;
+    clientSeed?: string;
+    nft?: Nft;
+    payoutTo?: IbcTarget;
+    side: Side;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  sessionFlip: ({
+    amount,
+    clientSeed,
+    owner,
+    side
+  }: {
+    amount?: Uint128;
+    clientSeed?: string;
+    owner: string;
+    side: Side;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  flipSigned: ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: Binary;
+    pubkey: Binary;
+    signature: Binary;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  grantSession: ({
+    bet,
+    duration,
+    session,
+    side,
+    spendCap
+  }: {
+    bet?: Range;
+    duration: number;
+    session: string;
+    side?: Side;
+    spendCap: Uint128;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeSession: ({
+    session
+  }: {
+    session: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  bonusFlip: ({
+    amount,
     side
   }: {
+    amount: Uint128;
     side: Side;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  redeemPromoCode: ({
+    code
+  }: {
+    code: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  topUp: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  withdrawBalance: ({
+    amount
+  }: {
+    amount?: Uint128;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  settleFlip: ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  refundFlip: ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  requestRandomness: ({
+    callback,
+    deferred,
+    jobId
+  }: {
+    callback: Callback;
+    deferred: boolean;
+    jobId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  fulfillRandomness: ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  refundRandomness: ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   claim: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimRakeback: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  distributeRevenue: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  processPayouts: ({
+    limit
+  }: {
+    limit: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  receiveNft: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   acceptAdminRole: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   deposit: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   withdraw: ({
@@ -27,6 +130,38 @@ export interface PlatformMsg {
     amount?: Uint128;
     recipient?: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  depositInsurance: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  withdrawInsurance: ({
+    amount,
+    recipient
+  }: {
+    amount?: Uint128;
+    recipient?: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  buyback: ({
+    beliefPrice,
+    maxSpread
+  }: {
+    beliefPrice?: Decimal;
+    maxSpread?: Decimal;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  commitSeedChain: ({
+    head
+  }: {
+    head: HexBinary;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revealSeed: ({
+    seed
+  }: {
+    seed: HexBinary;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  enqueuePayouts: ({
+    limit,
+    startAfter
+  }: {
+    limit: number;
+    startAfter?: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateConfig: ({
     admin,
     bet,
@@ -38,6 +173,95 @@ export interface PlatformMsg {
     platformFee?: Decimal;
     worker?: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateTiers: ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setUserTier: ({
+    address,
+    tier
+  }: {
+    address: string;
+    tier?: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateRakeback: ({
+    rakeback
+  }: {
+    rakeback?: RakebackConfig;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateInsurance: ({
+    insurance
+  }: {
+    insurance?: InsuranceConfig;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateContractCallers: ({
+    whitelist
+  }: {
+    whitelist?: string[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateDeferredOnly: ({
+    enabled
+  }: {
+    enabled: boolean;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateRandomnessFee: ({
+    fee
+  }: {
+    fee?: Uint128;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateNftPerk: ({
+    collection,
+    perk
+  }: {
+    collection: string;
+    perk?: NftPerk;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateRevenueSplit: ({
+    recipients
+  }: {
+    recipients: RevenueRecipient[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateBuyback: ({
+    buyback
+  }: {
+    buyback?: BuybackConfig;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateOracle: ({
+    oracle
+  }: {
+    oracle?: OracleConfig;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  creditBonus: ({
+    credits,
+    wagering
+  }: {
+    credits: BonusCredit[];
+    wagering: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updatePromoCode: ({
+    hash,
+    promo
+  }: {
+    hash: HexBinary;
+    promo?: PromoCode;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateNftFloorPrice: ({
+    collection,
+    price
+  }: {
+    collection: string;
+    price?: Uint128;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  withdrawNft: ({
+    collection,
+    recipient,
+    tokenId
+  }: {
+    collection: string;
+    recipient?: string;
+    tokenId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   pause: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   unpause: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
@@ -48,17 +272,65 @@ export class PlatformMsgComposer implements PlatformMsg {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.flip = this.flip.bind(this);
+    this.sessionFlip = this.sessionFlip.bind(this);
+    this.flipSigned = this.flipSigned.bind(this);
+    this.grantSession = this.grantSession.bind(this);
+    this.revokeSession = this.revokeSession.bind(this);
+    this.bonusFlip = this.bonusFlip.bind(this);
+    this.redeemPromoCode = this.redeemPromoCode.bind(this);
+    this.topUp = this.topUp.bind(this);
+    this.withdrawBalance = this.withdrawBalance.bind(this);
+    this.settleFlip = this.settleFlip.bind(this);
+    this.refundFlip = this.refundFlip.bind(this);
+    this.requestRandomness = this.requestRandomness.bind(this);
+    this.fulfillRandomness = this.fulfillRandomness.bind(this);
+    this.refundRandomness = this.refundRandomness.bind(this);
     this.claim = this.claim.bind(this);
+    this.claimRakeback = this.claimRakeback.bind(this);
+    this.distributeRevenue = this.distributeRevenue.bind(this);
+    this.processPayouts = this.processPayouts.bind(this);
+    this.receiveNft = this.receiveNft.bind(this);
     this.acceptAdminRole = this.acceptAdminRole.bind(this);
     this.deposit = this.deposit.bind(this);
     this.withdraw = this.withdraw.bind(this);
+    this.depositInsurance = this.depositInsurance.bind(this);
+    this.withdrawInsurance = this.withdrawInsurance.bind(this);
+    this.buyback = this.buyback.bind(this);
+    this.commitSeedChain = this.commitSeedChain.bind(this);
+    this.revealSeed = this.revealSeed.bind(this);
+    this.enqueuePayouts = this.enqueuePayouts.bind(this);
     this.updateConfig = this.updateConfig.bind(this);
+    this.updateTiers = this.updateTiers.bind(this);
+    this.setUserTier = this.setUserTier.bind(this);
+    this.updateRakeback = this.updateRakeback.bind(this);
+    this.updateInsurance = this.updateInsurance.bind(this);
+    this.updateContractCallers = this.updateContractCallers.bind(this);
+    this.updateDeferredOnly = this.updateDeferredOnly.bind(this);
+    this.updateRandomnessFee = this.updateRandomnessFee.bind(this);
+    this.updateNftPerk = this.updateNftPerk.bind(this);
+    this.updateRevenueSplit = this.updateRevenueSplit.bind(this);
+    this.updateBuyback = this.updateBuyback.bind(this);
+    this.updateOracle = this.updateOracle.bind(this);
+    this.creditBonus = this.creditBonus.bind(this);
+    this.updatePromoCode = this.updatePromoCode.bind(this);
+    this.updateNftFloorPrice = this.updateNftFloorPrice.bind(this);
+    this.withdrawNft = this.withdrawNft.bind(this);
     this.pause = this.pause.bind(this);
     this.unpause = this.unpause.bind(this);
   }
   flip = ({
+    amount,
+    callback,
+    clientSeed,
+    nft,
+    payoutTo,
     side
   }: {
+    amount?: Uint128;
+    callback?: Callback;
+    clientSeed?: string;
+    nft?: Nft;
+    payoutTo?: IbcTarget;
     side: Side;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -68,6 +340,11 @@ export class PlatformMsgComposer implements PlatformMsg {
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           flip: {
+            amount,
+            callback,
+            client_seed: clientSeed,
+            nft,
+            payout_to: payoutTo,
             side
           }
         })),
@@ -75,51 +352,115 @@ export class PlatformMsgComposer implements PlatformMsg {
       })
     };
   };
-  claim = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  sessionFlip = ({
+    amount,
+    clientSeed,
+    owner,
+    side
+  }: {
+    amount?: Uint128;
+    clientSeed?: string;
+    owner: string;
+    side: Side;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          claim: {}
+          session_flip: {
+            amount,
+            client_seed: clientSeed,
+            owner,
+            side
+          }
         })),
         funds: _funds
       })
     };
   };
-  acceptAdminRole = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  flipSigned = ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: Binary;
+    pubkey: Binary;
+    signature: Binary;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          accept_admin_role: {}
+          flip_signed: {
+            payload,
+            pubkey,
+            signature
+          }
         })),
         funds: _funds
       })
     };
   };
-  deposit = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  grantSession = ({
+    bet,
+    duration,
+    session,
+    side,
+    spendCap
+  }: {
+    bet?: Range;
+    duration: number;
+    session: string;
+    side?: Side;
+    spendCap: Uint128;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          deposit: {}
+          grant_session: {
+            bet,
+            duration,
+            session,
+            side,
+            spend_cap: spendCap
+          }
         })),
         funds: _funds
       })
     };
   };
-  withdraw = ({
+  revokeSession = ({
+    session
+  }: {
+    session: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_session: {
+            session
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  bonusFlip = ({
     amount,
-    recipient
+    side
   }: {
-    amount?: Uint128;
-    recipient?: string;
+    amount: Uint128;
+    side: Side;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -127,25 +468,19 @@ export class PlatformMsgComposer implements PlatformMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          withdraw: {
+          bonus_flip: {
             amount,
-            recipient
+            side
           }
         })),
         funds: _funds
       })
     };
   };
-  updateConfig = ({
-    admin,
-    bet,
-    platformFee,
-    worker
+  redeemPromoCode = ({
+    code
   }: {
-    admin?: string;
-    bet?: Range;
-    platformFee?: Decimal;
-    worker?: string;
+    code: string;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -153,11 +488,711 @@ export class PlatformMsgComposer implements PlatformMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          update_config: {
-            admin,
-            bet,
-            platform_fee: platformFee,
-            worker
+          redeem_promo_code: {
+            code
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  topUp = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          top_up: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  withdrawBalance = ({
+    amount
+  }: {
+    amount?: Uint128;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw_balance: {
+            amount
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  settleFlip = ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          settle_flip: {
+            id
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  refundFlip = ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          refund_flip: {
+            id
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  requestRandomness = ({
+    callback,
+    deferred,
+    jobId
+  }: {
+    callback: Callback;
+    deferred: boolean;
+    jobId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          request_randomness: {
+            callback,
+            deferred,
+            job_id: jobId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  fulfillRandomness = ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          fulfill_randomness: {
+            id
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  refundRandomness = ({
+    id
+  }: {
+    id: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          refund_randomness: {
+            id
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claim = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  claimRakeback = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_rakeback: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  distributeRevenue = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          distribute_revenue: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  processPayouts = ({
+    limit
+  }: {
+    limit: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          process_payouts: {
+            limit
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  receiveNft = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          receive_nft: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  acceptAdminRole = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          accept_admin_role: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  deposit = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          deposit: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  withdraw = ({
+    amount,
+    recipient
+  }: {
+    amount?: Uint128;
+    recipient?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw: {
+            amount,
+            recipient
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  depositInsurance = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          deposit_insurance: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  withdrawInsurance = ({
+    amount,
+    recipient
+  }: {
+    amount?: Uint128;
+    recipient?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw_insurance: {
+            amount,
+            recipient
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  buyback = ({
+    beliefPrice,
+    maxSpread
+  }: {
+    beliefPrice?: Decimal;
+    maxSpread?: Decimal;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          buyback: {
+            belief_price: beliefPrice,
+            max_spread: maxSpread
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  commitSeedChain = ({
+    head
+  }: {
+    head: HexBinary;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          commit_seed_chain: {
+            head
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  revealSeed = ({
+    seed
+  }: {
+    seed: HexBinary;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reveal_seed: {
+            seed
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  enqueuePayouts = ({
+    limit,
+    startAfter
+  }: {
+    limit: number;
+    startAfter?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          enqueue_payouts: {
+            limit,
+            start_after: startAfter
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateConfig = ({
+    admin,
+    bet,
+    platformFee,
+    worker
+  }: {
+    admin?: string;
+    bet?: Range;
+    platformFee?: Decimal;
+    worker?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_config: {
+            admin,
+            bet,
+            platform_fee: platformFee,
+            worker
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateTiers = ({
+    tiers
+  }: {
+    tiers: Tier[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_tiers: {
+            tiers
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  setUserTier = ({
+    address,
+    tier
+  }: {
+    address: string;
+    tier?: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_user_tier: {
+            address,
+            tier
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateRakeback = ({
+    rakeback
+  }: {
+    rakeback?: RakebackConfig;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_rakeback: {
+            rakeback
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateInsurance = ({
+    insurance
+  }: {
+    insurance?: InsuranceConfig;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_insurance: {
+            insurance
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateContractCallers = ({
+    whitelist
+  }: {
+    whitelist?: string[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_contract_callers: {
+            whitelist
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateDeferredOnly = ({
+    enabled
+  }: {
+    enabled: boolean;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_deferred_only: {
+            enabled
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateRandomnessFee = ({
+    fee
+  }: {
+    fee?: Uint128;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_randomness_fee: {
+            fee
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateNftPerk = ({
+    collection,
+    perk
+  }: {
+    collection: string;
+    perk?: NftPerk;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_nft_perk: {
+            collection,
+            perk
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateRevenueSplit = ({
+    recipients
+  }: {
+    recipients: RevenueRecipient[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_revenue_split: {
+            recipients
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateBuyback = ({
+    buyback
+  }: {
+    buyback?: BuybackConfig;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_buyback: {
+            buyback
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateOracle = ({
+    oracle
+  }: {
+    oracle?: OracleConfig;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_oracle: {
+            oracle
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  creditBonus = ({
+    credits,
+    wagering
+  }: {
+    credits: BonusCredit[];
+    wagering: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          credit_bonus: {
+            credits,
+            wagering
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updatePromoCode = ({
+    hash,
+    promo
+  }: {
+    hash: HexBinary;
+    promo?: PromoCode;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_promo_code: {
+            hash,
+            promo
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateNftFloorPrice = ({
+    collection,
+    price
+  }: {
+    collection: string;
+    price?: Uint128;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_nft_floor_price: {
+            collection,
+            price
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  withdrawNft = ({
+    collection,
+    recipient,
+    tokenId
+  }: {
+    collection: string;
+    recipient?: string;
+    tokenId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw_nft: {
+            collection,
+            recipient,
+            token_id: tokenId
           }
         })),
         funds: _funds
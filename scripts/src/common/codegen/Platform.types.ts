@@ -17,10 +17,87 @@ export interface Range {
 }
 export type ExecuteMsg = {
   flip: {
+    amount?: Uint128 | null;
+    callback?: Callback | null;
+    client_seed?: string | null;
+    nft?: Nft | null;
+    payout_to?: IbcTarget | null;
+    side: Side;
+  };
+} | {
+  session_flip: {
+    amount?: Uint128 | null;
+    client_seed?: string | null;
+    owner: string;
+    side: Side;
+  };
+} | {
+  flip_signed: {
+    payload: Binary;
+    pubkey: Binary;
+    signature: Binary;
+  };
+} | {
+  grant_session: {
+    bet?: Range | null;
+    duration: number;
+    session: string;
+    side?: Side | null;
+    spend_cap: Uint128;
+  };
+} | {
+  revoke_session: {
+    session: string;
+  };
+} | {
+  bonus_flip: {
+    amount: Uint128;
     side: Side;
   };
+} | {
+  redeem_promo_code: {
+    code: string;
+  };
+} | {
+  top_up: {};
+} | {
+  withdraw_balance: {
+    amount?: Uint128 | null;
+  };
+} | {
+  settle_flip: {
+    id: number;
+  };
+} | {
+  refund_flip: {
+    id: number;
+  };
+} | {
+  request_randomness: {
+    callback: Callback;
+    deferred: boolean;
+    job_id: string;
+  };
+} | {
+  fulfill_randomness: {
+    id: number;
+  };
+} | {
+  refund_randomness: {
+    id: number;
+  };
 } | {
   claim: {};
+} | {
+  claim_rakeback: {};
+} | {
+  distribute_revenue: {};
+} | {
+  process_payouts: {
+    limit: number;
+  };
+} | {
+  receive_nft: Cw721ReceiveMsg;
 } | {
   accept_admin_role: {};
 } | {
@@ -30,6 +107,31 @@ export type ExecuteMsg = {
     amount?: Uint128 | null;
     recipient?: string | null;
   };
+} | {
+  deposit_insurance: {};
+} | {
+  withdraw_insurance: {
+    amount?: Uint128 | null;
+    recipient?: string | null;
+  };
+} | {
+  buyback: {
+    belief_price?: Decimal | null;
+    max_spread?: Decimal | null;
+  };
+} | {
+  commit_seed_chain: {
+    head: HexBinary;
+  };
+} | {
+  reveal_seed: {
+    seed: HexBinary;
+  };
+} | {
+  enqueue_payouts: {
+    limit: number;
+    start_after?: string | null;
+  };
 } | {
   update_config: {
     admin?: string | null;
@@ -37,12 +139,148 @@ export type ExecuteMsg = {
     platform_fee?: Decimal | null;
     worker?: string | null;
   };
+} | {
+  update_tiers: {
+    tiers: Tier[];
+  };
+} | {
+  set_user_tier: {
+    address: string;
+    tier?: number | null;
+  };
+} | {
+  update_rakeback: {
+    rakeback?: RakebackConfig | null;
+  };
+} | {
+  update_insurance: {
+    insurance?: InsuranceConfig | null;
+  };
+} | {
+  update_contract_callers: {
+    whitelist?: string[] | null;
+  };
+} | {
+  update_deferred_only: {
+    enabled: boolean;
+  };
+} | {
+  update_randomness_fee: {
+    fee?: Uint128 | null;
+  };
+} | {
+  update_nft_perk: {
+    collection: string;
+    perk?: NftPerk | null;
+  };
+} | {
+  update_revenue_split: {
+    recipients: RevenueRecipient[];
+  };
+} | {
+  update_buyback: {
+    buyback?: BuybackConfig | null;
+  };
+} | {
+  update_oracle: {
+    oracle?: OracleConfig | null;
+  };
+} | {
+  credit_bonus: {
+    credits: BonusCredit[];
+    wagering: number;
+  };
+} | {
+  update_promo_code: {
+    hash: HexBinary;
+    promo?: PromoCode | null;
+  };
+} | {
+  update_nft_floor_price: {
+    collection: string;
+    price?: Uint128 | null;
+  };
+} | {
+  withdraw_nft: {
+    collection: string;
+    recipient?: string | null;
+    token_id: string;
+  };
 } | {
   pause: {};
 } | {
   unpause: {};
 };
+export type Binary = string;
 export type Side = "head" | "tail";
+export type HexBinary = string;
+export type TokenUnverified = {
+  native: {
+    denom: string;
+  };
+} | {
+  cw20: {
+    address: string;
+  };
+};
+export interface Callback {
+  contract: string;
+  msg: Binary;
+}
+export interface Nft {
+  collection: string;
+  token_id: string;
+}
+export interface IbcTarget {
+  channel_id: string;
+  receiver: string;
+}
+export interface Cw721ReceiveMsg {
+  msg: Binary;
+  sender: string;
+  token_id: string;
+}
+export interface Tier {
+  bet: Range;
+  cashback?: Decimal | null;
+  fee_discount: Decimal;
+  threshold: Uint128;
+}
+export interface RakebackConfig {
+  epoch: number;
+  rate: Decimal;
+}
+export interface InsuranceConfig {
+  rate: Decimal;
+  target: Uint128;
+}
+export interface NftPerk {
+  bet_max?: Uint128 | null;
+  fee_discount: Decimal;
+}
+export interface RevenueRecipient {
+  address: string;
+  weight: Decimal;
+}
+export interface BuybackConfig {
+  pair: string;
+  share: Decimal;
+  token: TokenUnverified;
+}
+export interface OracleConfig {
+  bet: Range;
+  contract: string;
+  max_age: number;
+}
+export interface BonusCredit {
+  address: string;
+  amount: Uint128;
+}
+export interface PromoCode {
+  amount: Uint128;
+  redemptions: number;
+  wagering: number;
+}
 export type QueryMsg = {
   config: {};
 } | {
@@ -60,17 +298,126 @@ export type QueryMsg = {
     amount: number;
     start_after?: string | null;
   };
+} | {
+  tiers: {};
+} | {
+  user_tier: {
+    address: string;
+  };
+} | {
+  user_balance: {
+    address: string;
+  };
+} | {
+  user_bonus: {
+    address: string;
+  };
+} | {
+  signed_nonce: {
+    address: string;
+  };
+} | {
+  ibc_sender: {
+    address: string;
+  };
+} | {
+  session_grants: {
+    amount: number;
+    owner: string;
+    start_after?: string | null;
+  };
+} | {
+  payout_queue_position: {
+    address: string;
+  };
+} | {
+  seed_chain: {};
+} | {
+  revealed_seed: {
+    round: number;
+  };
+} | {
+  pending_flips: {
+    amount: number;
+    start_after?: number | null;
+  };
+} | {
+  randomness_fee: {};
+} | {
+  randomness_requests: {
+    amount: number;
+    start_after?: number | null;
+  };
+} | {
+  rakeback: {};
+} | {
+  user_rakeback: {
+    address: string;
+  };
+} | {
+  insurance: {};
+} | {
+  contract_callers: {};
+} | {
+  deferred_only: {};
+} | {
+  nft_perks: {};
+} | {
+  revenue_split: {};
+} | {
+  buyback: {};
+} | {
+  oracle: {};
+} | {
+  bet_range: {};
+} | {
+  promo_code: {
+    hash: HexBinary;
+  };
+} | {
+  buyback_list: {
+    amount: number;
+    start_after?: number | null;
+  };
+} | {
+  nft_floor_prices: {};
+} | {
+  house_nfts: {
+    amount: number;
+    start_after?: Nft | null;
+  };
 };
 export interface MigrateMsg {
   version: string;
 }
+export type SudoMsg = {
+  ibc_lifecycle_complete: IbcLifecycleComplete;
+};
+export type IbcLifecycleComplete = {
+  ibc_ack: {
+    ack: string;
+    channel: string;
+    sequence: number;
+    success: boolean;
+  };
+} | {
+  ibc_timeout: {
+    channel: string;
+    sequence: number;
+  };
+};
 export type SignedDecimal = string;
 export type Int256 = string;
 export interface AppInfo {
   average_fee: SignedDecimal;
   balance: Uint128;
+  bonus_balances?: Uint128;
   deposited: Uint128;
+  insurance?: Uint128;
+  pending_bets?: Uint128;
   revenue: Revenue;
+  user_balances?: Uint128;
+  user_rakeback?: Uint128;
   user_stats: Stats;
   user_unclaimed: Uint128;
 }
@@ -86,6 +433,22 @@ export interface StatsItem {
   count: number;
   value: Uint128;
 }
+export interface BetRangeResp {
+  bet: Range;
+  price?: Decimal | null;
+}
+export type NullableBuybackConfig = BuybackConfig | null;
+export type ArrayOfBuybackListRespItem = BuybackListRespItem[];
+export interface BuybackListRespItem {
+  buyback: Buyback;
+  id: number;
+}
+export interface Buyback {
+  amount_in: Uint128;
+  amount_out: Uint128;
+  time: number;
+  token: TokenUnverified;
+}
 export type Addr = string;
 export interface Config {
   admin: Addr;
@@ -94,14 +457,133 @@ export interface Config {
   platform_fee: Decimal;
   worker?: Addr | null;
 }
+export type NullableArrayOfAddr = Addr[] | null;
+export type Boolean = boolean;
+export type ArrayOfHouseNftRespItem = HouseNftRespItem[];
+export interface HouseNftRespItem {
+  collection: Addr;
+  price: Uint128;
+  token_id: string;
+}
+export type NullableIbcTarget = IbcTarget | null;
+export interface InsuranceResp {
+  available_to_withdraw: Uint128;
+  config?: InsuranceConfig | null;
+  reserve: Uint128;
+}
+export type ArrayOfNftFloorPriceRespItem = NftFloorPriceRespItem[];
+export interface NftFloorPriceRespItem {
+  collection: Addr;
+  price: Uint128;
+}
+export type ArrayOfNftPerksRespItem = NftPerksRespItem[];
+export interface NftPerksRespItem {
+  collection: Addr;
+  perk: NftPerk;
+}
+export type NullableOracleConfig = OracleConfig | null;
+export interface PayoutQueuePositionResp {
+  length: number;
+  position?: number | null;
+  unclaimed: Uint128;
+}
+export type ArrayOfPendingFlipsRespItem = PendingFlipsRespItem[];
+export interface PendingFlipsRespItem {
+  flip: PendingFlip;
+  id: number;
+}
+export interface PendingFlip {
+  bet: Bet;
+  client_seed: string;
+  created_at: number;
+  nonce: number;
+  round: number;
+}
+export interface Bet {
+  amount: Uint128;
+  callback?: Callback | null;
+  cashback: Uint128;
+  fee: Decimal;
+  from_balance?: boolean;
+  nft?: Nft | null;
+  session?: Addr | null;
+  side: Side;
+  user: Addr;
+}
+export type NullablePromoCode = PromoCode | null;
+export type NullableRakebackConfig = RakebackConfig | null;
+export type NullableUint128 = Uint128 | null;
+export type ArrayOfRandomnessRequestsRespItem = RandomnessRequestsRespItem[];
+export interface RandomnessRequestsRespItem {
+  id: number;
+  request: RandomnessRequest;
+}
+export interface RandomnessRequest {
+  callback: Callback;
+  created_at: number;
+  fee: Uint128;
+  job_id: string;
+  requester: Addr;
+  round: number;
+}
+export type NullableHexBinary = HexBinary | null;
+export type ArrayOfRevenueSplitRespItem = RevenueSplitRespItem[];
+export interface RevenueSplitRespItem {
+  address: Addr;
+  distributed: Uint128;
+  weight: Decimal;
+}
+export type NullableSeedChain = SeedChain | null;
+export interface SeedChain {
+  head: HexBinary;
+  height: number;
+  round: number;
+}
+export type ArrayOfSessionGrantsRespItem = SessionGrantsRespItem[];
+export interface SessionGrantsRespItem {
+  grant: SessionGrant;
+  session: Addr;
+}
+export interface SessionGrant {
+  bet?: Range | null;
+  expiration: number;
+  side?: Side | null;
+  spend_cap: Uint128;
+  spent: Uint128;
+}
+export type Uint64 = number;
+export type ArrayOfTier = Tier[];
 export interface UserInfo {
   last_flip_date: number;
   roi: SignedDecimal;
   stats: Stats;
   unclaimed: Uint128;
 }
+export interface BonusInfo {
+  balance: Uint128;
+  wagered: Uint128;
+  wagering_requirement: Uint128;
+}
 export type ArrayOfUserListRespItem = UserListRespItem[];
 export interface UserListRespItem {
   address: Addr;
   info: UserInfo;
+}
+export interface UserRakebackResp {
+  accrued: Uint128;
+  epoch: number;
+  pending: Uint128;
+}
+export interface UserTierResp {
+  bets_value: Uint128;
+  is_assigned: boolean;
+  level: number;
+  next?: TierProgress | null;
+  tier: Tier;
+}
+export interface TierProgress {
+  level: number;
+  progress: Decimal;
+  remaining: Uint128;
+  threshold: Uint128;
 }
\ No newline at end of file
@@ -68,7 +68,7 @@ export const CHAIN_CONFIG: ChainConfig = {
                 bet: { min: `${500_000}`, max: `${10_000_000}` },
               }),
               MIGRATE_MSG: toJson<PlatformTypes.MigrateMsg>({
-                version: "1.0.0",
+                version: "1.1.0",
               }),
               UPDATE_MSG: toJson<PlatformTypes.ExecuteMsg>({
                 update_config: {},
@@ -102,7 +102,7 @@ export const CHAIN_CONFIG: ChainConfig = {
                 bet: { min: `${100_000}`, max: `${20_000_000}` },
               }),
               MIGRATE_MSG: toJson<PlatformTypes.MigrateMsg>({
-                version: "1.0.0",
+                version: "1.1.0",
               }),
               UPDATE_MSG: toJson<PlatformTypes.ExecuteMsg>({
                 update_config: {},